@@ -0,0 +1,40 @@
+//! Generates the `paperplane-types` sources from TDLib's `td_api.tl`.
+//!
+//! After updating the `td` submodule, regenerate them with
+//!
+//! ```text
+//! cargo run -p paperplane-codegen
+//! ```
+//!
+//! or pass the schema and the output directory explicitly:
+//! `cargo run -p paperplane-codegen -- path/to/td_api.tl paperplane-types/src`.
+//! The version of `paperplane-types` follows TDLib's and is bumped by hand.
+
+use std::{ fs, io, path::Path };
+
+pub mod tl;
+pub mod rust;
+
+/// Contents of `types.rs` and `methods.rs`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub types: String,
+    pub methods: String,
+}
+
+pub fn generate(schema: &str) -> Result<Generated, tl::ParseError> {
+    let schema = tl::parse(schema)?;
+    Ok(Generated {
+        types: rust::types(&schema),
+        methods: rust::methods(&schema),
+    })
+}
+
+/// Reads the schema at `schema` and writes `types.rs` and `methods.rs`
+/// into `output`
+pub fn generate_files(schema: &Path, output: &Path) -> io::Result<()> {
+    let source = fs::read_to_string(schema)?;
+    let generated = generate(&source).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    fs::write(output.join("types.rs"), generated.types)?;
+    fs::write(output.join("methods.rs"), generated.methods)
+}
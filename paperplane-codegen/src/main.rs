@@ -0,0 +1,17 @@
+use std::{ env, path::PathBuf, process };
+
+const DEFAULT_SCHEMA: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../paperplane/td/td/generate/scheme/td_api.tl");
+const DEFAULT_OUTPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../paperplane-types/src");
+
+// paperplane-codegen [td_api.tl] [output directory]
+fn main() {
+    let mut args = env::args_os().skip(1);
+    let schema = args.next().map_or_else(|| PathBuf::from(DEFAULT_SCHEMA), PathBuf::from);
+    let output = args.next().map_or_else(|| PathBuf::from(DEFAULT_OUTPUT), PathBuf::from);
+
+    if let Err(err) = paperplane_codegen::generate_files(&schema, &output) {
+        eprintln!("could not generate from {}: {}", schema.display(), err);
+        process::exit(1);
+    }
+    println!("generated {} from {}", output.display(), schema.display());
+}
@@ -0,0 +1,224 @@
+use std::fmt::Write;
+use crate::tl::{ Definition, Field, Schema, Type };
+
+const HEADER: &str = "// Generated by paperplane-codegen from td_api.tl, do not edit by hand\n";
+
+const TYPES_PRELUDE: &str = "use serde::{ Serialize, Deserialize };\n\n";
+
+const METHODS_PRELUDE: &str = r#"use crate::types::*;
+use serde::de::DeserializeOwned;
+use serde::{ Serialize, Deserialize };
+use std::fmt::Debug;
+
+pub trait Method: Serialize + Clone {
+    const TYPE: &'static str;
+    /// `@type` of the response if it is a single constructor, which
+    /// serializes without one unlike the classes
+    const RESPONSE_TYPE: Option<&'static str>;
+    type Response: DeserializeOwned + Debug;
+
+    fn tag(self) -> MethodType<Self>
+    where
+        Self: Sized,
+    {
+        MethodType {
+            type_: Self::TYPE,
+            payload: self,
+        }
+    }
+}
+/// Methods TDLib documents as "Can be called synchronously", which can
+/// be run with `td_json_client_execute` instead of going through `send`
+pub trait SyncMethod: Method {}
+#[derive(Serialize, Debug, Clone)]
+pub struct MethodType<T: Method> {
+    #[serde(rename = "@type")]
+    pub type_: &'static str,
+    #[serde(flatten)]
+    pub payload: T,
+}
+"#;
+
+const DERIVE: &str = "#[derive(Serialize, Deserialize, Debug, Clone)]\n";
+
+const NUMBER_FROM_STRING: &str = "::serde_aux::field_attributes::deserialize_number_from_string";
+
+// Field names that have to be renamed to be valid identifiers
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true",
+    "type", "unsafe", "use", "where", "while",
+];
+
+/// `types.rs`: a struct per constructor, then a `tagged_enum!` per class
+/// with more than one constructor or a constructor named differently
+pub fn types(schema: &Schema) -> String {
+    let mut out = format!("{}{}", HEADER, TYPES_PRELUDE);
+    for definition in &schema.types {
+        write_struct(&mut out, definition);
+    }
+
+    let mut classes: Vec<(&str, Vec<&Definition>)> = Vec::new();
+    for definition in &schema.types {
+        match classes.iter_mut().find(|(class, _)| *class == definition.result) {
+            Some((_, constructors)) => constructors.push(definition),
+            None => classes.push((&definition.result, vec![definition])),
+        }
+    }
+    for (class, constructors) in classes {
+        if let [constructor] = constructors.as_slice() {
+            if type_name(&constructor.name) == class {
+                continue;
+            }
+        }
+        let description = schema.class(class).map_or("", |class| &class.description);
+        out.push_str("tagged_enum! {\n");
+        write_doc(&mut out, "    ", description);
+        writeln!(out, "    pub enum {} {{", class).unwrap();
+        for constructor in constructors {
+            let variant = type_name(&constructor.name);
+            writeln!(out, "        {}({}),", variant, variant).unwrap();
+        }
+        out.push_str("    }\n}\n");
+    }
+    out
+}
+
+/// `methods.rs`: the `Method` trait, then a struct per function with its
+/// `Method` impl, and a `SyncMethod` impl for the ones TDLib documents as
+/// "Can be called synchronously"
+pub fn methods(schema: &Schema) -> String {
+    let mut out = format!("{}{}", HEADER, METHODS_PRELUDE);
+    for function in &schema.functions {
+        let name = type_name(&function.name);
+        write_struct(&mut out, function);
+        writeln!(out, "impl Method for {} {{", name).unwrap();
+        writeln!(out, "    const TYPE: &'static str = \"{}\";", function.name).unwrap();
+        match schema.types.iter().find(|constructor| type_name(&constructor.name) == function.result) {
+            Some(constructor) => writeln!(out, "    const RESPONSE_TYPE: Option<&'static str> = Some(\"{}\");", constructor.name),
+            None => writeln!(out, "    const RESPONSE_TYPE: Option<&'static str> = None;"),
+        }.unwrap();
+        writeln!(out, "    type Response = {};", type_name(&function.result)).unwrap();
+        out.push_str("}\n");
+        if function.description.contains("Can be called synchronously") {
+            writeln!(out, "impl SyncMethod for {} {{}}", name).unwrap();
+        }
+    }
+    out
+}
+
+fn write_struct(out: &mut String, definition: &Definition) {
+    out.push_str(DERIVE);
+    write_doc(out, "", &definition.description);
+    let name = type_name(&definition.name);
+    if definition.fields.is_empty() {
+        writeln!(out, "pub struct {} {{}}", name).unwrap();
+        return;
+    }
+    writeln!(out, "pub struct {} {{", name).unwrap();
+    for field in &definition.fields {
+        write_field(out, definition, field);
+    }
+    out.push_str("}\n");
+}
+
+fn write_field(out: &mut String, definition: &Definition, field: &Field) {
+    let name = if KEYWORDS.contains(&field.name.as_str()) {
+        writeln!(out, "    #[serde(rename = \"{}\")]", field.name).unwrap();
+        format!("{}_", field.name)
+    } else {
+        field.name.clone()
+    };
+    write_doc(out, "    ", &field.description);
+
+    let optional = is_nullable(definition, field);
+    match field.type_ {
+        Type::Bool => out.push_str("    #[serde(default)]\n"),
+        // int64 arrives as a string, which JSON numbers could not hold;
+        // the helper takes int53 numbers as well
+        Type::Int53 | Type::Int64 if !optional => {
+            writeln!(out, "    #[serde(deserialize_with = \"{}\")]", NUMBER_FROM_STRING).unwrap();
+        },
+        _ => (),
+    }
+
+    let type_ = rust_type(&field.type_);
+    if optional {
+        writeln!(out, "    pub {}: Option<{}>,", name, type_).unwrap();
+    } else {
+        writeln!(out, "    pub {}: {},", name, type_).unwrap();
+    }
+}
+
+// Empty descriptions would only give clippy's `empty_docs` something to say
+fn write_doc(out: &mut String, indent: &str, description: &str) {
+    if !description.is_empty() {
+        writeln!(out, "{}#[doc = \"{}\"]", indent, escape(description)).unwrap();
+    }
+}
+
+// How td_api.tl annotates fields that can be null, in lowercase
+const NULL_MARKERS: &[&str] = &[
+    "may be null",
+    "can be null",
+    "or null",
+    "pass null",
+];
+
+// Fields td_api.tl leaves without an annotation although TDLib takes null
+// for them, as `constructor.field`
+const NULLABLE: &[&str] = &[
+    "sendMessageOptions.scheduling_state",
+    // Only bots can attach a reply markup, so everyone else passes null
+    "sendMessage.reply_markup",
+    "editMessageText.reply_markup",
+    "editMessageLiveLocation.reply_markup",
+    "editMessageMedia.reply_markup",
+    "editMessageCaption.reply_markup",
+    "editInlineMessageMedia.reply_markup",
+    "stopPoll.reply_markup",
+];
+
+fn is_nullable(definition: &Definition, field: &Field) -> bool {
+    let description = field.description.to_lowercase();
+    NULL_MARKERS.iter().any(|marker| description.contains(marker))
+        // Objects that only mean something when present, e.g. the error of
+        // `finishFileGeneration`
+        || (matches!(field.type_, Type::Object(_)) && description.starts_with("if set,"))
+        || NULLABLE.contains(&format!("{}.{}", definition.name, field.name).as_str())
+}
+
+fn rust_type(type_: &Type) -> String {
+    match type_ {
+        Type::Int32 => "i32".to_owned(),
+        Type::Int53 | Type::Int64 => "i64".to_owned(),
+        Type::Double => "f64".to_owned(),
+        Type::String | Type::Bytes => "String".to_owned(),
+        Type::Bool => "bool".to_owned(),
+        Type::Vector(inner) => format!("Vec<{}>", rust_type(inner)),
+        Type::Object(name) => type_name(name),
+    }
+}
+
+/// `formattedText` and `FormattedText` are both `FormattedText`
+pub fn type_name(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
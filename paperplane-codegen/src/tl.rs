@@ -0,0 +1,235 @@
+use std::{ error, fmt };
+
+// Declared at the top of td_api.tl, mapped to Rust types directly
+const BUILTINS: &[&str] = &[
+    "double",
+    "string",
+    "int32",
+    "int53",
+    "int64",
+    "bytes",
+    "boolFalse",
+    "boolTrue",
+    "vector",
+];
+
+/// Type of a field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Type {
+    Int32,
+    Int53,
+    Int64,
+    Double,
+    String,
+    Bytes,
+    Bool,
+    Vector(Box<Type>),
+    /// A constructor, e.g. `formattedText`, or a class, e.g. `ChatList`
+    Object(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub name: String,
+    pub type_: Type,
+    pub description: String,
+}
+
+/// A constructor, or a function when listed in `Schema::functions`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Definition {
+    pub name: String,
+    pub fields: Vec<Field>,
+    /// The class of a constructor, the response type of a function
+    pub result: String,
+    pub description: String,
+}
+
+/// An abstract type declared with `//@class`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Class {
+    pub name: String,
+    pub description: String,
+}
+
+/// Everything in a `td_api.tl`, in the order it is declared
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Schema {
+    pub types: Vec<Definition>,
+    pub functions: Vec<Definition>,
+    pub classes: Vec<Class>,
+}
+
+impl Schema {
+    pub fn class(&self, name: &str) -> Option<&Class> {
+        self.classes.iter().find(|class| class.name == name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl error::Error for ParseError {}
+
+// `@tag text` pairs of the comment block above a declaration
+#[derive(Default)]
+struct Doc {
+    text: String,
+}
+
+impl Doc {
+    fn tags(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.text.split('@').skip(1).map(|tag| match tag.find(' ') {
+            Some(pos) => (&tag[..pos], &tag[pos + 1..]),
+            None => (tag, ""),
+        })
+    }
+
+    fn get(&self, name: &str) -> Option<&str> {
+        self.tags().find(|(tag, _)| *tag == name).map(|(_, text)| text)
+    }
+
+    // Fields called `description` are documented as `@param_description`
+    fn field(&self, name: &str) -> &str {
+        let tag = if name == "description" { "param_description" } else { name };
+        self.get(tag).unwrap_or_default()
+    }
+
+    fn is_class(&self) -> bool {
+        self.get("class").is_some()
+    }
+
+    fn take(&mut self) -> Doc {
+        Doc { text: std::mem::take(&mut self.text) }
+    }
+}
+
+/// Parses the text of a `td_api.tl`.
+///
+/// Comments are read the way TDLib writes them: `//@tag text` starts a tag,
+/// several of which may share a line, and `//-` continues the text of the
+/// last one on a new line.
+pub fn parse(source: &str) -> Result<Schema, ParseError> {
+    let mut schema = Schema::default();
+    let mut functions = false;
+    let mut doc = Doc::default();
+
+    for (index, line) in source.lines().enumerate() {
+        // Trailing spaces of comments are part of the text like in the
+        // middle of a line, where they separate it from the next tag
+        let line = line.trim_end_matches('\r');
+        let error = |message: String| ParseError { line: index + 1, message };
+
+        if let Some(text) = line.strip_prefix("//@") {
+            if doc.is_class() && (text.starts_with("class ") || text.starts_with("description ")) {
+                add_class(&mut schema, doc.take()).map_err(error)?;
+            }
+            doc.text.push('@');
+            doc.text.push_str(text);
+        } else if let Some(text) = line.strip_prefix("//-") {
+            doc.text.push_str("\n ");
+            doc.text.push_str(text);
+        } else if line.starts_with("//") {
+            continue;
+        } else if line.trim().is_empty() {
+            if doc.is_class() {
+                add_class(&mut schema, doc.take()).map_err(error)?;
+            }
+        } else if line.trim() == "---functions---" {
+            functions = true;
+            doc.take();
+        } else if line.trim() == "---types---" {
+            functions = false;
+            doc.take();
+        } else {
+            if doc.is_class() {
+                add_class(&mut schema, doc.take()).map_err(error)?;
+            }
+            let doc = doc.take();
+            if let Some(definition) = parse_definition(line.trim(), &doc).map_err(error)? {
+                if functions {
+                    schema.functions.push(definition);
+                } else {
+                    schema.types.push(definition);
+                }
+            }
+        }
+    }
+    Ok(schema)
+}
+
+fn add_class(schema: &mut Schema, doc: Doc) -> Result<(), String> {
+    let name = doc.get("class").unwrap_or_default().trim();
+    if name.is_empty() {
+        return Err("@class without a name".to_owned());
+    }
+    schema.classes.push(Class {
+        name: name.to_owned(),
+        description: doc.get("description").unwrap_or_default().to_owned(),
+    });
+    Ok(())
+}
+
+// `name field:type ... = Result;`, or `None` for the builtin types
+fn parse_definition(line: &str, doc: &Doc) -> Result<Option<Definition>, String> {
+    let declaration = line
+        .strip_suffix(';')
+        .ok_or_else(|| format!("expected `;` at the end of `{}`", line))?;
+    let (left, result) = match declaration.find('=') {
+        Some(pos) => (declaration[..pos].trim(), declaration[pos + 1..].trim()),
+        None => return Err(format!("expected `=` in `{}`", line)),
+    };
+    let mut words = left.split_whitespace();
+    let name = words.next().ok_or_else(|| format!("missing name in `{}`", line))?;
+    if BUILTINS.contains(&name) {
+        return Ok(None);
+    }
+
+    let fields = words
+        .map(|word| {
+            let pos = word.find(':').ok_or_else(|| format!("expected `name:type`, got `{}`", word))?;
+            let field = &word[..pos];
+            Ok(Field {
+                name: field.to_owned(),
+                type_: parse_type(&word[pos + 1..])?,
+                description: doc.field(field).to_owned(),
+            })
+        })
+        .collect::<Result<_, String>>()?;
+
+    Ok(Some(Definition {
+        name: name.to_owned(),
+        fields,
+        result: result.to_owned(),
+        description: doc.get("description").unwrap_or_default().to_owned(),
+    }))
+}
+
+fn parse_type(type_: &str) -> Result<Type, String> {
+    if let Some(inner) = type_.strip_prefix("vector<") {
+        let inner = inner
+            .strip_suffix('>')
+            .ok_or_else(|| format!("unclosed `vector<` in `{}`", type_))?;
+        return Ok(Type::Vector(Box::new(parse_type(inner)?)));
+    }
+    Ok(match type_ {
+        "int32" => Type::Int32,
+        "int53" => Type::Int53,
+        "int64" => Type::Int64,
+        "double" => Type::Double,
+        "string" => Type::String,
+        "bytes" => Type::Bytes,
+        "Bool" => Type::Bool,
+        "" => return Err("missing field type".to_owned()),
+        object => Type::Object(object.to_owned()),
+    })
+}
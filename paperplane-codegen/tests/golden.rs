@@ -0,0 +1,158 @@
+use std::{ fs, path::Path };
+use paperplane_codegen::generate;
+
+fn read(path: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+    fs::read_to_string(&path).unwrap_or_else(|err| panic!("could not read {}: {}", path.display(), err))
+}
+
+// Points at the first difference instead of printing both files whole
+fn assert_same(generated: &str, expected: &str, name: &str) {
+    for (number, (generated, expected)) in generated.lines().zip(expected.lines()).enumerate() {
+        assert_eq!(generated, expected, "{} differs at line {}", name, number + 1);
+    }
+    assert_eq!(generated.lines().count(), expected.lines().count(), "{} differs in length", name);
+    assert_eq!(generated, expected, "{} differs in line endings", name);
+}
+
+#[test]
+fn regenerates_paperplane_types() {
+    let generated = generate(&read("tests/data/td_api-1.6.4.tl")).unwrap();
+    assert_same(&generated.types, &read("../paperplane-types/src/types.rs"), "types.rs");
+    assert_same(&generated.methods, &read("../paperplane-types/src/methods.rs"), "methods.rs");
+}
+
+const SCHEMA: &str = r#"double ? = Double;
+string ? = String;
+
+int32 = Int32;
+int53 = Int53;
+int64 = Int64;
+bytes = Bytes;
+
+boolFalse = Bool;
+boolTrue = Bool;
+
+vector {t:Type} # [ t ] = Vector t;
+
+//@class ChatList @description Describes a list of chats
+
+//@description A main list of chats
+chatListMain = ChatList;
+
+//@description A list of chats in the chat archive
+chatListArchive = ChatList;
+
+//@description A chat @id Chat identifier @order Position of the chat in the list;
+//-0 if it is not in the list @chat_list The list the chat is in; may be null
+//@param_description Chat description @is_pinned True, if the chat is pinned @members Some "members"
+chat id:int53 order:int64 chat_list:ChatList description:bytes is_pinned:Bool members:vector<vector<int32>> = Chat;
+
+---functions---
+
+//@description Returns a chat @chat_id Chat identifier
+getChat chat_id:int53 = Chat;
+
+//@description Returns the main chat list. Can be called synchronously
+getMainChatList = ChatList;
+
+//@description Returns chats @chat_list The list to return chats from; pass null to return all chats
+//@error If set, an error to return instead
+getChats chat_list:ChatList error:error = Chats;
+
+getChatCount = Count;
+"#;
+
+const TYPES: &str = r#"// Generated by paperplane-codegen from td_api.tl, do not edit by hand
+use serde::{ Serialize, Deserialize };
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "A main list of chats"]
+pub struct ChatListMain {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "A list of chats in the chat archive"]
+pub struct ChatListArchive {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "A chat "]
+pub struct Chat {
+    #[doc = "Chat identifier "]
+    #[serde(deserialize_with = "::serde_aux::field_attributes::deserialize_number_from_string")]
+    pub id: i64,
+    #[doc = "Position of the chat in the list;\n 0 if it is not in the list "]
+    #[serde(deserialize_with = "::serde_aux::field_attributes::deserialize_number_from_string")]
+    pub order: i64,
+    #[doc = "The list the chat is in; may be null"]
+    pub chat_list: Option<ChatList>,
+    #[doc = "Chat description "]
+    pub description: String,
+    #[doc = "True, if the chat is pinned "]
+    #[serde(default)]
+    pub is_pinned: bool,
+    #[doc = "Some \"members\""]
+    pub members: Vec<Vec<i32>>,
+}
+tagged_enum! {
+    #[doc = "Describes a list of chats"]
+    pub enum ChatList {
+        ChatListMain(ChatListMain),
+        ChatListArchive(ChatListArchive),
+    }
+}
+"#;
+
+const FUNCTIONS: &str = r#"#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns a chat "]
+pub struct GetChat {
+    #[doc = "Chat identifier"]
+    #[serde(deserialize_with = "::serde_aux::field_attributes::deserialize_number_from_string")]
+    pub chat_id: i64,
+}
+impl Method for GetChat {
+    const TYPE: &'static str = "getChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chat");
+    type Response = Chat;
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns the main chat list. Can be called synchronously"]
+pub struct GetMainChatList {}
+impl Method for GetMainChatList {
+    const TYPE: &'static str = "getMainChatList";
+    const RESPONSE_TYPE: Option<&'static str> = None;
+    type Response = ChatList;
+}
+impl SyncMethod for GetMainChatList {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns chats "]
+pub struct GetChats {
+    #[doc = "The list to return chats from; pass null to return all chats"]
+    pub chat_list: Option<ChatList>,
+    #[doc = "If set, an error to return instead"]
+    pub error: Option<Error>,
+}
+impl Method for GetChats {
+    const TYPE: &'static str = "getChats";
+    const RESPONSE_TYPE: Option<&'static str> = None;
+    type Response = Chats;
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetChatCount {}
+impl Method for GetChatCount {
+    const TYPE: &'static str = "getChatCount";
+    const RESPONSE_TYPE: Option<&'static str> = None;
+    type Response = Count;
+}
+"#;
+
+#[test]
+fn maps_schema_features() {
+    let generated = generate(SCHEMA).unwrap();
+    assert_same(&generated.types, TYPES, "types.rs");
+    assert!(generated.methods.ends_with(FUNCTIONS), "methods.rs:\n{}", generated.methods);
+}
+
+#[test]
+fn reports_malformed_lines() {
+    let err = generate("//@description Broken\nbroken field = Broken;\n").unwrap_err();
+    assert_eq!(err.line, 2);
+    assert_eq!(err.to_string(), "line 2: expected `name:type`, got `field`");
+}
@@ -1,2 +1,9 @@
+// Variants hold TDLib's objects as they are, boxing the big ones would
+// only get in the way of matching on them
+#![allow(clippy::large_enum_variant)]
+
+#[macro_use]
+mod macros;
+
 pub mod methods;
 pub mod types;
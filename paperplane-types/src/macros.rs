@@ -0,0 +1,65 @@
+/// Declares a TDLib abstract type as an enum internally tagged by `@type`.
+///
+/// Besides the listed variants the enum gets an `Unknown` variant holding
+/// the raw JSON of any `@type` this version of the crate does not know,
+/// so objects from a newer libtdjson deserialize instead of failing.
+/// `Unknown` serializes back to the same JSON.
+macro_rules! tagged_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($variant:ident($ty:ty),)*
+        }
+    ) => {
+        #[derive(Debug, Clone)]
+        $(#[$meta])*
+        pub enum $name {
+            $($variant($ty),)*
+            #[doc = "An object of a type unknown to this version of the crate"]
+            Unknown {
+                type_name: String,
+                raw: ::serde_json::Value,
+            },
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                #[allow(clippy::enum_variant_names)]
+                #[derive(::serde::Serialize)]
+                #[serde(rename_all = "camelCase")]
+                #[serde(tag = "@type")]
+                enum Known<'a> {
+                    $($variant(&'a $ty),)*
+                }
+
+                match self {
+                    $($name::$variant(inner) => Known::$variant(inner).serialize(serializer),)*
+                    $name::Unknown { raw, .. } => raw.serialize(serializer),
+                }
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                #[allow(clippy::enum_variant_names, clippy::large_enum_variant)]
+                #[derive(::serde::Deserialize)]
+                #[serde(rename_all = "camelCase")]
+                #[serde(tag = "@type")]
+                enum Known {
+                    $($variant($ty),)*
+                    #[serde(other)]
+                    Unknown,
+                }
+
+                let raw = ::serde_json::Value::deserialize(deserializer)?;
+                match Known::deserialize(&raw).map_err(::serde::de::Error::custom)? {
+                    $(Known::$variant(inner) => Ok($name::$variant(inner)),)*
+                    Known::Unknown => Ok($name::Unknown {
+                        type_name: raw["@type"].as_str().unwrap_or_default().to_owned(),
+                        raw,
+                    }),
+                }
+            }
+        }
+    };
+}
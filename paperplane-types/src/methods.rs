@@ -1,3 +1,4 @@
+// Generated by paperplane-codegen from td_api.tl, do not edit by hand
 use crate::types::*;
 use serde::de::DeserializeOwned;
 use serde::{ Serialize, Deserialize };
@@ -5,6 +6,9 @@ use std::fmt::Debug;
 
 pub trait Method: Serialize + Clone {
     const TYPE: &'static str;
+    /// `@type` of the response if it is a single constructor, which
+    /// serializes without one unlike the classes
+    const RESPONSE_TYPE: Option<&'static str>;
     type Response: DeserializeOwned + Debug;
 
     fn tag(self) -> MethodType<Self>
@@ -17,6 +21,9 @@ pub trait Method: Serialize + Clone {
         }
     }
 }
+/// Methods TDLib documents as "Can be called synchronously", which can
+/// be run with `td_json_client_execute` instead of going through `send`
+pub trait SyncMethod: Method {}
 #[derive(Serialize, Debug, Clone)]
 pub struct MethodType<T: Method> {
     #[serde(rename = "@type")]
@@ -29,6 +36,7 @@ pub struct MethodType<T: Method> {
 pub struct GetAuthorizationState {}
 impl Method for GetAuthorizationState {
     const TYPE: &'static str = "getAuthorizationState";
+    const RESPONSE_TYPE: Option<&'static str> = None;
     type Response = AuthorizationState;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -39,6 +47,7 @@ pub struct SetTdlibParameters {
 }
 impl Method for SetTdlibParameters {
     const TYPE: &'static str = "setTdlibParameters";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -49,6 +58,7 @@ pub struct CheckDatabaseEncryptionKey {
 }
 impl Method for CheckDatabaseEncryptionKey {
     const TYPE: &'static str = "checkDatabaseEncryptionKey";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -61,6 +71,7 @@ pub struct SetAuthenticationPhoneNumber {
 }
 impl Method for SetAuthenticationPhoneNumber {
     const TYPE: &'static str = "setAuthenticationPhoneNumber";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -68,6 +79,7 @@ impl Method for SetAuthenticationPhoneNumber {
 pub struct ResendAuthenticationCode {}
 impl Method for ResendAuthenticationCode {
     const TYPE: &'static str = "resendAuthenticationCode";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -78,6 +90,7 @@ pub struct CheckAuthenticationCode {
 }
 impl Method for CheckAuthenticationCode {
     const TYPE: &'static str = "checkAuthenticationCode";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -88,6 +101,7 @@ pub struct RequestQrCodeAuthentication {
 }
 impl Method for RequestQrCodeAuthentication {
     const TYPE: &'static str = "requestQrCodeAuthentication";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -100,6 +114,7 @@ pub struct RegisterUser {
 }
 impl Method for RegisterUser {
     const TYPE: &'static str = "registerUser";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -110,6 +125,7 @@ pub struct CheckAuthenticationPassword {
 }
 impl Method for CheckAuthenticationPassword {
     const TYPE: &'static str = "checkAuthenticationPassword";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -117,6 +133,7 @@ impl Method for CheckAuthenticationPassword {
 pub struct RequestAuthenticationPasswordRecovery {}
 impl Method for RequestAuthenticationPasswordRecovery {
     const TYPE: &'static str = "requestAuthenticationPasswordRecovery";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -127,6 +144,7 @@ pub struct RecoverAuthenticationPassword {
 }
 impl Method for RecoverAuthenticationPassword {
     const TYPE: &'static str = "recoverAuthenticationPassword";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -137,6 +155,7 @@ pub struct CheckAuthenticationBotToken {
 }
 impl Method for CheckAuthenticationBotToken {
     const TYPE: &'static str = "checkAuthenticationBotToken";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -144,6 +163,7 @@ impl Method for CheckAuthenticationBotToken {
 pub struct LogOut {}
 impl Method for LogOut {
     const TYPE: &'static str = "logOut";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -151,6 +171,7 @@ impl Method for LogOut {
 pub struct Close {}
 impl Method for Close {
     const TYPE: &'static str = "close";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -158,6 +179,7 @@ impl Method for Close {
 pub struct Destroy {}
 impl Method for Destroy {
     const TYPE: &'static str = "destroy";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -168,6 +190,7 @@ pub struct ConfirmQrCodeAuthentication {
 }
 impl Method for ConfirmQrCodeAuthentication {
     const TYPE: &'static str = "confirmQrCodeAuthentication";
+    const RESPONSE_TYPE: Option<&'static str> = Some("session");
     type Response = Session;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -175,6 +198,7 @@ impl Method for ConfirmQrCodeAuthentication {
 pub struct GetCurrentState {}
 impl Method for GetCurrentState {
     const TYPE: &'static str = "getCurrentState";
+    const RESPONSE_TYPE: Option<&'static str> = Some("updates");
     type Response = Updates;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -185,6 +209,7 @@ pub struct SetDatabaseEncryptionKey {
 }
 impl Method for SetDatabaseEncryptionKey {
     const TYPE: &'static str = "setDatabaseEncryptionKey";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -192,6 +217,7 @@ impl Method for SetDatabaseEncryptionKey {
 pub struct GetPasswordState {}
 impl Method for GetPasswordState {
     const TYPE: &'static str = "getPasswordState";
+    const RESPONSE_TYPE: Option<&'static str> = Some("passwordState");
     type Response = PasswordState;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -211,6 +237,7 @@ pub struct SetPassword {
 }
 impl Method for SetPassword {
     const TYPE: &'static str = "setPassword";
+    const RESPONSE_TYPE: Option<&'static str> = Some("passwordState");
     type Response = PasswordState;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -221,6 +248,7 @@ pub struct GetRecoveryEmailAddress {
 }
 impl Method for GetRecoveryEmailAddress {
     const TYPE: &'static str = "getRecoveryEmailAddress";
+    const RESPONSE_TYPE: Option<&'static str> = Some("recoveryEmailAddress");
     type Response = RecoveryEmailAddress;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -233,6 +261,7 @@ pub struct SetRecoveryEmailAddress {
 }
 impl Method for SetRecoveryEmailAddress {
     const TYPE: &'static str = "setRecoveryEmailAddress";
+    const RESPONSE_TYPE: Option<&'static str> = Some("passwordState");
     type Response = PasswordState;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -243,6 +272,7 @@ pub struct CheckRecoveryEmailAddressCode {
 }
 impl Method for CheckRecoveryEmailAddressCode {
     const TYPE: &'static str = "checkRecoveryEmailAddressCode";
+    const RESPONSE_TYPE: Option<&'static str> = Some("passwordState");
     type Response = PasswordState;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -250,6 +280,7 @@ impl Method for CheckRecoveryEmailAddressCode {
 pub struct ResendRecoveryEmailAddressCode {}
 impl Method for ResendRecoveryEmailAddressCode {
     const TYPE: &'static str = "resendRecoveryEmailAddressCode";
+    const RESPONSE_TYPE: Option<&'static str> = Some("passwordState");
     type Response = PasswordState;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -257,6 +288,7 @@ impl Method for ResendRecoveryEmailAddressCode {
 pub struct RequestPasswordRecovery {}
 impl Method for RequestPasswordRecovery {
     const TYPE: &'static str = "requestPasswordRecovery";
+    const RESPONSE_TYPE: Option<&'static str> = Some("emailAddressAuthenticationCodeInfo");
     type Response = EmailAddressAuthenticationCodeInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -267,6 +299,7 @@ pub struct RecoverPassword {
 }
 impl Method for RecoverPassword {
     const TYPE: &'static str = "recoverPassword";
+    const RESPONSE_TYPE: Option<&'static str> = Some("passwordState");
     type Response = PasswordState;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -279,6 +312,7 @@ pub struct CreateTemporaryPassword {
 }
 impl Method for CreateTemporaryPassword {
     const TYPE: &'static str = "createTemporaryPassword";
+    const RESPONSE_TYPE: Option<&'static str> = Some("temporaryPasswordState");
     type Response = TemporaryPasswordState;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -286,6 +320,7 @@ impl Method for CreateTemporaryPassword {
 pub struct GetTemporaryPasswordState {}
 impl Method for GetTemporaryPasswordState {
     const TYPE: &'static str = "getTemporaryPasswordState";
+    const RESPONSE_TYPE: Option<&'static str> = Some("temporaryPasswordState");
     type Response = TemporaryPasswordState;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -293,6 +328,7 @@ impl Method for GetTemporaryPasswordState {
 pub struct GetMe {}
 impl Method for GetMe {
     const TYPE: &'static str = "getMe";
+    const RESPONSE_TYPE: Option<&'static str> = Some("user");
     type Response = User;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -303,6 +339,7 @@ pub struct GetUser {
 }
 impl Method for GetUser {
     const TYPE: &'static str = "getUser";
+    const RESPONSE_TYPE: Option<&'static str> = Some("user");
     type Response = User;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -313,6 +350,7 @@ pub struct GetUserFullInfo {
 }
 impl Method for GetUserFullInfo {
     const TYPE: &'static str = "getUserFullInfo";
+    const RESPONSE_TYPE: Option<&'static str> = Some("userFullInfo");
     type Response = UserFullInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -323,6 +361,7 @@ pub struct GetBasicGroup {
 }
 impl Method for GetBasicGroup {
     const TYPE: &'static str = "getBasicGroup";
+    const RESPONSE_TYPE: Option<&'static str> = Some("basicGroup");
     type Response = BasicGroup;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -333,6 +372,7 @@ pub struct GetBasicGroupFullInfo {
 }
 impl Method for GetBasicGroupFullInfo {
     const TYPE: &'static str = "getBasicGroupFullInfo";
+    const RESPONSE_TYPE: Option<&'static str> = Some("basicGroupFullInfo");
     type Response = BasicGroupFullInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -343,6 +383,7 @@ pub struct GetSupergroup {
 }
 impl Method for GetSupergroup {
     const TYPE: &'static str = "getSupergroup";
+    const RESPONSE_TYPE: Option<&'static str> = Some("supergroup");
     type Response = Supergroup;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -353,6 +394,7 @@ pub struct GetSupergroupFullInfo {
 }
 impl Method for GetSupergroupFullInfo {
     const TYPE: &'static str = "getSupergroupFullInfo";
+    const RESPONSE_TYPE: Option<&'static str> = Some("supergroupFullInfo");
     type Response = SupergroupFullInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -363,6 +405,7 @@ pub struct GetSecretChat {
 }
 impl Method for GetSecretChat {
     const TYPE: &'static str = "getSecretChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("secretChat");
     type Response = SecretChat;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -374,6 +417,7 @@ pub struct GetChat {
 }
 impl Method for GetChat {
     const TYPE: &'static str = "getChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chat");
     type Response = Chat;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -388,6 +432,7 @@ pub struct GetMessage {
 }
 impl Method for GetMessage {
     const TYPE: &'static str = "getMessage";
+    const RESPONSE_TYPE: Option<&'static str> = Some("message");
     type Response = Message;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -402,6 +447,7 @@ pub struct GetMessageLocally {
 }
 impl Method for GetMessageLocally {
     const TYPE: &'static str = "getMessageLocally";
+    const RESPONSE_TYPE: Option<&'static str> = Some("message");
     type Response = Message;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -416,6 +462,7 @@ pub struct GetRepliedMessage {
 }
 impl Method for GetRepliedMessage {
     const TYPE: &'static str = "getRepliedMessage";
+    const RESPONSE_TYPE: Option<&'static str> = Some("message");
     type Response = Message;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -427,6 +474,7 @@ pub struct GetChatPinnedMessage {
 }
 impl Method for GetChatPinnedMessage {
     const TYPE: &'static str = "getChatPinnedMessage";
+    const RESPONSE_TYPE: Option<&'static str> = Some("message");
     type Response = Message;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -440,6 +488,7 @@ pub struct GetMessages {
 }
 impl Method for GetMessages {
     const TYPE: &'static str = "getMessages";
+    const RESPONSE_TYPE: Option<&'static str> = Some("messages");
     type Response = Messages;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -450,6 +499,7 @@ pub struct GetFile {
 }
 impl Method for GetFile {
     const TYPE: &'static str = "getFile";
+    const RESPONSE_TYPE: Option<&'static str> = Some("file");
     type Response = File;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -462,6 +512,7 @@ pub struct GetRemoteFile {
 }
 impl Method for GetRemoteFile {
     const TYPE: &'static str = "getRemoteFile";
+    const RESPONSE_TYPE: Option<&'static str> = Some("file");
     type Response = File;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -480,6 +531,7 @@ pub struct GetChats {
 }
 impl Method for GetChats {
     const TYPE: &'static str = "getChats";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chats");
     type Response = Chats;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -490,6 +542,7 @@ pub struct SearchPublicChat {
 }
 impl Method for SearchPublicChat {
     const TYPE: &'static str = "searchPublicChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chat");
     type Response = Chat;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -500,6 +553,7 @@ pub struct SearchPublicChats {
 }
 impl Method for SearchPublicChats {
     const TYPE: &'static str = "searchPublicChats";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chats");
     type Response = Chats;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -512,6 +566,7 @@ pub struct SearchChats {
 }
 impl Method for SearchChats {
     const TYPE: &'static str = "searchChats";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chats");
     type Response = Chats;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -524,6 +579,7 @@ pub struct SearchChatsOnServer {
 }
 impl Method for SearchChatsOnServer {
     const TYPE: &'static str = "searchChatsOnServer";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chats");
     type Response = Chats;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -534,6 +590,7 @@ pub struct SearchChatsNearby {
 }
 impl Method for SearchChatsNearby {
     const TYPE: &'static str = "searchChatsNearby";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chatsNearby");
     type Response = ChatsNearby;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -546,6 +603,7 @@ pub struct GetTopChats {
 }
 impl Method for GetTopChats {
     const TYPE: &'static str = "getTopChats";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chats");
     type Response = Chats;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -559,6 +617,7 @@ pub struct RemoveTopChat {
 }
 impl Method for RemoveTopChat {
     const TYPE: &'static str = "removeTopChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -570,6 +629,7 @@ pub struct AddRecentlyFoundChat {
 }
 impl Method for AddRecentlyFoundChat {
     const TYPE: &'static str = "addRecentlyFoundChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -581,6 +641,7 @@ pub struct RemoveRecentlyFoundChat {
 }
 impl Method for RemoveRecentlyFoundChat {
     const TYPE: &'static str = "removeRecentlyFoundChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -588,6 +649,7 @@ impl Method for RemoveRecentlyFoundChat {
 pub struct ClearRecentlyFoundChats {}
 impl Method for ClearRecentlyFoundChats {
     const TYPE: &'static str = "clearRecentlyFoundChats";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -601,6 +663,7 @@ pub struct CheckChatUsername {
 }
 impl Method for CheckChatUsername {
     const TYPE: &'static str = "checkChatUsername";
+    const RESPONSE_TYPE: Option<&'static str> = None;
     type Response = CheckChatUsernameResult;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -612,6 +675,7 @@ pub struct GetCreatedPublicChats {
 }
 impl Method for GetCreatedPublicChats {
     const TYPE: &'static str = "getCreatedPublicChats";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chats");
     type Response = Chats;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -623,6 +687,7 @@ pub struct CheckCreatedPublicChatsLimit {
 }
 impl Method for CheckCreatedPublicChatsLimit {
     const TYPE: &'static str = "checkCreatedPublicChatsLimit";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -630,6 +695,7 @@ impl Method for CheckCreatedPublicChatsLimit {
 pub struct GetSuitableDiscussionChats {}
 impl Method for GetSuitableDiscussionChats {
     const TYPE: &'static str = "getSuitableDiscussionChats";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chats");
     type Response = Chats;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -637,6 +703,7 @@ impl Method for GetSuitableDiscussionChats {
 pub struct GetInactiveSupergroupChats {}
 impl Method for GetInactiveSupergroupChats {
     const TYPE: &'static str = "getInactiveSupergroupChats";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chats");
     type Response = Chats;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -652,6 +719,7 @@ pub struct GetGroupsInCommon {
 }
 impl Method for GetGroupsInCommon {
     const TYPE: &'static str = "getGroupsInCommon";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chats");
     type Response = Chats;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -673,6 +741,7 @@ pub struct GetChatHistory {
 }
 impl Method for GetChatHistory {
     const TYPE: &'static str = "getChatHistory";
+    const RESPONSE_TYPE: Option<&'static str> = Some("messages");
     type Response = Messages;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -690,6 +759,7 @@ pub struct DeleteChatHistory {
 }
 impl Method for DeleteChatHistory {
     const TYPE: &'static str = "deleteChatHistory";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -714,13 +784,14 @@ pub struct SearchChatMessages {
 }
 impl Method for SearchChatMessages {
     const TYPE: &'static str = "searchChatMessages";
+    const RESPONSE_TYPE: Option<&'static str> = Some("messages");
     type Response = Messages;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Searches for messages in all chats except secret chats. Returns the results in reverse chronological order (i.e., in order of decreasing (date, chat_id, message_id)).\n For optimal performance the number of returned messages is chosen by the library"]
 pub struct SearchMessages {
     #[doc = "Chat list in which to search messages; pass null to search in all chats regardless of their chat list"]
-    pub chat_list: ChatList,
+    pub chat_list: Option<ChatList>,
     #[doc = "Query to search for"]
     pub query: String,
     #[doc = "The date of the message starting from which the results should be fetched. Use 0 or any date in the future to get results from the last message"]
@@ -736,6 +807,7 @@ pub struct SearchMessages {
 }
 impl Method for SearchMessages {
     const TYPE: &'static str = "searchMessages";
+    const RESPONSE_TYPE: Option<&'static str> = Some("messages");
     type Response = Messages;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -756,6 +828,7 @@ pub struct SearchSecretMessages {
 }
 impl Method for SearchSecretMessages {
     const TYPE: &'static str = "searchSecretMessages";
+    const RESPONSE_TYPE: Option<&'static str> = Some("foundMessages");
     type Response = FoundMessages;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -772,6 +845,7 @@ pub struct SearchCallMessages {
 }
 impl Method for SearchCallMessages {
     const TYPE: &'static str = "searchCallMessages";
+    const RESPONSE_TYPE: Option<&'static str> = Some("messages");
     type Response = Messages;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -785,6 +859,7 @@ pub struct SearchChatRecentLocationMessages {
 }
 impl Method for SearchChatRecentLocationMessages {
     const TYPE: &'static str = "searchChatRecentLocationMessages";
+    const RESPONSE_TYPE: Option<&'static str> = Some("messages");
     type Response = Messages;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -792,6 +867,7 @@ impl Method for SearchChatRecentLocationMessages {
 pub struct GetActiveLiveLocationMessages {}
 impl Method for GetActiveLiveLocationMessages {
     const TYPE: &'static str = "getActiveLiveLocationMessages";
+    const RESPONSE_TYPE: Option<&'static str> = Some("messages");
     type Response = Messages;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -805,6 +881,7 @@ pub struct GetChatMessageByDate {
 }
 impl Method for GetChatMessageByDate {
     const TYPE: &'static str = "getChatMessageByDate";
+    const RESPONSE_TYPE: Option<&'static str> = Some("message");
     type Response = Message;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -821,6 +898,7 @@ pub struct GetChatMessageCount {
 }
 impl Method for GetChatMessageCount {
     const TYPE: &'static str = "getChatMessageCount";
+    const RESPONSE_TYPE: Option<&'static str> = Some("count");
     type Response = Count;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -832,6 +910,7 @@ pub struct GetChatScheduledMessages {
 }
 impl Method for GetChatScheduledMessages {
     const TYPE: &'static str = "getChatScheduledMessages";
+    const RESPONSE_TYPE: Option<&'static str> = Some("messages");
     type Response = Messages;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -844,6 +923,7 @@ pub struct RemoveNotification {
 }
 impl Method for RemoveNotification {
     const TYPE: &'static str = "removeNotification";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -856,6 +936,7 @@ pub struct RemoveNotificationGroup {
 }
 impl Method for RemoveNotificationGroup {
     const TYPE: &'static str = "removeNotificationGroup";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -873,6 +954,7 @@ pub struct GetPublicMessageLink {
 }
 impl Method for GetPublicMessageLink {
     const TYPE: &'static str = "getPublicMessageLink";
+    const RESPONSE_TYPE: Option<&'static str> = Some("publicMessageLink");
     type Response = PublicMessageLink;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -887,6 +969,7 @@ pub struct GetMessageLink {
 }
 impl Method for GetMessageLink {
     const TYPE: &'static str = "getMessageLink";
+    const RESPONSE_TYPE: Option<&'static str> = Some("httpUrl");
     type Response = HttpUrl;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -897,6 +980,7 @@ pub struct GetMessageLinkInfo {
 }
 impl Method for GetMessageLinkInfo {
     const TYPE: &'static str = "getMessageLinkInfo";
+    const RESPONSE_TYPE: Option<&'static str> = Some("messageLinkInfo");
     type Response = MessageLinkInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -917,6 +1001,7 @@ pub struct SendMessage {
 }
 impl Method for SendMessage {
     const TYPE: &'static str = "sendMessage";
+    const RESPONSE_TYPE: Option<&'static str> = Some("message");
     type Response = Message;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -935,6 +1020,7 @@ pub struct SendMessageAlbum {
 }
 impl Method for SendMessageAlbum {
     const TYPE: &'static str = "sendMessageAlbum";
+    const RESPONSE_TYPE: Option<&'static str> = Some("messages");
     type Response = Messages;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -950,6 +1036,7 @@ pub struct SendBotStartMessage {
 }
 impl Method for SendBotStartMessage {
     const TYPE: &'static str = "sendBotStartMessage";
+    const RESPONSE_TYPE: Option<&'static str> = Some("message");
     type Response = Message;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -974,6 +1061,7 @@ pub struct SendInlineQueryResultMessage {
 }
 impl Method for SendInlineQueryResultMessage {
     const TYPE: &'static str = "sendInlineQueryResultMessage";
+    const RESPONSE_TYPE: Option<&'static str> = Some("message");
     type Response = Message;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1001,6 +1089,7 @@ pub struct ForwardMessages {
 }
 impl Method for ForwardMessages {
     const TYPE: &'static str = "forwardMessages";
+    const RESPONSE_TYPE: Option<&'static str> = Some("messages");
     type Response = Messages;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1014,6 +1103,7 @@ pub struct ResendMessages {
 }
 impl Method for ResendMessages {
     const TYPE: &'static str = "resendMessages";
+    const RESPONSE_TYPE: Option<&'static str> = Some("messages");
     type Response = Messages;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1027,6 +1117,7 @@ pub struct SendChatSetTtlMessage {
 }
 impl Method for SendChatSetTtlMessage {
     const TYPE: &'static str = "sendChatSetTtlMessage";
+    const RESPONSE_TYPE: Option<&'static str> = Some("message");
     type Response = Message;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1038,6 +1129,7 @@ pub struct SendChatScreenshotTakenNotification {
 }
 impl Method for SendChatScreenshotTakenNotification {
     const TYPE: &'static str = "sendChatScreenshotTakenNotification";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1059,6 +1151,7 @@ pub struct AddLocalMessage {
 }
 impl Method for AddLocalMessage {
     const TYPE: &'static str = "addLocalMessage";
+    const RESPONSE_TYPE: Option<&'static str> = Some("message");
     type Response = Message;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1075,6 +1168,7 @@ pub struct DeleteMessages {
 }
 impl Method for DeleteMessages {
     const TYPE: &'static str = "deleteMessages";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1088,6 +1182,7 @@ pub struct DeleteChatMessagesFromUser {
 }
 impl Method for DeleteChatMessagesFromUser {
     const TYPE: &'static str = "deleteChatMessagesFromUser";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1106,6 +1201,7 @@ pub struct EditMessageText {
 }
 impl Method for EditMessageText {
     const TYPE: &'static str = "editMessageText";
+    const RESPONSE_TYPE: Option<&'static str> = Some("message");
     type Response = Message;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1124,6 +1220,7 @@ pub struct EditMessageLiveLocation {
 }
 impl Method for EditMessageLiveLocation {
     const TYPE: &'static str = "editMessageLiveLocation";
+    const RESPONSE_TYPE: Option<&'static str> = Some("message");
     type Response = Message;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1142,6 +1239,7 @@ pub struct EditMessageMedia {
 }
 impl Method for EditMessageMedia {
     const TYPE: &'static str = "editMessageMedia";
+    const RESPONSE_TYPE: Option<&'static str> = Some("message");
     type Response = Message;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1160,6 +1258,7 @@ pub struct EditMessageCaption {
 }
 impl Method for EditMessageCaption {
     const TYPE: &'static str = "editMessageCaption";
+    const RESPONSE_TYPE: Option<&'static str> = Some("message");
     type Response = Message;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1176,6 +1275,7 @@ pub struct EditMessageReplyMarkup {
 }
 impl Method for EditMessageReplyMarkup {
     const TYPE: &'static str = "editMessageReplyMarkup";
+    const RESPONSE_TYPE: Option<&'static str> = Some("message");
     type Response = Message;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1190,6 +1290,7 @@ pub struct EditInlineMessageText {
 }
 impl Method for EditInlineMessageText {
     const TYPE: &'static str = "editInlineMessageText";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1204,6 +1305,7 @@ pub struct EditInlineMessageLiveLocation {
 }
 impl Method for EditInlineMessageLiveLocation {
     const TYPE: &'static str = "editInlineMessageLiveLocation";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1218,6 +1320,7 @@ pub struct EditInlineMessageMedia {
 }
 impl Method for EditInlineMessageMedia {
     const TYPE: &'static str = "editInlineMessageMedia";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1232,6 +1335,7 @@ pub struct EditInlineMessageCaption {
 }
 impl Method for EditInlineMessageCaption {
     const TYPE: &'static str = "editInlineMessageCaption";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1244,6 +1348,7 @@ pub struct EditInlineMessageReplyMarkup {
 }
 impl Method for EditInlineMessageReplyMarkup {
     const TYPE: &'static str = "editInlineMessageReplyMarkup";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1256,10 +1361,11 @@ pub struct EditMessageSchedulingState {
     #[serde(deserialize_with = "::serde_aux::field_attributes::deserialize_number_from_string")]
     pub message_id: i64,
     #[doc = "The new message scheduling state. Pass null to send the message immediately"]
-    pub scheduling_state: MessageSchedulingState,
+    pub scheduling_state: Option<MessageSchedulingState>,
 }
 impl Method for EditMessageSchedulingState {
     const TYPE: &'static str = "editMessageSchedulingState";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1270,8 +1376,10 @@ pub struct GetTextEntities {
 }
 impl Method for GetTextEntities {
     const TYPE: &'static str = "getTextEntities";
+    const RESPONSE_TYPE: Option<&'static str> = Some("textEntities");
     type Response = TextEntities;
 }
+impl SyncMethod for GetTextEntities {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Parses Bold, Italic, Underline, Strikethrough, Code, Pre, PreCode, TextUrl and MentionName entities contained in the text. This is an offline method. Can be called before authorization. Can be called synchronously "]
 pub struct ParseTextEntities {
@@ -1282,8 +1390,10 @@ pub struct ParseTextEntities {
 }
 impl Method for ParseTextEntities {
     const TYPE: &'static str = "parseTextEntities";
+    const RESPONSE_TYPE: Option<&'static str> = Some("formattedText");
     type Response = FormattedText;
 }
+impl SyncMethod for ParseTextEntities {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Parses Markdown entities in a human-friendly format, ignoring mark up errors. This is an offline method. Can be called before authorization. Can be called synchronously"]
 pub struct ParseMarkdown {
@@ -1292,8 +1402,10 @@ pub struct ParseMarkdown {
 }
 impl Method for ParseMarkdown {
     const TYPE: &'static str = "parseMarkdown";
+    const RESPONSE_TYPE: Option<&'static str> = Some("formattedText");
     type Response = FormattedText;
 }
+impl SyncMethod for ParseMarkdown {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Replaces text entities with Markdown formatting in a human-friendly format. Entities that can't be represented in Markdown unambiguously are kept as is. This is an offline method. Can be called before authorization. Can be called synchronously "]
 pub struct GetMarkdownText {
@@ -1302,8 +1414,10 @@ pub struct GetMarkdownText {
 }
 impl Method for GetMarkdownText {
     const TYPE: &'static str = "getMarkdownText";
+    const RESPONSE_TYPE: Option<&'static str> = Some("formattedText");
     type Response = FormattedText;
 }
+impl SyncMethod for GetMarkdownText {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Returns the MIME type of a file, guessed by its extension. Returns an empty string on failure. This is an offline method. Can be called before authorization. Can be called synchronously "]
 pub struct GetFileMimeType {
@@ -1312,8 +1426,10 @@ pub struct GetFileMimeType {
 }
 impl Method for GetFileMimeType {
     const TYPE: &'static str = "getFileMimeType";
+    const RESPONSE_TYPE: Option<&'static str> = Some("text");
     type Response = Text;
 }
+impl SyncMethod for GetFileMimeType {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Returns the extension of a file, guessed by its MIME type. Returns an empty string on failure. This is an offline method. Can be called before authorization. Can be called synchronously "]
 pub struct GetFileExtension {
@@ -1322,8 +1438,10 @@ pub struct GetFileExtension {
 }
 impl Method for GetFileExtension {
     const TYPE: &'static str = "getFileExtension";
+    const RESPONSE_TYPE: Option<&'static str> = Some("text");
     type Response = Text;
 }
+impl SyncMethod for GetFileExtension {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Removes potentially dangerous characters from the name of a file. The encoding of the file name is supposed to be UTF-8. Returns an empty string on failure. This is an offline method. Can be called before authorization. Can be called synchronously "]
 pub struct CleanFileName {
@@ -1332,8 +1450,10 @@ pub struct CleanFileName {
 }
 impl Method for CleanFileName {
     const TYPE: &'static str = "cleanFileName";
+    const RESPONSE_TYPE: Option<&'static str> = Some("text");
     type Response = Text;
 }
+impl SyncMethod for CleanFileName {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Returns a string stored in the local database from the specified localization target and language pack by its key. Returns a 404 error if the string is not found. This is an offline method. Can be called before authorization. Can be called synchronously"]
 pub struct GetLanguagePackString {
@@ -1348,8 +1468,10 @@ pub struct GetLanguagePackString {
 }
 impl Method for GetLanguagePackString {
     const TYPE: &'static str = "getLanguagePackString";
+    const RESPONSE_TYPE: Option<&'static str> = None;
     type Response = LanguagePackStringValue;
 }
+impl SyncMethod for GetLanguagePackString {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Converts a JSON-serialized string to corresponding JsonValue object. This is an offline method. Can be called before authorization. Can be called synchronously "]
 pub struct GetJsonValue {
@@ -1358,8 +1480,10 @@ pub struct GetJsonValue {
 }
 impl Method for GetJsonValue {
     const TYPE: &'static str = "getJsonValue";
+    const RESPONSE_TYPE: Option<&'static str> = None;
     type Response = JsonValue;
 }
+impl SyncMethod for GetJsonValue {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Converts a JsonValue object to corresponding JSON-serialized string. This is an offline method. Can be called before authorization. Can be called synchronously "]
 pub struct GetJsonString {
@@ -1368,8 +1492,10 @@ pub struct GetJsonString {
 }
 impl Method for GetJsonString {
     const TYPE: &'static str = "getJsonString";
+    const RESPONSE_TYPE: Option<&'static str> = Some("text");
     type Response = Text;
 }
+impl SyncMethod for GetJsonString {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Changes the user answer to a poll. A poll in quiz mode can be answered only once"]
 pub struct SetPollAnswer {
@@ -1384,6 +1510,7 @@ pub struct SetPollAnswer {
 }
 impl Method for SetPollAnswer {
     const TYPE: &'static str = "setPollAnswer";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1404,6 +1531,7 @@ pub struct GetPollVoters {
 }
 impl Method for GetPollVoters {
     const TYPE: &'static str = "getPollVoters";
+    const RESPONSE_TYPE: Option<&'static str> = Some("users");
     type Response = Users;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1420,6 +1548,7 @@ pub struct StopPoll {
 }
 impl Method for StopPoll {
     const TYPE: &'static str = "stopPoll";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1436,6 +1565,7 @@ pub struct GetLoginUrlInfo {
 }
 impl Method for GetLoginUrlInfo {
     const TYPE: &'static str = "getLoginUrlInfo";
+    const RESPONSE_TYPE: Option<&'static str> = None;
     type Response = LoginUrlInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1455,6 +1585,7 @@ pub struct GetLoginUrl {
 }
 impl Method for GetLoginUrl {
     const TYPE: &'static str = "getLoginUrl";
+    const RESPONSE_TYPE: Option<&'static str> = Some("httpUrl");
     type Response = HttpUrl;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1474,6 +1605,7 @@ pub struct GetInlineQueryResults {
 }
 impl Method for GetInlineQueryResults {
     const TYPE: &'static str = "getInlineQueryResults";
+    const RESPONSE_TYPE: Option<&'static str> = Some("inlineQueryResults");
     type Response = InlineQueryResults;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1498,6 +1630,7 @@ pub struct AnswerInlineQuery {
 }
 impl Method for AnswerInlineQuery {
     const TYPE: &'static str = "answerInlineQuery";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1514,6 +1647,7 @@ pub struct GetCallbackQueryAnswer {
 }
 impl Method for GetCallbackQueryAnswer {
     const TYPE: &'static str = "getCallbackQueryAnswer";
+    const RESPONSE_TYPE: Option<&'static str> = Some("callbackQueryAnswer");
     type Response = CallbackQueryAnswer;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1534,6 +1668,7 @@ pub struct AnswerCallbackQuery {
 }
 impl Method for AnswerCallbackQuery {
     const TYPE: &'static str = "answerCallbackQuery";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1549,6 +1684,7 @@ pub struct AnswerShippingQuery {
 }
 impl Method for AnswerShippingQuery {
     const TYPE: &'static str = "answerShippingQuery";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1562,6 +1698,7 @@ pub struct AnswerPreCheckoutQuery {
 }
 impl Method for AnswerPreCheckoutQuery {
     const TYPE: &'static str = "answerPreCheckoutQuery";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1586,6 +1723,7 @@ pub struct SetGameScore {
 }
 impl Method for SetGameScore {
     const TYPE: &'static str = "setGameScore";
+    const RESPONSE_TYPE: Option<&'static str> = Some("message");
     type Response = Message;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1606,6 +1744,7 @@ pub struct SetInlineGameScore {
 }
 impl Method for SetInlineGameScore {
     const TYPE: &'static str = "setInlineGameScore";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1622,6 +1761,7 @@ pub struct GetGameHighScores {
 }
 impl Method for GetGameHighScores {
     const TYPE: &'static str = "getGameHighScores";
+    const RESPONSE_TYPE: Option<&'static str> = Some("gameHighScores");
     type Response = GameHighScores;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1634,6 +1774,7 @@ pub struct GetInlineGameHighScores {
 }
 impl Method for GetInlineGameHighScores {
     const TYPE: &'static str = "getInlineGameHighScores";
+    const RESPONSE_TYPE: Option<&'static str> = Some("gameHighScores");
     type Response = GameHighScores;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1648,6 +1789,7 @@ pub struct DeleteChatReplyMarkup {
 }
 impl Method for DeleteChatReplyMarkup {
     const TYPE: &'static str = "deleteChatReplyMarkup";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1661,6 +1803,7 @@ pub struct SendChatAction {
 }
 impl Method for SendChatAction {
     const TYPE: &'static str = "sendChatAction";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1672,6 +1815,7 @@ pub struct OpenChat {
 }
 impl Method for OpenChat {
     const TYPE: &'static str = "openChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1683,6 +1827,7 @@ pub struct CloseChat {
 }
 impl Method for CloseChat {
     const TYPE: &'static str = "closeChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1699,6 +1844,7 @@ pub struct ViewMessages {
 }
 impl Method for ViewMessages {
     const TYPE: &'static str = "viewMessages";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1713,6 +1859,7 @@ pub struct OpenMessageContent {
 }
 impl Method for OpenMessageContent {
     const TYPE: &'static str = "openMessageContent";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1724,6 +1871,7 @@ pub struct ReadAllChatMentions {
 }
 impl Method for ReadAllChatMentions {
     const TYPE: &'static str = "readAllChatMentions";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1737,6 +1885,7 @@ pub struct CreatePrivateChat {
 }
 impl Method for CreatePrivateChat {
     const TYPE: &'static str = "createPrivateChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chat");
     type Response = Chat;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1750,6 +1899,7 @@ pub struct CreateBasicGroupChat {
 }
 impl Method for CreateBasicGroupChat {
     const TYPE: &'static str = "createBasicGroupChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chat");
     type Response = Chat;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1763,6 +1913,7 @@ pub struct CreateSupergroupChat {
 }
 impl Method for CreateSupergroupChat {
     const TYPE: &'static str = "createSupergroupChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chat");
     type Response = Chat;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1773,6 +1924,7 @@ pub struct CreateSecretChat {
 }
 impl Method for CreateSecretChat {
     const TYPE: &'static str = "createSecretChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chat");
     type Response = Chat;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1785,6 +1937,7 @@ pub struct CreateNewBasicGroupChat {
 }
 impl Method for CreateNewBasicGroupChat {
     const TYPE: &'static str = "createNewBasicGroupChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chat");
     type Response = Chat;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1802,6 +1955,7 @@ pub struct CreateNewSupergroupChat {
 }
 impl Method for CreateNewSupergroupChat {
     const TYPE: &'static str = "createNewSupergroupChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chat");
     type Response = Chat;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1812,6 +1966,7 @@ pub struct CreateNewSecretChat {
 }
 impl Method for CreateNewSecretChat {
     const TYPE: &'static str = "createNewSecretChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chat");
     type Response = Chat;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1823,6 +1978,7 @@ pub struct UpgradeBasicGroupChatToSupergroupChat {
 }
 impl Method for UpgradeBasicGroupChatToSupergroupChat {
     const TYPE: &'static str = "upgradeBasicGroupChatToSupergroupChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chat");
     type Response = Chat;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1836,6 +1992,7 @@ pub struct SetChatChatList {
 }
 impl Method for SetChatChatList {
     const TYPE: &'static str = "setChatChatList";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1849,6 +2006,7 @@ pub struct SetChatTitle {
 }
 impl Method for SetChatTitle {
     const TYPE: &'static str = "setChatTitle";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1862,6 +2020,7 @@ pub struct SetChatPhoto {
 }
 impl Method for SetChatPhoto {
     const TYPE: &'static str = "setChatPhoto";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1875,6 +2034,7 @@ pub struct SetChatPermissions {
 }
 impl Method for SetChatPermissions {
     const TYPE: &'static str = "setChatPermissions";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1888,6 +2048,7 @@ pub struct SetChatDraftMessage {
 }
 impl Method for SetChatDraftMessage {
     const TYPE: &'static str = "setChatDraftMessage";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1901,6 +2062,7 @@ pub struct SetChatNotificationSettings {
 }
 impl Method for SetChatNotificationSettings {
     const TYPE: &'static str = "setChatNotificationSettings";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1915,6 +2077,7 @@ pub struct ToggleChatIsPinned {
 }
 impl Method for ToggleChatIsPinned {
     const TYPE: &'static str = "toggleChatIsPinned";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1929,6 +2092,7 @@ pub struct ToggleChatIsMarkedAsUnread {
 }
 impl Method for ToggleChatIsMarkedAsUnread {
     const TYPE: &'static str = "toggleChatIsMarkedAsUnread";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1943,6 +2107,7 @@ pub struct ToggleChatDefaultDisableNotification {
 }
 impl Method for ToggleChatDefaultDisableNotification {
     const TYPE: &'static str = "toggleChatDefaultDisableNotification";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1956,6 +2121,7 @@ pub struct SetChatClientData {
 }
 impl Method for SetChatClientData {
     const TYPE: &'static str = "setChatClientData";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1969,6 +2135,7 @@ pub struct SetChatDescription {
 }
 impl Method for SetChatDescription {
     const TYPE: &'static str = "setChatDescription";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1983,6 +2150,7 @@ pub struct SetChatDiscussionGroup {
 }
 impl Method for SetChatDiscussionGroup {
     const TYPE: &'static str = "setChatDiscussionGroup";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1996,6 +2164,7 @@ pub struct SetChatLocation {
 }
 impl Method for SetChatLocation {
     const TYPE: &'static str = "setChatLocation";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2009,6 +2178,7 @@ pub struct SetChatSlowModeDelay {
 }
 impl Method for SetChatSlowModeDelay {
     const TYPE: &'static str = "setChatSlowModeDelay";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2026,6 +2196,7 @@ pub struct PinChatMessage {
 }
 impl Method for PinChatMessage {
     const TYPE: &'static str = "pinChatMessage";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2037,6 +2208,7 @@ pub struct UnpinChatMessage {
 }
 impl Method for UnpinChatMessage {
     const TYPE: &'static str = "unpinChatMessage";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2048,6 +2220,7 @@ pub struct JoinChat {
 }
 impl Method for JoinChat {
     const TYPE: &'static str = "joinChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2059,6 +2232,7 @@ pub struct LeaveChat {
 }
 impl Method for LeaveChat {
     const TYPE: &'static str = "leaveChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2074,6 +2248,7 @@ pub struct AddChatMember {
 }
 impl Method for AddChatMember {
     const TYPE: &'static str = "addChatMember";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2087,6 +2262,7 @@ pub struct AddChatMembers {
 }
 impl Method for AddChatMembers {
     const TYPE: &'static str = "addChatMembers";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2102,6 +2278,7 @@ pub struct SetChatMemberStatus {
 }
 impl Method for SetChatMemberStatus {
     const TYPE: &'static str = "setChatMemberStatus";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2109,6 +2286,7 @@ impl Method for SetChatMemberStatus {
 pub struct CanTransferOwnership {}
 impl Method for CanTransferOwnership {
     const TYPE: &'static str = "canTransferOwnership";
+    const RESPONSE_TYPE: Option<&'static str> = None;
     type Response = CanTransferOwnershipResult;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2124,6 +2302,7 @@ pub struct TransferChatOwnership {
 }
 impl Method for TransferChatOwnership {
     const TYPE: &'static str = "transferChatOwnership";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2137,6 +2316,7 @@ pub struct GetChatMember {
 }
 impl Method for GetChatMember {
     const TYPE: &'static str = "getChatMember";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chatMember");
     type Response = ChatMember;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2154,6 +2334,7 @@ pub struct SearchChatMembers {
 }
 impl Method for SearchChatMembers {
     const TYPE: &'static str = "searchChatMembers";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chatMembers");
     type Response = ChatMembers;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2165,6 +2346,7 @@ pub struct GetChatAdministrators {
 }
 impl Method for GetChatAdministrators {
     const TYPE: &'static str = "getChatAdministrators";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chatAdministrators");
     type Response = ChatAdministrators;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2176,6 +2358,7 @@ pub struct ClearAllDraftMessages {
 }
 impl Method for ClearAllDraftMessages {
     const TYPE: &'static str = "clearAllDraftMessages";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2189,6 +2372,7 @@ pub struct GetChatNotificationSettingsExceptions {
 }
 impl Method for GetChatNotificationSettingsExceptions {
     const TYPE: &'static str = "getChatNotificationSettingsExceptions";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chats");
     type Response = Chats;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2199,6 +2383,7 @@ pub struct GetScopeNotificationSettings {
 }
 impl Method for GetScopeNotificationSettings {
     const TYPE: &'static str = "getScopeNotificationSettings";
+    const RESPONSE_TYPE: Option<&'static str> = Some("scopeNotificationSettings");
     type Response = ScopeNotificationSettings;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2211,6 +2396,7 @@ pub struct SetScopeNotificationSettings {
 }
 impl Method for SetScopeNotificationSettings {
     const TYPE: &'static str = "setScopeNotificationSettings";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2218,6 +2404,7 @@ impl Method for SetScopeNotificationSettings {
 pub struct ResetAllNotificationSettings {}
 impl Method for ResetAllNotificationSettings {
     const TYPE: &'static str = "resetAllNotificationSettings";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2230,6 +2417,7 @@ pub struct SetPinnedChats {
 }
 impl Method for SetPinnedChats {
     const TYPE: &'static str = "setPinnedChats";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2249,6 +2437,7 @@ pub struct DownloadFile {
 }
 impl Method for DownloadFile {
     const TYPE: &'static str = "downloadFile";
+    const RESPONSE_TYPE: Option<&'static str> = Some("file");
     type Response = File;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2261,6 +2450,7 @@ pub struct GetFileDownloadedPrefixSize {
 }
 impl Method for GetFileDownloadedPrefixSize {
     const TYPE: &'static str = "getFileDownloadedPrefixSize";
+    const RESPONSE_TYPE: Option<&'static str> = Some("count");
     type Response = Count;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2274,6 +2464,7 @@ pub struct CancelDownloadFile {
 }
 impl Method for CancelDownloadFile {
     const TYPE: &'static str = "cancelDownloadFile";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2288,6 +2479,7 @@ pub struct UploadFile {
 }
 impl Method for UploadFile {
     const TYPE: &'static str = "uploadFile";
+    const RESPONSE_TYPE: Option<&'static str> = Some("file");
     type Response = File;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2298,6 +2490,7 @@ pub struct CancelUploadFile {
 }
 impl Method for CancelUploadFile {
     const TYPE: &'static str = "cancelUploadFile";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2313,6 +2506,7 @@ pub struct WriteGeneratedFilePart {
 }
 impl Method for WriteGeneratedFilePart {
     const TYPE: &'static str = "writeGeneratedFilePart";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2328,6 +2522,7 @@ pub struct SetFileGenerationProgress {
 }
 impl Method for SetFileGenerationProgress {
     const TYPE: &'static str = "setFileGenerationProgress";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2337,10 +2532,11 @@ pub struct FinishFileGeneration {
     #[serde(deserialize_with = "::serde_aux::field_attributes::deserialize_number_from_string")]
     pub generation_id: i64,
     #[doc = "If set, means that file generation has failed and should be terminated"]
-    pub error: Error,
+    pub error: Option<Error>,
 }
 impl Method for FinishFileGeneration {
     const TYPE: &'static str = "finishFileGeneration";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2355,6 +2551,7 @@ pub struct ReadFilePart {
 }
 impl Method for ReadFilePart {
     const TYPE: &'static str = "readFilePart";
+    const RESPONSE_TYPE: Option<&'static str> = Some("filePart");
     type Response = FilePart;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2365,6 +2562,7 @@ pub struct DeleteFile {
 }
 impl Method for DeleteFile {
     const TYPE: &'static str = "deleteFile";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2376,6 +2574,7 @@ pub struct GenerateChatInviteLink {
 }
 impl Method for GenerateChatInviteLink {
     const TYPE: &'static str = "generateChatInviteLink";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chatInviteLink");
     type Response = ChatInviteLink;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2386,6 +2585,7 @@ pub struct CheckChatInviteLink {
 }
 impl Method for CheckChatInviteLink {
     const TYPE: &'static str = "checkChatInviteLink";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chatInviteLinkInfo");
     type Response = ChatInviteLinkInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2396,6 +2596,7 @@ pub struct JoinChatByInviteLink {
 }
 impl Method for JoinChatByInviteLink {
     const TYPE: &'static str = "joinChatByInviteLink";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chat");
     type Response = Chat;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2408,6 +2609,7 @@ pub struct CreateCall {
 }
 impl Method for CreateCall {
     const TYPE: &'static str = "createCall";
+    const RESPONSE_TYPE: Option<&'static str> = Some("callId");
     type Response = CallId;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2420,6 +2622,7 @@ pub struct AcceptCall {
 }
 impl Method for AcceptCall {
     const TYPE: &'static str = "acceptCall";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2438,6 +2641,7 @@ pub struct DiscardCall {
 }
 impl Method for DiscardCall {
     const TYPE: &'static str = "discardCall";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2454,6 +2658,7 @@ pub struct SendCallRating {
 }
 impl Method for SendCallRating {
     const TYPE: &'static str = "sendCallRating";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2466,6 +2671,7 @@ pub struct SendCallDebugInformation {
 }
 impl Method for SendCallDebugInformation {
     const TYPE: &'static str = "sendCallDebugInformation";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2476,6 +2682,7 @@ pub struct BlockUser {
 }
 impl Method for BlockUser {
     const TYPE: &'static str = "blockUser";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2486,6 +2693,7 @@ pub struct UnblockUser {
 }
 impl Method for UnblockUser {
     const TYPE: &'static str = "unblockUser";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2498,6 +2706,7 @@ pub struct GetBlockedUsers {
 }
 impl Method for GetBlockedUsers {
     const TYPE: &'static str = "getBlockedUsers";
+    const RESPONSE_TYPE: Option<&'static str> = Some("users");
     type Response = Users;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2511,6 +2720,7 @@ pub struct AddContact {
 }
 impl Method for AddContact {
     const TYPE: &'static str = "addContact";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2521,6 +2731,7 @@ pub struct ImportContacts {
 }
 impl Method for ImportContacts {
     const TYPE: &'static str = "importContacts";
+    const RESPONSE_TYPE: Option<&'static str> = Some("importedContacts");
     type Response = ImportedContacts;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2528,6 +2739,7 @@ impl Method for ImportContacts {
 pub struct GetContacts {}
 impl Method for GetContacts {
     const TYPE: &'static str = "getContacts";
+    const RESPONSE_TYPE: Option<&'static str> = Some("users");
     type Response = Users;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2540,6 +2752,7 @@ pub struct SearchContacts {
 }
 impl Method for SearchContacts {
     const TYPE: &'static str = "searchContacts";
+    const RESPONSE_TYPE: Option<&'static str> = Some("users");
     type Response = Users;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2550,6 +2763,7 @@ pub struct RemoveContacts {
 }
 impl Method for RemoveContacts {
     const TYPE: &'static str = "removeContacts";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2557,6 +2771,7 @@ impl Method for RemoveContacts {
 pub struct GetImportedContactCount {}
 impl Method for GetImportedContactCount {
     const TYPE: &'static str = "getImportedContactCount";
+    const RESPONSE_TYPE: Option<&'static str> = Some("count");
     type Response = Count;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2567,6 +2782,7 @@ pub struct ChangeImportedContacts {
 }
 impl Method for ChangeImportedContacts {
     const TYPE: &'static str = "changeImportedContacts";
+    const RESPONSE_TYPE: Option<&'static str> = Some("importedContacts");
     type Response = ImportedContacts;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2574,6 +2790,7 @@ impl Method for ChangeImportedContacts {
 pub struct ClearImportedContacts {}
 impl Method for ClearImportedContacts {
     const TYPE: &'static str = "clearImportedContacts";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2584,6 +2801,7 @@ pub struct SharePhoneNumber {
 }
 impl Method for SharePhoneNumber {
     const TYPE: &'static str = "sharePhoneNumber";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2598,6 +2816,7 @@ pub struct GetUserProfilePhotos {
 }
 impl Method for GetUserProfilePhotos {
     const TYPE: &'static str = "getUserProfilePhotos";
+    const RESPONSE_TYPE: Option<&'static str> = Some("userProfilePhotos");
     type Response = UserProfilePhotos;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2610,6 +2829,7 @@ pub struct GetStickers {
 }
 impl Method for GetStickers {
     const TYPE: &'static str = "getStickers";
+    const RESPONSE_TYPE: Option<&'static str> = Some("stickers");
     type Response = Stickers;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2622,6 +2842,7 @@ pub struct SearchStickers {
 }
 impl Method for SearchStickers {
     const TYPE: &'static str = "searchStickers";
+    const RESPONSE_TYPE: Option<&'static str> = Some("stickers");
     type Response = Stickers;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2633,6 +2854,7 @@ pub struct GetInstalledStickerSets {
 }
 impl Method for GetInstalledStickerSets {
     const TYPE: &'static str = "getInstalledStickerSets";
+    const RESPONSE_TYPE: Option<&'static str> = Some("stickerSets");
     type Response = StickerSets;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2649,6 +2871,7 @@ pub struct GetArchivedStickerSets {
 }
 impl Method for GetArchivedStickerSets {
     const TYPE: &'static str = "getArchivedStickerSets";
+    const RESPONSE_TYPE: Option<&'static str> = Some("stickerSets");
     type Response = StickerSets;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2661,6 +2884,7 @@ pub struct GetTrendingStickerSets {
 }
 impl Method for GetTrendingStickerSets {
     const TYPE: &'static str = "getTrendingStickerSets";
+    const RESPONSE_TYPE: Option<&'static str> = Some("stickerSets");
     type Response = StickerSets;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2671,6 +2895,7 @@ pub struct GetAttachedStickerSets {
 }
 impl Method for GetAttachedStickerSets {
     const TYPE: &'static str = "getAttachedStickerSets";
+    const RESPONSE_TYPE: Option<&'static str> = Some("stickerSets");
     type Response = StickerSets;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2682,6 +2907,7 @@ pub struct GetStickerSet {
 }
 impl Method for GetStickerSet {
     const TYPE: &'static str = "getStickerSet";
+    const RESPONSE_TYPE: Option<&'static str> = Some("stickerSet");
     type Response = StickerSet;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2692,6 +2918,7 @@ pub struct SearchStickerSet {
 }
 impl Method for SearchStickerSet {
     const TYPE: &'static str = "searchStickerSet";
+    const RESPONSE_TYPE: Option<&'static str> = Some("stickerSet");
     type Response = StickerSet;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2707,6 +2934,7 @@ pub struct SearchInstalledStickerSets {
 }
 impl Method for SearchInstalledStickerSets {
     const TYPE: &'static str = "searchInstalledStickerSets";
+    const RESPONSE_TYPE: Option<&'static str> = Some("stickerSets");
     type Response = StickerSets;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2717,6 +2945,7 @@ pub struct SearchStickerSets {
 }
 impl Method for SearchStickerSets {
     const TYPE: &'static str = "searchStickerSets";
+    const RESPONSE_TYPE: Option<&'static str> = Some("stickerSets");
     type Response = StickerSets;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2734,6 +2963,7 @@ pub struct ChangeStickerSet {
 }
 impl Method for ChangeStickerSet {
     const TYPE: &'static str = "changeStickerSet";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2744,6 +2974,7 @@ pub struct ViewTrendingStickerSets {
 }
 impl Method for ViewTrendingStickerSets {
     const TYPE: &'static str = "viewTrendingStickerSets";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2757,6 +2988,7 @@ pub struct ReorderInstalledStickerSets {
 }
 impl Method for ReorderInstalledStickerSets {
     const TYPE: &'static str = "reorderInstalledStickerSets";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2768,6 +3000,7 @@ pub struct GetRecentStickers {
 }
 impl Method for GetRecentStickers {
     const TYPE: &'static str = "getRecentStickers";
+    const RESPONSE_TYPE: Option<&'static str> = Some("stickers");
     type Response = Stickers;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2781,6 +3014,7 @@ pub struct AddRecentSticker {
 }
 impl Method for AddRecentSticker {
     const TYPE: &'static str = "addRecentSticker";
+    const RESPONSE_TYPE: Option<&'static str> = Some("stickers");
     type Response = Stickers;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2794,6 +3028,7 @@ pub struct RemoveRecentSticker {
 }
 impl Method for RemoveRecentSticker {
     const TYPE: &'static str = "removeRecentSticker";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2805,6 +3040,7 @@ pub struct ClearRecentStickers {
 }
 impl Method for ClearRecentStickers {
     const TYPE: &'static str = "clearRecentStickers";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2812,6 +3048,7 @@ impl Method for ClearRecentStickers {
 pub struct GetFavoriteStickers {}
 impl Method for GetFavoriteStickers {
     const TYPE: &'static str = "getFavoriteStickers";
+    const RESPONSE_TYPE: Option<&'static str> = Some("stickers");
     type Response = Stickers;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2822,6 +3059,7 @@ pub struct AddFavoriteSticker {
 }
 impl Method for AddFavoriteSticker {
     const TYPE: &'static str = "addFavoriteSticker";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2832,6 +3070,7 @@ pub struct RemoveFavoriteSticker {
 }
 impl Method for RemoveFavoriteSticker {
     const TYPE: &'static str = "removeFavoriteSticker";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2842,6 +3081,7 @@ pub struct GetStickerEmojis {
 }
 impl Method for GetStickerEmojis {
     const TYPE: &'static str = "getStickerEmojis";
+    const RESPONSE_TYPE: Option<&'static str> = Some("emojis");
     type Response = Emojis;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2857,6 +3097,7 @@ pub struct SearchEmojis {
 }
 impl Method for SearchEmojis {
     const TYPE: &'static str = "searchEmojis";
+    const RESPONSE_TYPE: Option<&'static str> = Some("emojis");
     type Response = Emojis;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2867,6 +3108,7 @@ pub struct GetEmojiSuggestionsUrl {
 }
 impl Method for GetEmojiSuggestionsUrl {
     const TYPE: &'static str = "getEmojiSuggestionsUrl";
+    const RESPONSE_TYPE: Option<&'static str> = Some("httpUrl");
     type Response = HttpUrl;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2874,6 +3116,7 @@ impl Method for GetEmojiSuggestionsUrl {
 pub struct GetSavedAnimations {}
 impl Method for GetSavedAnimations {
     const TYPE: &'static str = "getSavedAnimations";
+    const RESPONSE_TYPE: Option<&'static str> = Some("animations");
     type Response = Animations;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2884,6 +3127,7 @@ pub struct AddSavedAnimation {
 }
 impl Method for AddSavedAnimation {
     const TYPE: &'static str = "addSavedAnimation";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2894,6 +3138,7 @@ pub struct RemoveSavedAnimation {
 }
 impl Method for RemoveSavedAnimation {
     const TYPE: &'static str = "removeSavedAnimation";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2901,6 +3146,7 @@ impl Method for RemoveSavedAnimation {
 pub struct GetRecentInlineBots {}
 impl Method for GetRecentInlineBots {
     const TYPE: &'static str = "getRecentInlineBots";
+    const RESPONSE_TYPE: Option<&'static str> = Some("users");
     type Response = Users;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2913,6 +3159,7 @@ pub struct SearchHashtags {
 }
 impl Method for SearchHashtags {
     const TYPE: &'static str = "searchHashtags";
+    const RESPONSE_TYPE: Option<&'static str> = Some("hashtags");
     type Response = Hashtags;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2923,6 +3170,7 @@ pub struct RemoveRecentHashtag {
 }
 impl Method for RemoveRecentHashtag {
     const TYPE: &'static str = "removeRecentHashtag";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2933,6 +3181,7 @@ pub struct GetWebPagePreview {
 }
 impl Method for GetWebPagePreview {
     const TYPE: &'static str = "getWebPagePreview";
+    const RESPONSE_TYPE: Option<&'static str> = Some("webPage");
     type Response = WebPage;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2946,6 +3195,7 @@ pub struct GetWebPageInstantView {
 }
 impl Method for GetWebPageInstantView {
     const TYPE: &'static str = "getWebPageInstantView";
+    const RESPONSE_TYPE: Option<&'static str> = Some("webPageInstantView");
     type Response = WebPageInstantView;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2956,6 +3206,7 @@ pub struct SetProfilePhoto {
 }
 impl Method for SetProfilePhoto {
     const TYPE: &'static str = "setProfilePhoto";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2967,6 +3218,7 @@ pub struct DeleteProfilePhoto {
 }
 impl Method for DeleteProfilePhoto {
     const TYPE: &'static str = "deleteProfilePhoto";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2979,6 +3231,7 @@ pub struct SetName {
 }
 impl Method for SetName {
     const TYPE: &'static str = "setName";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2989,6 +3242,7 @@ pub struct SetBio {
 }
 impl Method for SetBio {
     const TYPE: &'static str = "setBio";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2999,6 +3253,7 @@ pub struct SetUsername {
 }
 impl Method for SetUsername {
     const TYPE: &'static str = "setUsername";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3009,6 +3264,7 @@ pub struct SetLocation {
 }
 impl Method for SetLocation {
     const TYPE: &'static str = "setLocation";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3021,6 +3277,7 @@ pub struct ChangePhoneNumber {
 }
 impl Method for ChangePhoneNumber {
     const TYPE: &'static str = "changePhoneNumber";
+    const RESPONSE_TYPE: Option<&'static str> = Some("authenticationCodeInfo");
     type Response = AuthenticationCodeInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3028,6 +3285,7 @@ impl Method for ChangePhoneNumber {
 pub struct ResendChangePhoneNumberCode {}
 impl Method for ResendChangePhoneNumberCode {
     const TYPE: &'static str = "resendChangePhoneNumberCode";
+    const RESPONSE_TYPE: Option<&'static str> = Some("authenticationCodeInfo");
     type Response = AuthenticationCodeInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3038,6 +3296,7 @@ pub struct CheckChangePhoneNumberCode {
 }
 impl Method for CheckChangePhoneNumberCode {
     const TYPE: &'static str = "checkChangePhoneNumberCode";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3048,6 +3307,7 @@ pub struct SetCommands {
 }
 impl Method for SetCommands {
     const TYPE: &'static str = "setCommands";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3055,6 +3315,7 @@ impl Method for SetCommands {
 pub struct GetActiveSessions {}
 impl Method for GetActiveSessions {
     const TYPE: &'static str = "getActiveSessions";
+    const RESPONSE_TYPE: Option<&'static str> = Some("sessions");
     type Response = Sessions;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3066,6 +3327,7 @@ pub struct TerminateSession {
 }
 impl Method for TerminateSession {
     const TYPE: &'static str = "terminateSession";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3073,6 +3335,7 @@ impl Method for TerminateSession {
 pub struct TerminateAllOtherSessions {}
 impl Method for TerminateAllOtherSessions {
     const TYPE: &'static str = "terminateAllOtherSessions";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3080,6 +3343,7 @@ impl Method for TerminateAllOtherSessions {
 pub struct GetConnectedWebsites {}
 impl Method for GetConnectedWebsites {
     const TYPE: &'static str = "getConnectedWebsites";
+    const RESPONSE_TYPE: Option<&'static str> = Some("connectedWebsites");
     type Response = ConnectedWebsites;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3091,6 +3355,7 @@ pub struct DisconnectWebsite {
 }
 impl Method for DisconnectWebsite {
     const TYPE: &'static str = "disconnectWebsite";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3098,6 +3363,7 @@ impl Method for DisconnectWebsite {
 pub struct DisconnectAllWebsites {}
 impl Method for DisconnectAllWebsites {
     const TYPE: &'static str = "disconnectAllWebsites";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3110,6 +3376,7 @@ pub struct SetSupergroupUsername {
 }
 impl Method for SetSupergroupUsername {
     const TYPE: &'static str = "setSupergroupUsername";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3123,6 +3390,7 @@ pub struct SetSupergroupStickerSet {
 }
 impl Method for SetSupergroupStickerSet {
     const TYPE: &'static str = "setSupergroupStickerSet";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3136,6 +3404,7 @@ pub struct ToggleSupergroupSignMessages {
 }
 impl Method for ToggleSupergroupSignMessages {
     const TYPE: &'static str = "toggleSupergroupSignMessages";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3149,6 +3418,7 @@ pub struct ToggleSupergroupIsAllHistoryAvailable {
 }
 impl Method for ToggleSupergroupIsAllHistoryAvailable {
     const TYPE: &'static str = "toggleSupergroupIsAllHistoryAvailable";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3163,6 +3433,7 @@ pub struct ReportSupergroupSpam {
 }
 impl Method for ReportSupergroupSpam {
     const TYPE: &'static str = "reportSupergroupSpam";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3179,6 +3450,7 @@ pub struct GetSupergroupMembers {
 }
 impl Method for GetSupergroupMembers {
     const TYPE: &'static str = "getSupergroupMembers";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chatMembers");
     type Response = ChatMembers;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3189,6 +3461,7 @@ pub struct DeleteSupergroup {
 }
 impl Method for DeleteSupergroup {
     const TYPE: &'static str = "deleteSupergroup";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3199,6 +3472,7 @@ pub struct CloseSecretChat {
 }
 impl Method for CloseSecretChat {
     const TYPE: &'static str = "closeSecretChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3221,6 +3495,7 @@ pub struct GetChatEventLog {
 }
 impl Method for GetChatEventLog {
     const TYPE: &'static str = "getChatEventLog";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chatEvents");
     type Response = ChatEvents;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3235,6 +3510,7 @@ pub struct GetPaymentForm {
 }
 impl Method for GetPaymentForm {
     const TYPE: &'static str = "getPaymentForm";
+    const RESPONSE_TYPE: Option<&'static str> = Some("paymentForm");
     type Response = PaymentForm;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3254,6 +3530,7 @@ pub struct ValidateOrderInfo {
 }
 impl Method for ValidateOrderInfo {
     const TYPE: &'static str = "validateOrderInfo";
+    const RESPONSE_TYPE: Option<&'static str> = Some("validatedOrderInfo");
     type Response = ValidatedOrderInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3274,6 +3551,7 @@ pub struct SendPaymentForm {
 }
 impl Method for SendPaymentForm {
     const TYPE: &'static str = "sendPaymentForm";
+    const RESPONSE_TYPE: Option<&'static str> = Some("paymentResult");
     type Response = PaymentResult;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3288,6 +3566,7 @@ pub struct GetPaymentReceipt {
 }
 impl Method for GetPaymentReceipt {
     const TYPE: &'static str = "getPaymentReceipt";
+    const RESPONSE_TYPE: Option<&'static str> = Some("paymentReceipt");
     type Response = PaymentReceipt;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3295,6 +3574,7 @@ impl Method for GetPaymentReceipt {
 pub struct GetSavedOrderInfo {}
 impl Method for GetSavedOrderInfo {
     const TYPE: &'static str = "getSavedOrderInfo";
+    const RESPONSE_TYPE: Option<&'static str> = Some("orderInfo");
     type Response = OrderInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3302,6 +3582,7 @@ impl Method for GetSavedOrderInfo {
 pub struct DeleteSavedOrderInfo {}
 impl Method for DeleteSavedOrderInfo {
     const TYPE: &'static str = "deleteSavedOrderInfo";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3309,6 +3590,7 @@ impl Method for DeleteSavedOrderInfo {
 pub struct DeleteSavedCredentials {}
 impl Method for DeleteSavedCredentials {
     const TYPE: &'static str = "deleteSavedCredentials";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3316,6 +3598,7 @@ impl Method for DeleteSavedCredentials {
 pub struct GetSupportUser {}
 impl Method for GetSupportUser {
     const TYPE: &'static str = "getSupportUser";
+    const RESPONSE_TYPE: Option<&'static str> = Some("user");
     type Response = User;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3327,6 +3610,7 @@ pub struct GetBackgrounds {
 }
 impl Method for GetBackgrounds {
     const TYPE: &'static str = "getBackgrounds";
+    const RESPONSE_TYPE: Option<&'static str> = Some("backgrounds");
     type Response = Backgrounds;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3340,6 +3624,7 @@ pub struct GetBackgroundUrl {
 }
 impl Method for GetBackgroundUrl {
     const TYPE: &'static str = "getBackgroundUrl";
+    const RESPONSE_TYPE: Option<&'static str> = Some("httpUrl");
     type Response = HttpUrl;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3350,6 +3635,7 @@ pub struct SearchBackground {
 }
 impl Method for SearchBackground {
     const TYPE: &'static str = "searchBackground";
+    const RESPONSE_TYPE: Option<&'static str> = Some("background");
     type Response = Background;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3366,6 +3652,7 @@ pub struct SetBackground {
 }
 impl Method for SetBackground {
     const TYPE: &'static str = "setBackground";
+    const RESPONSE_TYPE: Option<&'static str> = Some("background");
     type Response = Background;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3377,6 +3664,7 @@ pub struct RemoveBackground {
 }
 impl Method for RemoveBackground {
     const TYPE: &'static str = "removeBackground";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3384,6 +3672,7 @@ impl Method for RemoveBackground {
 pub struct ResetBackgrounds {}
 impl Method for ResetBackgrounds {
     const TYPE: &'static str = "resetBackgrounds";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3395,6 +3684,7 @@ pub struct GetLocalizationTargetInfo {
 }
 impl Method for GetLocalizationTargetInfo {
     const TYPE: &'static str = "getLocalizationTargetInfo";
+    const RESPONSE_TYPE: Option<&'static str> = Some("localizationTargetInfo");
     type Response = LocalizationTargetInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3405,6 +3695,7 @@ pub struct GetLanguagePackInfo {
 }
 impl Method for GetLanguagePackInfo {
     const TYPE: &'static str = "getLanguagePackInfo";
+    const RESPONSE_TYPE: Option<&'static str> = Some("languagePackInfo");
     type Response = LanguagePackInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3417,6 +3708,7 @@ pub struct GetLanguagePackStrings {
 }
 impl Method for GetLanguagePackStrings {
     const TYPE: &'static str = "getLanguagePackStrings";
+    const RESPONSE_TYPE: Option<&'static str> = Some("languagePackStrings");
     type Response = LanguagePackStrings;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3427,6 +3719,7 @@ pub struct SynchronizeLanguagePack {
 }
 impl Method for SynchronizeLanguagePack {
     const TYPE: &'static str = "synchronizeLanguagePack";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3437,6 +3730,7 @@ pub struct AddCustomServerLanguagePack {
 }
 impl Method for AddCustomServerLanguagePack {
     const TYPE: &'static str = "addCustomServerLanguagePack";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3449,6 +3743,7 @@ pub struct SetCustomLanguagePack {
 }
 impl Method for SetCustomLanguagePack {
     const TYPE: &'static str = "setCustomLanguagePack";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3459,6 +3754,7 @@ pub struct EditCustomLanguagePackInfo {
 }
 impl Method for EditCustomLanguagePackInfo {
     const TYPE: &'static str = "editCustomLanguagePackInfo";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3471,6 +3767,7 @@ pub struct SetCustomLanguagePackString {
 }
 impl Method for SetCustomLanguagePackString {
     const TYPE: &'static str = "setCustomLanguagePackString";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3481,6 +3778,7 @@ pub struct DeleteLanguagePack {
 }
 impl Method for DeleteLanguagePack {
     const TYPE: &'static str = "deleteLanguagePack";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3493,6 +3791,7 @@ pub struct RegisterDevice {
 }
 impl Method for RegisterDevice {
     const TYPE: &'static str = "registerDevice";
+    const RESPONSE_TYPE: Option<&'static str> = Some("pushReceiverId");
     type Response = PushReceiverId;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3503,6 +3802,7 @@ pub struct ProcessPushNotification {
 }
 impl Method for ProcessPushNotification {
     const TYPE: &'static str = "processPushNotification";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3513,8 +3813,10 @@ pub struct GetPushReceiverId {
 }
 impl Method for GetPushReceiverId {
     const TYPE: &'static str = "getPushReceiverId";
+    const RESPONSE_TYPE: Option<&'static str> = Some("pushReceiverId");
     type Response = PushReceiverId;
 }
+impl SyncMethod for GetPushReceiverId {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Returns t.me URLs recently visited by a newly registered user "]
 pub struct GetRecentlyVisitedTMeUrls {
@@ -3523,6 +3825,7 @@ pub struct GetRecentlyVisitedTMeUrls {
 }
 impl Method for GetRecentlyVisitedTMeUrls {
     const TYPE: &'static str = "getRecentlyVisitedTMeUrls";
+    const RESPONSE_TYPE: Option<&'static str> = Some("tMeUrls");
     type Response = TMeUrls;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3535,6 +3838,7 @@ pub struct SetUserPrivacySettingRules {
 }
 impl Method for SetUserPrivacySettingRules {
     const TYPE: &'static str = "setUserPrivacySettingRules";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3545,6 +3849,7 @@ pub struct GetUserPrivacySettingRules {
 }
 impl Method for GetUserPrivacySettingRules {
     const TYPE: &'static str = "getUserPrivacySettingRules";
+    const RESPONSE_TYPE: Option<&'static str> = Some("userPrivacySettingRules");
     type Response = UserPrivacySettingRules;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3555,6 +3860,7 @@ pub struct GetOption {
 }
 impl Method for GetOption {
     const TYPE: &'static str = "getOption";
+    const RESPONSE_TYPE: Option<&'static str> = None;
     type Response = OptionValue;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3567,6 +3873,7 @@ pub struct SetOption {
 }
 impl Method for SetOption {
     const TYPE: &'static str = "setOption";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3577,6 +3884,7 @@ pub struct SetAccountTtl {
 }
 impl Method for SetAccountTtl {
     const TYPE: &'static str = "setAccountTtl";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3584,6 +3892,7 @@ impl Method for SetAccountTtl {
 pub struct GetAccountTtl {}
 impl Method for GetAccountTtl {
     const TYPE: &'static str = "getAccountTtl";
+    const RESPONSE_TYPE: Option<&'static str> = Some("accountTtl");
     type Response = AccountTtl;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3594,6 +3903,7 @@ pub struct DeleteAccount {
 }
 impl Method for DeleteAccount {
     const TYPE: &'static str = "deleteAccount";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3605,6 +3915,7 @@ pub struct RemoveChatActionBar {
 }
 impl Method for RemoveChatActionBar {
     const TYPE: &'static str = "removeChatActionBar";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3620,6 +3931,7 @@ pub struct ReportChat {
 }
 impl Method for ReportChat {
     const TYPE: &'static str = "reportChat";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3636,6 +3948,7 @@ pub struct GetChatStatisticsUrl {
 }
 impl Method for GetChatStatisticsUrl {
     const TYPE: &'static str = "getChatStatisticsUrl";
+    const RESPONSE_TYPE: Option<&'static str> = Some("httpUrl");
     type Response = HttpUrl;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3650,6 +3963,7 @@ pub struct GetChatStatistics {
 }
 impl Method for GetChatStatistics {
     const TYPE: &'static str = "getChatStatistics";
+    const RESPONSE_TYPE: Option<&'static str> = Some("chatStatistics");
     type Response = ChatStatistics;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3666,6 +3980,7 @@ pub struct GetChatStatisticsGraph {
 }
 impl Method for GetChatStatisticsGraph {
     const TYPE: &'static str = "getChatStatisticsGraph";
+    const RESPONSE_TYPE: Option<&'static str> = None;
     type Response = StatisticsGraph;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3676,6 +3991,7 @@ pub struct GetStorageStatistics {
 }
 impl Method for GetStorageStatistics {
     const TYPE: &'static str = "getStorageStatistics";
+    const RESPONSE_TYPE: Option<&'static str> = Some("storageStatistics");
     type Response = StorageStatistics;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3683,6 +3999,7 @@ impl Method for GetStorageStatistics {
 pub struct GetStorageStatisticsFast {}
 impl Method for GetStorageStatisticsFast {
     const TYPE: &'static str = "getStorageStatisticsFast";
+    const RESPONSE_TYPE: Option<&'static str> = Some("storageStatisticsFast");
     type Response = StorageStatisticsFast;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3690,6 +4007,7 @@ impl Method for GetStorageStatisticsFast {
 pub struct GetDatabaseStatistics {}
 impl Method for GetDatabaseStatistics {
     const TYPE: &'static str = "getDatabaseStatistics";
+    const RESPONSE_TYPE: Option<&'static str> = Some("databaseStatistics");
     type Response = DatabaseStatistics;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3718,6 +4036,7 @@ pub struct OptimizeStorage {
 }
 impl Method for OptimizeStorage {
     const TYPE: &'static str = "optimizeStorage";
+    const RESPONSE_TYPE: Option<&'static str> = Some("storageStatistics");
     type Response = StorageStatistics;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3729,6 +4048,7 @@ pub struct SetNetworkType {
 }
 impl Method for SetNetworkType {
     const TYPE: &'static str = "setNetworkType";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3740,6 +4060,7 @@ pub struct GetNetworkStatistics {
 }
 impl Method for GetNetworkStatistics {
     const TYPE: &'static str = "getNetworkStatistics";
+    const RESPONSE_TYPE: Option<&'static str> = Some("networkStatistics");
     type Response = NetworkStatistics;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3750,6 +4071,7 @@ pub struct AddNetworkStatistics {
 }
 impl Method for AddNetworkStatistics {
     const TYPE: &'static str = "addNetworkStatistics";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3757,6 +4079,7 @@ impl Method for AddNetworkStatistics {
 pub struct ResetNetworkStatistics {}
 impl Method for ResetNetworkStatistics {
     const TYPE: &'static str = "resetNetworkStatistics";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3764,6 +4087,7 @@ impl Method for ResetNetworkStatistics {
 pub struct GetAutoDownloadSettingsPresets {}
 impl Method for GetAutoDownloadSettingsPresets {
     const TYPE: &'static str = "getAutoDownloadSettingsPresets";
+    const RESPONSE_TYPE: Option<&'static str> = Some("autoDownloadSettingsPresets");
     type Response = AutoDownloadSettingsPresets;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3777,6 +4101,7 @@ pub struct SetAutoDownloadSettings {
 }
 impl Method for SetAutoDownloadSettings {
     const TYPE: &'static str = "setAutoDownloadSettings";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3787,6 +4112,7 @@ pub struct GetBankCardInfo {
 }
 impl Method for GetBankCardInfo {
     const TYPE: &'static str = "getBankCardInfo";
+    const RESPONSE_TYPE: Option<&'static str> = Some("bankCardInfo");
     type Response = BankCardInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3800,6 +4126,7 @@ pub struct GetPassportElement {
 }
 impl Method for GetPassportElement {
     const TYPE: &'static str = "getPassportElement";
+    const RESPONSE_TYPE: Option<&'static str> = None;
     type Response = PassportElement;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3810,6 +4137,7 @@ pub struct GetAllPassportElements {
 }
 impl Method for GetAllPassportElements {
     const TYPE: &'static str = "getAllPassportElements";
+    const RESPONSE_TYPE: Option<&'static str> = Some("passportElements");
     type Response = PassportElements;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3822,6 +4150,7 @@ pub struct SetPassportElement {
 }
 impl Method for SetPassportElement {
     const TYPE: &'static str = "setPassportElement";
+    const RESPONSE_TYPE: Option<&'static str> = None;
     type Response = PassportElement;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3833,6 +4162,7 @@ pub struct DeletePassportElement {
 }
 impl Method for DeletePassportElement {
     const TYPE: &'static str = "deletePassportElement";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3845,6 +4175,7 @@ pub struct SetPassportElementErrors {
 }
 impl Method for SetPassportElementErrors {
     const TYPE: &'static str = "setPassportElementErrors";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3855,6 +4186,7 @@ pub struct GetPreferredCountryLanguage {
 }
 impl Method for GetPreferredCountryLanguage {
     const TYPE: &'static str = "getPreferredCountryLanguage";
+    const RESPONSE_TYPE: Option<&'static str> = Some("text");
     type Response = Text;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3867,6 +4199,7 @@ pub struct SendPhoneNumberVerificationCode {
 }
 impl Method for SendPhoneNumberVerificationCode {
     const TYPE: &'static str = "sendPhoneNumberVerificationCode";
+    const RESPONSE_TYPE: Option<&'static str> = Some("authenticationCodeInfo");
     type Response = AuthenticationCodeInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3874,6 +4207,7 @@ impl Method for SendPhoneNumberVerificationCode {
 pub struct ResendPhoneNumberVerificationCode {}
 impl Method for ResendPhoneNumberVerificationCode {
     const TYPE: &'static str = "resendPhoneNumberVerificationCode";
+    const RESPONSE_TYPE: Option<&'static str> = Some("authenticationCodeInfo");
     type Response = AuthenticationCodeInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3884,6 +4218,7 @@ pub struct CheckPhoneNumberVerificationCode {
 }
 impl Method for CheckPhoneNumberVerificationCode {
     const TYPE: &'static str = "checkPhoneNumberVerificationCode";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3894,6 +4229,7 @@ pub struct SendEmailAddressVerificationCode {
 }
 impl Method for SendEmailAddressVerificationCode {
     const TYPE: &'static str = "sendEmailAddressVerificationCode";
+    const RESPONSE_TYPE: Option<&'static str> = Some("emailAddressAuthenticationCodeInfo");
     type Response = EmailAddressAuthenticationCodeInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3901,6 +4237,7 @@ impl Method for SendEmailAddressVerificationCode {
 pub struct ResendEmailAddressVerificationCode {}
 impl Method for ResendEmailAddressVerificationCode {
     const TYPE: &'static str = "resendEmailAddressVerificationCode";
+    const RESPONSE_TYPE: Option<&'static str> = Some("emailAddressAuthenticationCodeInfo");
     type Response = EmailAddressAuthenticationCodeInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3911,6 +4248,7 @@ pub struct CheckEmailAddressVerificationCode {
 }
 impl Method for CheckEmailAddressVerificationCode {
     const TYPE: &'static str = "checkEmailAddressVerificationCode";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3927,6 +4265,7 @@ pub struct GetPassportAuthorizationForm {
 }
 impl Method for GetPassportAuthorizationForm {
     const TYPE: &'static str = "getPassportAuthorizationForm";
+    const RESPONSE_TYPE: Option<&'static str> = Some("passportAuthorizationForm");
     type Response = PassportAuthorizationForm;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3939,6 +4278,7 @@ pub struct GetPassportAuthorizationFormAvailableElements {
 }
 impl Method for GetPassportAuthorizationFormAvailableElements {
     const TYPE: &'static str = "getPassportAuthorizationFormAvailableElements";
+    const RESPONSE_TYPE: Option<&'static str> = Some("passportElementsWithErrors");
     type Response = PassportElementsWithErrors;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3951,6 +4291,7 @@ pub struct SendPassportAuthorizationForm {
 }
 impl Method for SendPassportAuthorizationForm {
     const TYPE: &'static str = "sendPassportAuthorizationForm";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3965,6 +4306,7 @@ pub struct SendPhoneNumberConfirmationCode {
 }
 impl Method for SendPhoneNumberConfirmationCode {
     const TYPE: &'static str = "sendPhoneNumberConfirmationCode";
+    const RESPONSE_TYPE: Option<&'static str> = Some("authenticationCodeInfo");
     type Response = AuthenticationCodeInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3972,6 +4314,7 @@ impl Method for SendPhoneNumberConfirmationCode {
 pub struct ResendPhoneNumberConfirmationCode {}
 impl Method for ResendPhoneNumberConfirmationCode {
     const TYPE: &'static str = "resendPhoneNumberConfirmationCode";
+    const RESPONSE_TYPE: Option<&'static str> = Some("authenticationCodeInfo");
     type Response = AuthenticationCodeInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3982,6 +4325,7 @@ pub struct CheckPhoneNumberConfirmationCode {
 }
 impl Method for CheckPhoneNumberConfirmationCode {
     const TYPE: &'static str = "checkPhoneNumberConfirmationCode";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -3994,6 +4338,7 @@ pub struct SetBotUpdatesStatus {
 }
 impl Method for SetBotUpdatesStatus {
     const TYPE: &'static str = "setBotUpdatesStatus";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4006,6 +4351,7 @@ pub struct UploadStickerFile {
 }
 impl Method for UploadStickerFile {
     const TYPE: &'static str = "uploadStickerFile";
+    const RESPONSE_TYPE: Option<&'static str> = Some("file");
     type Response = File;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4025,6 +4371,7 @@ pub struct CreateNewStickerSet {
 }
 impl Method for CreateNewStickerSet {
     const TYPE: &'static str = "createNewStickerSet";
+    const RESPONSE_TYPE: Option<&'static str> = Some("stickerSet");
     type Response = StickerSet;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4039,6 +4386,7 @@ pub struct AddStickerToSet {
 }
 impl Method for AddStickerToSet {
     const TYPE: &'static str = "addStickerToSet";
+    const RESPONSE_TYPE: Option<&'static str> = Some("stickerSet");
     type Response = StickerSet;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4053,6 +4401,7 @@ pub struct SetStickerSetThumbnail {
 }
 impl Method for SetStickerSetThumbnail {
     const TYPE: &'static str = "setStickerSetThumbnail";
+    const RESPONSE_TYPE: Option<&'static str> = Some("stickerSet");
     type Response = StickerSet;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4065,6 +4414,7 @@ pub struct SetStickerPositionInSet {
 }
 impl Method for SetStickerPositionInSet {
     const TYPE: &'static str = "setStickerPositionInSet";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4075,6 +4425,7 @@ pub struct RemoveStickerFromSet {
 }
 impl Method for RemoveStickerFromSet {
     const TYPE: &'static str = "removeStickerFromSet";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4096,6 +4447,7 @@ pub struct GetMapThumbnailFile {
 }
 impl Method for GetMapThumbnailFile {
     const TYPE: &'static str = "getMapThumbnailFile";
+    const RESPONSE_TYPE: Option<&'static str> = Some("file");
     type Response = File;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4106,6 +4458,7 @@ pub struct AcceptTermsOfService {
 }
 impl Method for AcceptTermsOfService {
     const TYPE: &'static str = "acceptTermsOfService";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4118,6 +4471,7 @@ pub struct SendCustomRequest {
 }
 impl Method for SendCustomRequest {
     const TYPE: &'static str = "sendCustomRequest";
+    const RESPONSE_TYPE: Option<&'static str> = Some("customRequestResult");
     type Response = CustomRequestResult;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4131,6 +4485,7 @@ pub struct AnswerCustomQuery {
 }
 impl Method for AnswerCustomQuery {
     const TYPE: &'static str = "answerCustomQuery";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4141,6 +4496,7 @@ pub struct SetAlarm {
 }
 impl Method for SetAlarm {
     const TYPE: &'static str = "setAlarm";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4148,6 +4504,7 @@ impl Method for SetAlarm {
 pub struct GetCountryCode {}
 impl Method for GetCountryCode {
     const TYPE: &'static str = "getCountryCode";
+    const RESPONSE_TYPE: Option<&'static str> = Some("text");
     type Response = Text;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4155,6 +4512,7 @@ impl Method for GetCountryCode {
 pub struct GetInviteText {}
 impl Method for GetInviteText {
     const TYPE: &'static str = "getInviteText";
+    const RESPONSE_TYPE: Option<&'static str> = Some("text");
     type Response = Text;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4165,6 +4523,7 @@ pub struct GetDeepLinkInfo {
 }
 impl Method for GetDeepLinkInfo {
     const TYPE: &'static str = "getDeepLinkInfo";
+    const RESPONSE_TYPE: Option<&'static str> = Some("deepLinkInfo");
     type Response = DeepLinkInfo;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4172,6 +4531,7 @@ impl Method for GetDeepLinkInfo {
 pub struct GetApplicationConfig {}
 impl Method for GetApplicationConfig {
     const TYPE: &'static str = "getApplicationConfig";
+    const RESPONSE_TYPE: Option<&'static str> = None;
     type Response = JsonValue;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4188,6 +4548,7 @@ pub struct SaveApplicationLogEvent {
 }
 impl Method for SaveApplicationLogEvent {
     const TYPE: &'static str = "saveApplicationLogEvent";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4206,6 +4567,7 @@ pub struct AddProxy {
 }
 impl Method for AddProxy {
     const TYPE: &'static str = "addProxy";
+    const RESPONSE_TYPE: Option<&'static str> = Some("proxy");
     type Response = Proxy;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4226,6 +4588,7 @@ pub struct EditProxy {
 }
 impl Method for EditProxy {
     const TYPE: &'static str = "editProxy";
+    const RESPONSE_TYPE: Option<&'static str> = Some("proxy");
     type Response = Proxy;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4236,6 +4599,7 @@ pub struct EnableProxy {
 }
 impl Method for EnableProxy {
     const TYPE: &'static str = "enableProxy";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4243,6 +4607,7 @@ impl Method for EnableProxy {
 pub struct DisableProxy {}
 impl Method for DisableProxy {
     const TYPE: &'static str = "disableProxy";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4253,6 +4618,7 @@ pub struct RemoveProxy {
 }
 impl Method for RemoveProxy {
     const TYPE: &'static str = "removeProxy";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4260,6 +4626,7 @@ impl Method for RemoveProxy {
 pub struct GetProxies {}
 impl Method for GetProxies {
     const TYPE: &'static str = "getProxies";
+    const RESPONSE_TYPE: Option<&'static str> = Some("proxies");
     type Response = Proxies;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4270,6 +4637,7 @@ pub struct GetProxyLink {
 }
 impl Method for GetProxyLink {
     const TYPE: &'static str = "getProxyLink";
+    const RESPONSE_TYPE: Option<&'static str> = Some("text");
     type Response = Text;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4280,6 +4648,7 @@ pub struct PingProxy {
 }
 impl Method for PingProxy {
     const TYPE: &'static str = "pingProxy";
+    const RESPONSE_TYPE: Option<&'static str> = Some("seconds");
     type Response = Seconds;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4290,15 +4659,19 @@ pub struct SetLogStream {
 }
 impl Method for SetLogStream {
     const TYPE: &'static str = "setLogStream";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
+impl SyncMethod for SetLogStream {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Returns information about currently used log stream for internal logging of TDLib. This is an offline method. Can be called before authorization. Can be called synchronously"]
 pub struct GetLogStream {}
 impl Method for GetLogStream {
     const TYPE: &'static str = "getLogStream";
+    const RESPONSE_TYPE: Option<&'static str> = None;
     type Response = LogStream;
 }
+impl SyncMethod for GetLogStream {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Sets the verbosity level of the internal logging of TDLib. This is an offline method. Can be called before authorization. Can be called synchronously"]
 pub struct SetLogVerbosityLevel {
@@ -4307,22 +4680,28 @@ pub struct SetLogVerbosityLevel {
 }
 impl Method for SetLogVerbosityLevel {
     const TYPE: &'static str = "setLogVerbosityLevel";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
+impl SyncMethod for SetLogVerbosityLevel {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Returns current verbosity level of the internal logging of TDLib. This is an offline method. Can be called before authorization. Can be called synchronously"]
 pub struct GetLogVerbosityLevel {}
 impl Method for GetLogVerbosityLevel {
     const TYPE: &'static str = "getLogVerbosityLevel";
+    const RESPONSE_TYPE: Option<&'static str> = Some("logVerbosityLevel");
     type Response = LogVerbosityLevel;
 }
+impl SyncMethod for GetLogVerbosityLevel {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Returns list of available TDLib internal log tags, for example, [\"actor\", \"binlog\", \"connections\", \"notifications\", \"proxy\"]. This is an offline method. Can be called before authorization. Can be called synchronously"]
 pub struct GetLogTags {}
 impl Method for GetLogTags {
     const TYPE: &'static str = "getLogTags";
+    const RESPONSE_TYPE: Option<&'static str> = Some("logTags");
     type Response = LogTags;
 }
+impl SyncMethod for GetLogTags {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Sets the verbosity level for a specified TDLib internal log tag. This is an offline method. Can be called before authorization. Can be called synchronously"]
 pub struct SetLogTagVerbosityLevel {
@@ -4333,8 +4712,10 @@ pub struct SetLogTagVerbosityLevel {
 }
 impl Method for SetLogTagVerbosityLevel {
     const TYPE: &'static str = "setLogTagVerbosityLevel";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
+impl SyncMethod for SetLogTagVerbosityLevel {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Returns current verbosity level for a specified TDLib internal log tag. This is an offline method. Can be called before authorization. Can be called synchronously "]
 pub struct GetLogTagVerbosityLevel {
@@ -4343,8 +4724,10 @@ pub struct GetLogTagVerbosityLevel {
 }
 impl Method for GetLogTagVerbosityLevel {
     const TYPE: &'static str = "getLogTagVerbosityLevel";
+    const RESPONSE_TYPE: Option<&'static str> = Some("logVerbosityLevel");
     type Response = LogVerbosityLevel;
 }
+impl SyncMethod for GetLogTagVerbosityLevel {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Adds a message to TDLib internal log. This is an offline method. Can be called before authorization. Can be called synchronously"]
 pub struct AddLogMessage {
@@ -4355,13 +4738,16 @@ pub struct AddLogMessage {
 }
 impl Method for AddLogMessage {
     const TYPE: &'static str = "addLogMessage";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
+impl SyncMethod for AddLogMessage {}
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Does nothing; for testing only. This is an offline method. Can be called before authorization"]
 pub struct TestCallEmpty {}
 impl Method for TestCallEmpty {
     const TYPE: &'static str = "testCallEmpty";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4372,6 +4758,7 @@ pub struct TestCallString {
 }
 impl Method for TestCallString {
     const TYPE: &'static str = "testCallString";
+    const RESPONSE_TYPE: Option<&'static str> = Some("testString");
     type Response = TestString;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4382,6 +4769,7 @@ pub struct TestCallBytes {
 }
 impl Method for TestCallBytes {
     const TYPE: &'static str = "testCallBytes";
+    const RESPONSE_TYPE: Option<&'static str> = Some("testBytes");
     type Response = TestBytes;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4392,6 +4780,7 @@ pub struct TestCallVectorInt {
 }
 impl Method for TestCallVectorInt {
     const TYPE: &'static str = "testCallVectorInt";
+    const RESPONSE_TYPE: Option<&'static str> = Some("testVectorInt");
     type Response = TestVectorInt;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4402,6 +4791,7 @@ pub struct TestCallVectorIntObject {
 }
 impl Method for TestCallVectorIntObject {
     const TYPE: &'static str = "testCallVectorIntObject";
+    const RESPONSE_TYPE: Option<&'static str> = Some("testVectorIntObject");
     type Response = TestVectorIntObject;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4412,6 +4802,7 @@ pub struct TestCallVectorString {
 }
 impl Method for TestCallVectorString {
     const TYPE: &'static str = "testCallVectorString";
+    const RESPONSE_TYPE: Option<&'static str> = Some("testVectorString");
     type Response = TestVectorString;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4422,6 +4813,7 @@ pub struct TestCallVectorStringObject {
 }
 impl Method for TestCallVectorStringObject {
     const TYPE: &'static str = "testCallVectorStringObject";
+    const RESPONSE_TYPE: Option<&'static str> = Some("testVectorStringObject");
     type Response = TestVectorStringObject;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4432,6 +4824,7 @@ pub struct TestSquareInt {
 }
 impl Method for TestSquareInt {
     const TYPE: &'static str = "testSquareInt";
+    const RESPONSE_TYPE: Option<&'static str> = Some("testInt");
     type Response = TestInt;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4439,6 +4832,7 @@ impl Method for TestSquareInt {
 pub struct TestNetwork {}
 impl Method for TestNetwork {
     const TYPE: &'static str = "testNetwork";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4458,6 +4852,7 @@ pub struct TestProxy {
 }
 impl Method for TestProxy {
     const TYPE: &'static str = "testProxy";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4465,6 +4860,7 @@ impl Method for TestProxy {
 pub struct TestGetDifference {}
 impl Method for TestGetDifference {
     const TYPE: &'static str = "testGetDifference";
+    const RESPONSE_TYPE: Option<&'static str> = Some("ok");
     type Response = Ok;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4472,6 +4868,7 @@ impl Method for TestGetDifference {
 pub struct TestUseUpdate {}
 impl Method for TestUseUpdate {
     const TYPE: &'static str = "testUseUpdate";
+    const RESPONSE_TYPE: Option<&'static str> = None;
     type Response = Update;
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -4482,5 +4879,7 @@ pub struct TestReturnError {
 }
 impl Method for TestReturnError {
     const TYPE: &'static str = "testReturnError";
+    const RESPONSE_TYPE: Option<&'static str> = Some("error");
     type Response = Error;
 }
+impl SyncMethod for TestReturnError {}
@@ -1,3 +1,4 @@
+// Generated by paperplane-codegen from td_api.tl, do not edit by hand
 use serde::{ Serialize, Deserialize };
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -718,7 +719,7 @@ pub struct User {
     #[doc = "Type of the user "]
     pub type_: UserType,
     #[doc = "IETF language tag of the user's language; only available to bots"]
-    pub language_code: Option<String>,
+    pub language_code: String,
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "Contains full information about a user (except the full list of profile photos) "]
@@ -3224,7 +3225,7 @@ pub struct SendMessageOptions {
     #[serde(default)]
     pub from_background: bool,
     #[doc = "Message scheduling state. Messages sent to a secret chat, live location messages and self-destructing messages can't be scheduled"]
-    pub scheduling_state: MessageSchedulingState,
+    pub scheduling_state: Option<MessageSchedulingState>,
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "A text message "]
@@ -3434,12 +3435,12 @@ pub struct InputMessagePoll {
     #[doc = "Type of the poll"]
     pub type_: PollType,
     #[doc = "Amount of time the poll will be active after creation, in seconds; for bots only"]
-    pub open_period: Option<i32>,
+    pub open_period: i32,
     #[doc = "Point in time (Unix timestamp) when the poll will be automatically closed; for bots only"]
-    pub close_date: Option<i32>,
+    pub close_date: i32,
     #[doc = "True, if the poll needs to be sent already closed; for bots only"]
     #[serde(default)]
-    pub is_closed: Option<bool>,
+    pub is_closed: bool,
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[doc = "A forwarded message "]
@@ -6655,1053 +6656,905 @@ pub struct TestVectorStringObject {
     #[doc = "Vector of objects"]
     pub value: Vec<TestString>,
 }
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum MaskPoint {
-    MaskPointForehead(MaskPointForehead),
-    MaskPointEyes(MaskPointEyes),
-    MaskPointMouth(MaskPointMouth),
-    MaskPointChin(MaskPointChin),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum FileType {
-    FileTypeNone(FileTypeNone),
-    FileTypeAnimation(FileTypeAnimation),
-    FileTypeAudio(FileTypeAudio),
-    FileTypeDocument(FileTypeDocument),
-    FileTypePhoto(FileTypePhoto),
-    FileTypeProfilePhoto(FileTypeProfilePhoto),
-    FileTypeSecret(FileTypeSecret),
-    FileTypeSecretThumbnail(FileTypeSecretThumbnail),
-    FileTypeSecure(FileTypeSecure),
-    FileTypeSticker(FileTypeSticker),
-    FileTypeThumbnail(FileTypeThumbnail),
-    FileTypeUnknown(FileTypeUnknown),
-    FileTypeVideo(FileTypeVideo),
-    FileTypeVideoNote(FileTypeVideoNote),
-    FileTypeVoiceNote(FileTypeVoiceNote),
-    FileTypeWallpaper(FileTypeWallpaper),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum ProxyType {
-    ProxyTypeSocks5(ProxyTypeSocks5),
-    ProxyTypeHttp(ProxyTypeHttp),
-    ProxyTypeMtproto(ProxyTypeMtproto),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum LoginUrlInfo {
-    LoginUrlInfoOpen(LoginUrlInfoOpen),
-    LoginUrlInfoRequestConfirmation(LoginUrlInfoRequestConfirmation),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum ChatMembersFilter {
-    ChatMembersFilterContacts(ChatMembersFilterContacts),
-    ChatMembersFilterAdministrators(ChatMembersFilterAdministrators),
-    ChatMembersFilterMembers(ChatMembersFilterMembers),
-    ChatMembersFilterRestricted(ChatMembersFilterRestricted),
-    ChatMembersFilterBanned(ChatMembersFilterBanned),
-    ChatMembersFilterBots(ChatMembersFilterBots),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum ChatEventAction {
-    ChatEventMessageEdited(ChatEventMessageEdited),
-    ChatEventMessageDeleted(ChatEventMessageDeleted),
-    ChatEventPollStopped(ChatEventPollStopped),
-    ChatEventMessagePinned(ChatEventMessagePinned),
-    ChatEventMessageUnpinned(ChatEventMessageUnpinned),
-    ChatEventMemberJoined(ChatEventMemberJoined),
-    ChatEventMemberLeft(ChatEventMemberLeft),
-    ChatEventMemberInvited(ChatEventMemberInvited),
-    ChatEventMemberPromoted(ChatEventMemberPromoted),
-    ChatEventMemberRestricted(ChatEventMemberRestricted),
-    ChatEventTitleChanged(ChatEventTitleChanged),
-    ChatEventPermissionsChanged(ChatEventPermissionsChanged),
-    ChatEventDescriptionChanged(ChatEventDescriptionChanged),
-    ChatEventUsernameChanged(ChatEventUsernameChanged),
-    ChatEventPhotoChanged(ChatEventPhotoChanged),
-    ChatEventInvitesToggled(ChatEventInvitesToggled),
-    ChatEventLinkedChatChanged(ChatEventLinkedChatChanged),
-    ChatEventSlowModeDelayChanged(ChatEventSlowModeDelayChanged),
-    ChatEventSignMessagesToggled(ChatEventSignMessagesToggled),
-    ChatEventStickerSetChanged(ChatEventStickerSetChanged),
-    ChatEventLocationChanged(ChatEventLocationChanged),
-    ChatEventIsAllHistoryAvailableToggled(ChatEventIsAllHistoryAvailableToggled),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum InputSticker {
-    InputStickerStatic(InputStickerStatic),
-    InputStickerAnimated(InputStickerAnimated),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum ChatSource {
-    ChatSourceMtprotoProxy(ChatSourceMtprotoProxy),
-    ChatSourcePublicServiceAnnouncement(ChatSourcePublicServiceAnnouncement),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum TextEntityType {
-    TextEntityTypeMention(TextEntityTypeMention),
-    TextEntityTypeHashtag(TextEntityTypeHashtag),
-    TextEntityTypeCashtag(TextEntityTypeCashtag),
-    TextEntityTypeBotCommand(TextEntityTypeBotCommand),
-    TextEntityTypeUrl(TextEntityTypeUrl),
-    TextEntityTypeEmailAddress(TextEntityTypeEmailAddress),
-    TextEntityTypePhoneNumber(TextEntityTypePhoneNumber),
-    TextEntityTypeBankCardNumber(TextEntityTypeBankCardNumber),
-    TextEntityTypeBold(TextEntityTypeBold),
-    TextEntityTypeItalic(TextEntityTypeItalic),
-    TextEntityTypeUnderline(TextEntityTypeUnderline),
-    TextEntityTypeStrikethrough(TextEntityTypeStrikethrough),
-    TextEntityTypeCode(TextEntityTypeCode),
-    TextEntityTypePre(TextEntityTypePre),
-    TextEntityTypePreCode(TextEntityTypePreCode),
-    TextEntityTypeTextUrl(TextEntityTypeTextUrl),
-    TextEntityTypeMentionName(TextEntityTypeMentionName),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum NetworkType {
-    NetworkTypeNone(NetworkTypeNone),
-    NetworkTypeMobile(NetworkTypeMobile),
-    NetworkTypeMobileRoaming(NetworkTypeMobileRoaming),
-    NetworkTypeWiFi(NetworkTypeWiFi),
-    NetworkTypeOther(NetworkTypeOther),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum PassportElement {
-    PassportElementPersonalDetails(PassportElementPersonalDetails),
-    PassportElementPassport(PassportElementPassport),
-    PassportElementDriverLicense(PassportElementDriverLicense),
-    PassportElementIdentityCard(PassportElementIdentityCard),
-    PassportElementInternalPassport(PassportElementInternalPassport),
-    PassportElementAddress(PassportElementAddress),
-    PassportElementUtilityBill(PassportElementUtilityBill),
-    PassportElementBankStatement(PassportElementBankStatement),
-    PassportElementRentalAgreement(PassportElementRentalAgreement),
-    PassportElementPassportRegistration(PassportElementPassportRegistration),
-    PassportElementTemporaryRegistration(PassportElementTemporaryRegistration),
-    PassportElementPhoneNumber(PassportElementPhoneNumber),
-    PassportElementEmailAddress(PassportElementEmailAddress),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum MessageContent {
-    MessageText(MessageText),
-    MessageAnimation(MessageAnimation),
-    MessageAudio(MessageAudio),
-    MessageDocument(MessageDocument),
-    MessagePhoto(MessagePhoto),
-    MessageExpiredPhoto(MessageExpiredPhoto),
-    MessageSticker(MessageSticker),
-    MessageVideo(MessageVideo),
-    MessageExpiredVideo(MessageExpiredVideo),
-    MessageVideoNote(MessageVideoNote),
-    MessageVoiceNote(MessageVoiceNote),
-    MessageLocation(MessageLocation),
-    MessageVenue(MessageVenue),
-    MessageContact(MessageContact),
-    MessageDice(MessageDice),
-    MessageGame(MessageGame),
-    MessagePoll(MessagePoll),
-    MessageInvoice(MessageInvoice),
-    MessageCall(MessageCall),
-    MessageBasicGroupChatCreate(MessageBasicGroupChatCreate),
-    MessageSupergroupChatCreate(MessageSupergroupChatCreate),
-    MessageChatChangeTitle(MessageChatChangeTitle),
-    MessageChatChangePhoto(MessageChatChangePhoto),
-    MessageChatDeletePhoto(MessageChatDeletePhoto),
-    MessageChatAddMembers(MessageChatAddMembers),
-    MessageChatJoinByLink(MessageChatJoinByLink),
-    MessageChatDeleteMember(MessageChatDeleteMember),
-    MessageChatUpgradeTo(MessageChatUpgradeTo),
-    MessageChatUpgradeFrom(MessageChatUpgradeFrom),
-    MessagePinMessage(MessagePinMessage),
-    MessageScreenshotTaken(MessageScreenshotTaken),
-    MessageChatSetTtl(MessageChatSetTtl),
-    MessageCustomServiceAction(MessageCustomServiceAction),
-    MessageGameScore(MessageGameScore),
-    MessagePaymentSuccessful(MessagePaymentSuccessful),
-    MessagePaymentSuccessfulBot(MessagePaymentSuccessfulBot),
-    MessageContactRegistered(MessageContactRegistered),
-    MessageWebsiteConnected(MessageWebsiteConnected),
-    MessagePassportDataSent(MessagePassportDataSent),
-    MessagePassportDataReceived(MessagePassportDataReceived),
-    MessageUnsupported(MessageUnsupported),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum LanguagePackStringValue {
-    LanguagePackStringValueOrdinary(LanguagePackStringValueOrdinary),
-    LanguagePackStringValuePluralized(LanguagePackStringValuePluralized),
-    LanguagePackStringValueDeleted(LanguagePackStringValueDeleted),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum CallState {
-    CallStatePending(CallStatePending),
-    CallStateExchangingKeys(CallStateExchangingKeys),
-    CallStateReady(CallStateReady),
-    CallStateHangingUp(CallStateHangingUp),
-    CallStateDiscarded(CallStateDiscarded),
-    CallStateError(CallStateError),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum KeyboardButtonType {
-    KeyboardButtonTypeText(KeyboardButtonTypeText),
-    KeyboardButtonTypeRequestPhoneNumber(KeyboardButtonTypeRequestPhoneNumber),
-    KeyboardButtonTypeRequestLocation(KeyboardButtonTypeRequestLocation),
-    KeyboardButtonTypeRequestPoll(KeyboardButtonTypeRequestPoll),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum RichText {
-    RichTextPlain(RichTextPlain),
-    RichTextBold(RichTextBold),
-    RichTextItalic(RichTextItalic),
-    RichTextUnderline(RichTextUnderline),
-    RichTextStrikethrough(RichTextStrikethrough),
-    RichTextFixed(RichTextFixed),
-    RichTextUrl(RichTextUrl),
-    RichTextEmailAddress(RichTextEmailAddress),
-    RichTextSubscript(RichTextSubscript),
-    RichTextSuperscript(RichTextSuperscript),
-    RichTextMarked(RichTextMarked),
-    RichTextPhoneNumber(RichTextPhoneNumber),
-    RichTextIcon(RichTextIcon),
-    RichTextReference(RichTextReference),
-    RichTextAnchor(RichTextAnchor),
-    RichTextAnchorLink(RichTextAnchorLink),
-    RichTexts(RichTexts),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum PageBlock {
-    PageBlockTitle(PageBlockTitle),
-    PageBlockSubtitle(PageBlockSubtitle),
-    PageBlockAuthorDate(PageBlockAuthorDate),
-    PageBlockHeader(PageBlockHeader),
-    PageBlockSubheader(PageBlockSubheader),
-    PageBlockKicker(PageBlockKicker),
-    PageBlockParagraph(PageBlockParagraph),
-    PageBlockPreformatted(PageBlockPreformatted),
-    PageBlockFooter(PageBlockFooter),
-    PageBlockDivider(PageBlockDivider),
-    PageBlockAnchor(PageBlockAnchor),
-    PageBlockList(PageBlockList),
-    PageBlockBlockQuote(PageBlockBlockQuote),
-    PageBlockPullQuote(PageBlockPullQuote),
-    PageBlockAnimation(PageBlockAnimation),
-    PageBlockAudio(PageBlockAudio),
-    PageBlockPhoto(PageBlockPhoto),
-    PageBlockVideo(PageBlockVideo),
-    PageBlockVoiceNote(PageBlockVoiceNote),
-    PageBlockCover(PageBlockCover),
-    PageBlockEmbedded(PageBlockEmbedded),
-    PageBlockEmbeddedPost(PageBlockEmbeddedPost),
-    PageBlockCollage(PageBlockCollage),
-    PageBlockSlideshow(PageBlockSlideshow),
-    PageBlockChatLink(PageBlockChatLink),
-    PageBlockTable(PageBlockTable),
-    PageBlockDetails(PageBlockDetails),
-    PageBlockRelatedArticles(PageBlockRelatedArticles),
-    PageBlockMap(PageBlockMap),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum InputBackground {
-    InputBackgroundLocal(InputBackgroundLocal),
-    InputBackgroundRemote(InputBackgroundRemote),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum InputPassportElementErrorSource {
-    InputPassportElementErrorSourceUnspecified(InputPassportElementErrorSourceUnspecified),
-    InputPassportElementErrorSourceDataField(InputPassportElementErrorSourceDataField),
-    InputPassportElementErrorSourceFrontSide(InputPassportElementErrorSourceFrontSide),
-    InputPassportElementErrorSourceReverseSide(InputPassportElementErrorSourceReverseSide),
-    InputPassportElementErrorSourceSelfie(InputPassportElementErrorSourceSelfie),
-    InputPassportElementErrorSourceTranslationFile(InputPassportElementErrorSourceTranslationFile),
-    InputPassportElementErrorSourceTranslationFiles(
-        InputPassportElementErrorSourceTranslationFiles,
-    ),
-    InputPassportElementErrorSourceFile(InputPassportElementErrorSourceFile),
-    InputPassportElementErrorSourceFiles(InputPassportElementErrorSourceFiles),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum PushMessageContent {
-    PushMessageContentHidden(PushMessageContentHidden),
-    PushMessageContentAnimation(PushMessageContentAnimation),
-    PushMessageContentAudio(PushMessageContentAudio),
-    PushMessageContentContact(PushMessageContentContact),
-    PushMessageContentContactRegistered(PushMessageContentContactRegistered),
-    PushMessageContentDocument(PushMessageContentDocument),
-    PushMessageContentGame(PushMessageContentGame),
-    PushMessageContentGameScore(PushMessageContentGameScore),
-    PushMessageContentInvoice(PushMessageContentInvoice),
-    PushMessageContentLocation(PushMessageContentLocation),
-    PushMessageContentPhoto(PushMessageContentPhoto),
-    PushMessageContentPoll(PushMessageContentPoll),
-    PushMessageContentScreenshotTaken(PushMessageContentScreenshotTaken),
-    PushMessageContentSticker(PushMessageContentSticker),
-    PushMessageContentText(PushMessageContentText),
-    PushMessageContentVideo(PushMessageContentVideo),
-    PushMessageContentVideoNote(PushMessageContentVideoNote),
-    PushMessageContentVoiceNote(PushMessageContentVoiceNote),
-    PushMessageContentBasicGroupChatCreate(PushMessageContentBasicGroupChatCreate),
-    PushMessageContentChatAddMembers(PushMessageContentChatAddMembers),
-    PushMessageContentChatChangePhoto(PushMessageContentChatChangePhoto),
-    PushMessageContentChatChangeTitle(PushMessageContentChatChangeTitle),
-    PushMessageContentChatDeleteMember(PushMessageContentChatDeleteMember),
-    PushMessageContentChatJoinByLink(PushMessageContentChatJoinByLink),
-    PushMessageContentMessageForwards(PushMessageContentMessageForwards),
-    PushMessageContentMediaAlbum(PushMessageContentMediaAlbum),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum AuthenticationCodeType {
-    AuthenticationCodeTypeTelegramMessage(AuthenticationCodeTypeTelegramMessage),
-    AuthenticationCodeTypeSms(AuthenticationCodeTypeSms),
-    AuthenticationCodeTypeCall(AuthenticationCodeTypeCall),
-    AuthenticationCodeTypeFlashCall(AuthenticationCodeTypeFlashCall),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum ChatMemberStatus {
-    ChatMemberStatusCreator(ChatMemberStatusCreator),
-    ChatMemberStatusAdministrator(ChatMemberStatusAdministrator),
-    ChatMemberStatusMember(ChatMemberStatusMember),
-    ChatMemberStatusRestricted(ChatMemberStatusRestricted),
-    ChatMemberStatusLeft(ChatMemberStatusLeft),
-    ChatMemberStatusBanned(ChatMemberStatusBanned),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum UserPrivacySettingRule {
-    UserPrivacySettingRuleAllowAll(UserPrivacySettingRuleAllowAll),
-    UserPrivacySettingRuleAllowContacts(UserPrivacySettingRuleAllowContacts),
-    UserPrivacySettingRuleAllowUsers(UserPrivacySettingRuleAllowUsers),
-    UserPrivacySettingRuleAllowChatMembers(UserPrivacySettingRuleAllowChatMembers),
-    UserPrivacySettingRuleRestrictAll(UserPrivacySettingRuleRestrictAll),
-    UserPrivacySettingRuleRestrictContacts(UserPrivacySettingRuleRestrictContacts),
-    UserPrivacySettingRuleRestrictUsers(UserPrivacySettingRuleRestrictUsers),
-    UserPrivacySettingRuleRestrictChatMembers(UserPrivacySettingRuleRestrictChatMembers),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum AuthorizationState {
-    AuthorizationStateWaitTdlibParameters(AuthorizationStateWaitTdlibParameters),
-    AuthorizationStateWaitEncryptionKey(AuthorizationStateWaitEncryptionKey),
-    AuthorizationStateWaitPhoneNumber(AuthorizationStateWaitPhoneNumber),
-    AuthorizationStateWaitCode(AuthorizationStateWaitCode),
-    AuthorizationStateWaitOtherDeviceConfirmation(AuthorizationStateWaitOtherDeviceConfirmation),
-    AuthorizationStateWaitRegistration(AuthorizationStateWaitRegistration),
-    AuthorizationStateWaitPassword(AuthorizationStateWaitPassword),
-    AuthorizationStateReady(AuthorizationStateReady),
-    AuthorizationStateLoggingOut(AuthorizationStateLoggingOut),
-    AuthorizationStateClosing(AuthorizationStateClosing),
-    AuthorizationStateClosed(AuthorizationStateClosed),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum OptionValue {
-    OptionValueBoolean(OptionValueBoolean),
-    OptionValueEmpty(OptionValueEmpty),
-    OptionValueInteger(OptionValueInteger),
-    OptionValueString(OptionValueString),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum ChatType {
-    ChatTypePrivate(ChatTypePrivate),
-    ChatTypeBasicGroup(ChatTypeBasicGroup),
-    ChatTypeSupergroup(ChatTypeSupergroup),
-    ChatTypeSecret(ChatTypeSecret),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum UserStatus {
-    UserStatusEmpty(UserStatusEmpty),
-    UserStatusOnline(UserStatusOnline),
-    UserStatusOffline(UserStatusOffline),
-    UserStatusRecently(UserStatusRecently),
-    UserStatusLastWeek(UserStatusLastWeek),
-    UserStatusLastMonth(UserStatusLastMonth),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum MessageSendingState {
-    MessageSendingStatePending(MessageSendingStatePending),
-    MessageSendingStateFailed(MessageSendingStateFailed),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum MessageForwardOrigin {
-    MessageForwardOriginUser(MessageForwardOriginUser),
-    MessageForwardOriginHiddenUser(MessageForwardOriginHiddenUser),
-    MessageForwardOriginChannel(MessageForwardOriginChannel),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum PageBlockVerticalAlignment {
-    PageBlockVerticalAlignmentTop(PageBlockVerticalAlignmentTop),
-    PageBlockVerticalAlignmentMiddle(PageBlockVerticalAlignmentMiddle),
-    PageBlockVerticalAlignmentBottom(PageBlockVerticalAlignmentBottom),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum CanTransferOwnershipResult {
-    CanTransferOwnershipResultOk(CanTransferOwnershipResultOk),
-    CanTransferOwnershipResultPasswordNeeded(CanTransferOwnershipResultPasswordNeeded),
-    CanTransferOwnershipResultPasswordTooFresh(CanTransferOwnershipResultPasswordTooFresh),
-    CanTransferOwnershipResultSessionTooFresh(CanTransferOwnershipResultSessionTooFresh),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum PageBlockHorizontalAlignment {
-    PageBlockHorizontalAlignmentLeft(PageBlockHorizontalAlignmentLeft),
-    PageBlockHorizontalAlignmentCenter(PageBlockHorizontalAlignmentCenter),
-    PageBlockHorizontalAlignmentRight(PageBlockHorizontalAlignmentRight),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum SearchMessagesFilter {
-    SearchMessagesFilterEmpty(SearchMessagesFilterEmpty),
-    SearchMessagesFilterAnimation(SearchMessagesFilterAnimation),
-    SearchMessagesFilterAudio(SearchMessagesFilterAudio),
-    SearchMessagesFilterDocument(SearchMessagesFilterDocument),
-    SearchMessagesFilterPhoto(SearchMessagesFilterPhoto),
-    SearchMessagesFilterVideo(SearchMessagesFilterVideo),
-    SearchMessagesFilterVoiceNote(SearchMessagesFilterVoiceNote),
-    SearchMessagesFilterPhotoAndVideo(SearchMessagesFilterPhotoAndVideo),
-    SearchMessagesFilterUrl(SearchMessagesFilterUrl),
-    SearchMessagesFilterChatPhoto(SearchMessagesFilterChatPhoto),
-    SearchMessagesFilterCall(SearchMessagesFilterCall),
-    SearchMessagesFilterMissedCall(SearchMessagesFilterMissedCall),
-    SearchMessagesFilterVideoNote(SearchMessagesFilterVideoNote),
-    SearchMessagesFilterVoiceAndVideoNote(SearchMessagesFilterVoiceAndVideoNote),
-    SearchMessagesFilterMention(SearchMessagesFilterMention),
-    SearchMessagesFilterUnreadMention(SearchMessagesFilterUnreadMention),
-    SearchMessagesFilterFailedToSend(SearchMessagesFilterFailedToSend),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum ReplyMarkup {
-    ReplyMarkupRemoveKeyboard(ReplyMarkupRemoveKeyboard),
-    ReplyMarkupForceReply(ReplyMarkupForceReply),
-    ReplyMarkupShowKeyboard(ReplyMarkupShowKeyboard),
-    ReplyMarkupInlineKeyboard(ReplyMarkupInlineKeyboard),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum InputInlineQueryResult {
-    InputInlineQueryResultAnimatedGif(InputInlineQueryResultAnimatedGif),
-    InputInlineQueryResultAnimatedMpeg4(InputInlineQueryResultAnimatedMpeg4),
-    InputInlineQueryResultArticle(InputInlineQueryResultArticle),
-    InputInlineQueryResultAudio(InputInlineQueryResultAudio),
-    InputInlineQueryResultContact(InputInlineQueryResultContact),
-    InputInlineQueryResultDocument(InputInlineQueryResultDocument),
-    InputInlineQueryResultGame(InputInlineQueryResultGame),
-    InputInlineQueryResultLocation(InputInlineQueryResultLocation),
-    InputInlineQueryResultPhoto(InputInlineQueryResultPhoto),
-    InputInlineQueryResultSticker(InputInlineQueryResultSticker),
-    InputInlineQueryResultVenue(InputInlineQueryResultVenue),
-    InputInlineQueryResultVideo(InputInlineQueryResultVideo),
-    InputInlineQueryResultVoiceNote(InputInlineQueryResultVoiceNote),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum TMeUrlType {
-    TMeUrlTypeUser(TMeUrlTypeUser),
-    TMeUrlTypeSupergroup(TMeUrlTypeSupergroup),
-    TMeUrlTypeChatInvite(TMeUrlTypeChatInvite),
-    TMeUrlTypeStickerSet(TMeUrlTypeStickerSet),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum ChatAction {
-    ChatActionTyping(ChatActionTyping),
-    ChatActionRecordingVideo(ChatActionRecordingVideo),
-    ChatActionUploadingVideo(ChatActionUploadingVideo),
-    ChatActionRecordingVoiceNote(ChatActionRecordingVoiceNote),
-    ChatActionUploadingVoiceNote(ChatActionUploadingVoiceNote),
-    ChatActionUploadingPhoto(ChatActionUploadingPhoto),
-    ChatActionUploadingDocument(ChatActionUploadingDocument),
-    ChatActionChoosingLocation(ChatActionChoosingLocation),
-    ChatActionChoosingContact(ChatActionChoosingContact),
-    ChatActionStartPlayingGame(ChatActionStartPlayingGame),
-    ChatActionRecordingVideoNote(ChatActionRecordingVideoNote),
-    ChatActionUploadingVideoNote(ChatActionUploadingVideoNote),
-    ChatActionCancel(ChatActionCancel),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum UserPrivacySetting {
-    UserPrivacySettingShowStatus(UserPrivacySettingShowStatus),
-    UserPrivacySettingShowProfilePhoto(UserPrivacySettingShowProfilePhoto),
-    UserPrivacySettingShowLinkInForwardedMessages(UserPrivacySettingShowLinkInForwardedMessages),
-    UserPrivacySettingShowPhoneNumber(UserPrivacySettingShowPhoneNumber),
-    UserPrivacySettingAllowChatInvites(UserPrivacySettingAllowChatInvites),
-    UserPrivacySettingAllowCalls(UserPrivacySettingAllowCalls),
-    UserPrivacySettingAllowPeerToPeerCalls(UserPrivacySettingAllowPeerToPeerCalls),
-    UserPrivacySettingAllowFindingByPhoneNumber(UserPrivacySettingAllowFindingByPhoneNumber),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum ChatList {
-    ChatListMain(ChatListMain),
-    ChatListArchive(ChatListArchive),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum UserType {
-    UserTypeRegular(UserTypeRegular),
-    UserTypeDeleted(UserTypeDeleted),
-    UserTypeBot(UserTypeBot),
-    UserTypeUnknown(UserTypeUnknown),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum NotificationSettingsScope {
-    NotificationSettingsScopePrivateChats(NotificationSettingsScopePrivateChats),
-    NotificationSettingsScopeGroupChats(NotificationSettingsScopeGroupChats),
-    NotificationSettingsScopeChannelChats(NotificationSettingsScopeChannelChats),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum TopChatCategory {
-    TopChatCategoryUsers(TopChatCategoryUsers),
-    TopChatCategoryBots(TopChatCategoryBots),
-    TopChatCategoryGroups(TopChatCategoryGroups),
-    TopChatCategoryChannels(TopChatCategoryChannels),
-    TopChatCategoryInlineBots(TopChatCategoryInlineBots),
-    TopChatCategoryCalls(TopChatCategoryCalls),
-    TopChatCategoryForwardChats(TopChatCategoryForwardChats),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum PassportElementErrorSource {
-    PassportElementErrorSourceUnspecified(PassportElementErrorSourceUnspecified),
-    PassportElementErrorSourceDataField(PassportElementErrorSourceDataField),
-    PassportElementErrorSourceFrontSide(PassportElementErrorSourceFrontSide),
-    PassportElementErrorSourceReverseSide(PassportElementErrorSourceReverseSide),
-    PassportElementErrorSourceSelfie(PassportElementErrorSourceSelfie),
-    PassportElementErrorSourceTranslationFile(PassportElementErrorSourceTranslationFile),
-    PassportElementErrorSourceTranslationFiles(PassportElementErrorSourceTranslationFiles),
-    PassportElementErrorSourceFile(PassportElementErrorSourceFile),
-    PassportElementErrorSourceFiles(PassportElementErrorSourceFiles),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum SecretChatState {
-    SecretChatStatePending(SecretChatStatePending),
-    SecretChatStateReady(SecretChatStateReady),
-    SecretChatStateClosed(SecretChatStateClosed),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum PublicChatType {
-    PublicChatTypeHasUsername(PublicChatTypeHasUsername),
-    PublicChatTypeIsLocationBased(PublicChatTypeIsLocationBased),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum InputMessageContent {
-    InputMessageText(InputMessageText),
-    InputMessageAnimation(InputMessageAnimation),
-    InputMessageAudio(InputMessageAudio),
-    InputMessageDocument(InputMessageDocument),
-    InputMessagePhoto(InputMessagePhoto),
-    InputMessageSticker(InputMessageSticker),
-    InputMessageVideo(InputMessageVideo),
-    InputMessageVideoNote(InputMessageVideoNote),
-    InputMessageVoiceNote(InputMessageVoiceNote),
-    InputMessageLocation(InputMessageLocation),
-    InputMessageVenue(InputMessageVenue),
-    InputMessageContact(InputMessageContact),
-    InputMessageDice(InputMessageDice),
-    InputMessageGame(InputMessageGame),
-    InputMessageInvoice(InputMessageInvoice),
-    InputMessagePoll(InputMessagePoll),
-    InputMessageForwarded(InputMessageForwarded),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum InlineQueryResult {
-    InlineQueryResultArticle(InlineQueryResultArticle),
-    InlineQueryResultContact(InlineQueryResultContact),
-    InlineQueryResultLocation(InlineQueryResultLocation),
-    InlineQueryResultVenue(InlineQueryResultVenue),
-    InlineQueryResultGame(InlineQueryResultGame),
-    InlineQueryResultAnimation(InlineQueryResultAnimation),
-    InlineQueryResultAudio(InlineQueryResultAudio),
-    InlineQueryResultDocument(InlineQueryResultDocument),
-    InlineQueryResultPhoto(InlineQueryResultPhoto),
-    InlineQueryResultSticker(InlineQueryResultSticker),
-    InlineQueryResultVideo(InlineQueryResultVideo),
-    InlineQueryResultVoiceNote(InlineQueryResultVoiceNote),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum InputPassportElement {
-    InputPassportElementPersonalDetails(InputPassportElementPersonalDetails),
-    InputPassportElementPassport(InputPassportElementPassport),
-    InputPassportElementDriverLicense(InputPassportElementDriverLicense),
-    InputPassportElementIdentityCard(InputPassportElementIdentityCard),
-    InputPassportElementInternalPassport(InputPassportElementInternalPassport),
-    InputPassportElementAddress(InputPassportElementAddress),
-    InputPassportElementUtilityBill(InputPassportElementUtilityBill),
-    InputPassportElementBankStatement(InputPassportElementBankStatement),
-    InputPassportElementRentalAgreement(InputPassportElementRentalAgreement),
-    InputPassportElementPassportRegistration(InputPassportElementPassportRegistration),
-    InputPassportElementTemporaryRegistration(InputPassportElementTemporaryRegistration),
-    InputPassportElementPhoneNumber(InputPassportElementPhoneNumber),
-    InputPassportElementEmailAddress(InputPassportElementEmailAddress),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum SupergroupMembersFilter {
-    SupergroupMembersFilterRecent(SupergroupMembersFilterRecent),
-    SupergroupMembersFilterContacts(SupergroupMembersFilterContacts),
-    SupergroupMembersFilterAdministrators(SupergroupMembersFilterAdministrators),
-    SupergroupMembersFilterSearch(SupergroupMembersFilterSearch),
-    SupergroupMembersFilterRestricted(SupergroupMembersFilterRestricted),
-    SupergroupMembersFilterBanned(SupergroupMembersFilterBanned),
-    SupergroupMembersFilterBots(SupergroupMembersFilterBots),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum JsonValue {
-    JsonValueNull(JsonValueNull),
-    JsonValueBoolean(JsonValueBoolean),
-    JsonValueNumber(JsonValueNumber),
-    JsonValueString(JsonValueString),
-    JsonValueArray(JsonValueArray),
-    JsonValueObject(JsonValueObject),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum InputFile {
-    InputFileId(InputFileId),
-    InputFileRemote(InputFileRemote),
-    InputFileLocal(InputFileLocal),
-    InputFileGenerated(InputFileGenerated),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum BackgroundFill {
-    BackgroundFillSolid(BackgroundFillSolid),
-    BackgroundFillGradient(BackgroundFillGradient),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum InlineKeyboardButtonType {
-    InlineKeyboardButtonTypeUrl(InlineKeyboardButtonTypeUrl),
-    InlineKeyboardButtonTypeLoginUrl(InlineKeyboardButtonTypeLoginUrl),
-    InlineKeyboardButtonTypeCallback(InlineKeyboardButtonTypeCallback),
-    InlineKeyboardButtonTypeCallbackGame(InlineKeyboardButtonTypeCallbackGame),
-    InlineKeyboardButtonTypeSwitchInline(InlineKeyboardButtonTypeSwitchInline),
-    InlineKeyboardButtonTypeBuy(InlineKeyboardButtonTypeBuy),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum NetworkStatisticsEntry {
-    NetworkStatisticsEntryFile(NetworkStatisticsEntryFile),
-    NetworkStatisticsEntryCall(NetworkStatisticsEntryCall),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum PollType {
-    PollTypeRegular(PollTypeRegular),
-    PollTypeQuiz(PollTypeQuiz),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum ChatActionBar {
-    ChatActionBarReportSpam(ChatActionBarReportSpam),
-    ChatActionBarReportUnrelatedLocation(ChatActionBarReportUnrelatedLocation),
-    ChatActionBarReportAddBlock(ChatActionBarReportAddBlock),
-    ChatActionBarAddContact(ChatActionBarAddContact),
-    ChatActionBarSharePhoneNumber(ChatActionBarSharePhoneNumber),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum BackgroundType {
-    BackgroundTypeWallpaper(BackgroundTypeWallpaper),
-    BackgroundTypePattern(BackgroundTypePattern),
-    BackgroundTypeFill(BackgroundTypeFill),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum PassportElementType {
-    PassportElementTypePersonalDetails(PassportElementTypePersonalDetails),
-    PassportElementTypePassport(PassportElementTypePassport),
-    PassportElementTypeDriverLicense(PassportElementTypeDriverLicense),
-    PassportElementTypeIdentityCard(PassportElementTypeIdentityCard),
-    PassportElementTypeInternalPassport(PassportElementTypeInternalPassport),
-    PassportElementTypeAddress(PassportElementTypeAddress),
-    PassportElementTypeUtilityBill(PassportElementTypeUtilityBill),
-    PassportElementTypeBankStatement(PassportElementTypeBankStatement),
-    PassportElementTypeRentalAgreement(PassportElementTypeRentalAgreement),
-    PassportElementTypePassportRegistration(PassportElementTypePassportRegistration),
-    PassportElementTypeTemporaryRegistration(PassportElementTypeTemporaryRegistration),
-    PassportElementTypePhoneNumber(PassportElementTypePhoneNumber),
-    PassportElementTypeEmailAddress(PassportElementTypeEmailAddress),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum DeviceToken {
-    DeviceTokenFirebaseCloudMessaging(DeviceTokenFirebaseCloudMessaging),
-    DeviceTokenApplePush(DeviceTokenApplePush),
-    DeviceTokenApplePushVoIP(DeviceTokenApplePushVoIP),
-    DeviceTokenWindowsPush(DeviceTokenWindowsPush),
-    DeviceTokenMicrosoftPush(DeviceTokenMicrosoftPush),
-    DeviceTokenMicrosoftPushVoIP(DeviceTokenMicrosoftPushVoIP),
-    DeviceTokenWebPush(DeviceTokenWebPush),
-    DeviceTokenSimplePush(DeviceTokenSimplePush),
-    DeviceTokenUbuntuPush(DeviceTokenUbuntuPush),
-    DeviceTokenBlackBerryPush(DeviceTokenBlackBerryPush),
-    DeviceTokenTizenPush(DeviceTokenTizenPush),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum CheckChatUsernameResult {
-    CheckChatUsernameResultOk(CheckChatUsernameResultOk),
-    CheckChatUsernameResultUsernameInvalid(CheckChatUsernameResultUsernameInvalid),
-    CheckChatUsernameResultUsernameOccupied(CheckChatUsernameResultUsernameOccupied),
-    CheckChatUsernameResultPublicChatsTooMuch(CheckChatUsernameResultPublicChatsTooMuch),
-    CheckChatUsernameResultPublicGroupsUnavailable(CheckChatUsernameResultPublicGroupsUnavailable),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum MessageSchedulingState {
-    MessageSchedulingStateSendAtDate(MessageSchedulingStateSendAtDate),
-    MessageSchedulingStateSendWhenOnline(MessageSchedulingStateSendWhenOnline),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum StatisticsGraph {
-    StatisticsGraphData(StatisticsGraphData),
-    StatisticsGraphAsync(StatisticsGraphAsync),
-    StatisticsGraphError(StatisticsGraphError),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum TextParseMode {
-    TextParseModeMarkdown(TextParseModeMarkdown),
-    TextParseModeHTML(TextParseModeHTML),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum NotificationType {
-    NotificationTypeNewMessage(NotificationTypeNewMessage),
-    NotificationTypeNewSecretChat(NotificationTypeNewSecretChat),
-    NotificationTypeNewCall(NotificationTypeNewCall),
-    NotificationTypeNewPushMessage(NotificationTypeNewPushMessage),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum ConnectionState {
-    ConnectionStateWaitingForNetwork(ConnectionStateWaitingForNetwork),
-    ConnectionStateConnectingToProxy(ConnectionStateConnectingToProxy),
-    ConnectionStateConnecting(ConnectionStateConnecting),
-    ConnectionStateUpdating(ConnectionStateUpdating),
-    ConnectionStateReady(ConnectionStateReady),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum CallProblem {
-    CallProblemEcho(CallProblemEcho),
-    CallProblemNoise(CallProblemNoise),
-    CallProblemInterruptions(CallProblemInterruptions),
-    CallProblemDistortedSpeech(CallProblemDistortedSpeech),
-    CallProblemSilentLocal(CallProblemSilentLocal),
-    CallProblemSilentRemote(CallProblemSilentRemote),
-    CallProblemDropped(CallProblemDropped),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum InputCredentials {
-    InputCredentialsSaved(InputCredentialsSaved),
-    InputCredentialsNew(InputCredentialsNew),
-    InputCredentialsAndroidPay(InputCredentialsAndroidPay),
-    InputCredentialsApplePay(InputCredentialsApplePay),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum NotificationGroupType {
-    NotificationGroupTypeMessages(NotificationGroupTypeMessages),
-    NotificationGroupTypeMentions(NotificationGroupTypeMentions),
-    NotificationGroupTypeSecretChat(NotificationGroupTypeSecretChat),
-    NotificationGroupTypeCalls(NotificationGroupTypeCalls),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum LogStream {
-    LogStreamDefault(LogStreamDefault),
-    LogStreamFile(LogStreamFile),
-    LogStreamEmpty(LogStreamEmpty),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum ChatReportReason {
-    ChatReportReasonSpam(ChatReportReasonSpam),
-    ChatReportReasonViolence(ChatReportReasonViolence),
-    ChatReportReasonPornography(ChatReportReasonPornography),
-    ChatReportReasonChildAbuse(ChatReportReasonChildAbuse),
-    ChatReportReasonCopyright(ChatReportReasonCopyright),
-    ChatReportReasonUnrelatedLocation(ChatReportReasonUnrelatedLocation),
-    ChatReportReasonCustom(ChatReportReasonCustom),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum Update {
-    UpdateAuthorizationState(UpdateAuthorizationState),
-    UpdateNewMessage(UpdateNewMessage),
-    UpdateMessageSendAcknowledged(UpdateMessageSendAcknowledged),
-    UpdateMessageSendSucceeded(UpdateMessageSendSucceeded),
-    UpdateMessageSendFailed(UpdateMessageSendFailed),
-    UpdateMessageContent(UpdateMessageContent),
-    UpdateMessageEdited(UpdateMessageEdited),
-    UpdateMessageViews(UpdateMessageViews),
-    UpdateMessageContentOpened(UpdateMessageContentOpened),
-    UpdateMessageMentionRead(UpdateMessageMentionRead),
-    UpdateMessageLiveLocationViewed(UpdateMessageLiveLocationViewed),
-    UpdateNewChat(UpdateNewChat),
-    UpdateChatChatList(UpdateChatChatList),
-    UpdateChatTitle(UpdateChatTitle),
-    UpdateChatPhoto(UpdateChatPhoto),
-    UpdateChatPermissions(UpdateChatPermissions),
-    UpdateChatLastMessage(UpdateChatLastMessage),
-    UpdateChatOrder(UpdateChatOrder),
-    UpdateChatIsPinned(UpdateChatIsPinned),
-    UpdateChatIsMarkedAsUnread(UpdateChatIsMarkedAsUnread),
-    UpdateChatSource(UpdateChatSource),
-    UpdateChatHasScheduledMessages(UpdateChatHasScheduledMessages),
-    UpdateChatDefaultDisableNotification(UpdateChatDefaultDisableNotification),
-    UpdateChatReadInbox(UpdateChatReadInbox),
-    UpdateChatReadOutbox(UpdateChatReadOutbox),
-    UpdateChatUnreadMentionCount(UpdateChatUnreadMentionCount),
-    UpdateChatNotificationSettings(UpdateChatNotificationSettings),
-    UpdateScopeNotificationSettings(UpdateScopeNotificationSettings),
-    UpdateChatActionBar(UpdateChatActionBar),
-    UpdateChatPinnedMessage(UpdateChatPinnedMessage),
-    UpdateChatReplyMarkup(UpdateChatReplyMarkup),
-    UpdateChatDraftMessage(UpdateChatDraftMessage),
-    UpdateChatOnlineMemberCount(UpdateChatOnlineMemberCount),
-    UpdateNotification(UpdateNotification),
-    UpdateNotificationGroup(UpdateNotificationGroup),
-    UpdateActiveNotifications(UpdateActiveNotifications),
-    UpdateHavePendingNotifications(UpdateHavePendingNotifications),
-    UpdateDeleteMessages(UpdateDeleteMessages),
-    UpdateUserChatAction(UpdateUserChatAction),
-    UpdateUserStatus(UpdateUserStatus),
-    UpdateUser(UpdateUser),
-    UpdateBasicGroup(UpdateBasicGroup),
-    UpdateSupergroup(UpdateSupergroup),
-    UpdateSecretChat(UpdateSecretChat),
-    UpdateUserFullInfo(UpdateUserFullInfo),
-    UpdateBasicGroupFullInfo(UpdateBasicGroupFullInfo),
-    UpdateSupergroupFullInfo(UpdateSupergroupFullInfo),
-    UpdateServiceNotification(UpdateServiceNotification),
-    UpdateFile(UpdateFile),
-    UpdateFileGenerationStart(UpdateFileGenerationStart),
-    UpdateFileGenerationStop(UpdateFileGenerationStop),
-    UpdateCall(UpdateCall),
-    UpdateUserPrivacySettingRules(UpdateUserPrivacySettingRules),
-    UpdateUnreadMessageCount(UpdateUnreadMessageCount),
-    UpdateUnreadChatCount(UpdateUnreadChatCount),
-    UpdateOption(UpdateOption),
-    UpdateStickerSet(UpdateStickerSet),
-    UpdateInstalledStickerSets(UpdateInstalledStickerSets),
-    UpdateTrendingStickerSets(UpdateTrendingStickerSets),
-    UpdateRecentStickers(UpdateRecentStickers),
-    UpdateFavoriteStickers(UpdateFavoriteStickers),
-    UpdateSavedAnimations(UpdateSavedAnimations),
-    UpdateSelectedBackground(UpdateSelectedBackground),
-    UpdateLanguagePackStrings(UpdateLanguagePackStrings),
-    UpdateConnectionState(UpdateConnectionState),
-    UpdateTermsOfService(UpdateTermsOfService),
-    UpdateUsersNearby(UpdateUsersNearby),
-    UpdateDiceEmojis(UpdateDiceEmojis),
-    UpdateNewInlineQuery(UpdateNewInlineQuery),
-    UpdateNewChosenInlineResult(UpdateNewChosenInlineResult),
-    UpdateNewCallbackQuery(UpdateNewCallbackQuery),
-    UpdateNewInlineCallbackQuery(UpdateNewInlineCallbackQuery),
-    UpdateNewShippingQuery(UpdateNewShippingQuery),
-    UpdateNewPreCheckoutQuery(UpdateNewPreCheckoutQuery),
-    UpdateNewCustomEvent(UpdateNewCustomEvent),
-    UpdateNewCustomQuery(UpdateNewCustomQuery),
-    UpdatePoll(UpdatePoll),
-    UpdatePollAnswer(UpdatePollAnswer),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum CallDiscardReason {
-    CallDiscardReasonEmpty(CallDiscardReasonEmpty),
-    CallDiscardReasonMissed(CallDiscardReasonMissed),
-    CallDiscardReasonDeclined(CallDiscardReasonDeclined),
-    CallDiscardReasonDisconnected(CallDiscardReasonDisconnected),
-    CallDiscardReasonHungUp(CallDiscardReasonHungUp),
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "@type")]
-#[doc = ""]
-pub enum CallbackQueryPayload {
-    CallbackQueryPayloadData(CallbackQueryPayloadData),
-    CallbackQueryPayloadGame(CallbackQueryPayloadGame),
+tagged_enum! {
+    pub enum AuthenticationCodeType {
+        AuthenticationCodeTypeTelegramMessage(AuthenticationCodeTypeTelegramMessage),
+        AuthenticationCodeTypeSms(AuthenticationCodeTypeSms),
+        AuthenticationCodeTypeCall(AuthenticationCodeTypeCall),
+        AuthenticationCodeTypeFlashCall(AuthenticationCodeTypeFlashCall),
+    }
+}
+tagged_enum! {
+    pub enum AuthorizationState {
+        AuthorizationStateWaitTdlibParameters(AuthorizationStateWaitTdlibParameters),
+        AuthorizationStateWaitEncryptionKey(AuthorizationStateWaitEncryptionKey),
+        AuthorizationStateWaitPhoneNumber(AuthorizationStateWaitPhoneNumber),
+        AuthorizationStateWaitCode(AuthorizationStateWaitCode),
+        AuthorizationStateWaitOtherDeviceConfirmation(AuthorizationStateWaitOtherDeviceConfirmation),
+        AuthorizationStateWaitRegistration(AuthorizationStateWaitRegistration),
+        AuthorizationStateWaitPassword(AuthorizationStateWaitPassword),
+        AuthorizationStateReady(AuthorizationStateReady),
+        AuthorizationStateLoggingOut(AuthorizationStateLoggingOut),
+        AuthorizationStateClosing(AuthorizationStateClosing),
+        AuthorizationStateClosed(AuthorizationStateClosed),
+    }
+}
+tagged_enum! {
+    pub enum InputFile {
+        InputFileId(InputFileId),
+        InputFileRemote(InputFileRemote),
+        InputFileLocal(InputFileLocal),
+        InputFileGenerated(InputFileGenerated),
+    }
+}
+tagged_enum! {
+    pub enum MaskPoint {
+        MaskPointForehead(MaskPointForehead),
+        MaskPointEyes(MaskPointEyes),
+        MaskPointMouth(MaskPointMouth),
+        MaskPointChin(MaskPointChin),
+    }
+}
+tagged_enum! {
+    pub enum PollType {
+        PollTypeRegular(PollTypeRegular),
+        PollTypeQuiz(PollTypeQuiz),
+    }
+}
+tagged_enum! {
+    pub enum UserType {
+        UserTypeRegular(UserTypeRegular),
+        UserTypeDeleted(UserTypeDeleted),
+        UserTypeBot(UserTypeBot),
+        UserTypeUnknown(UserTypeUnknown),
+    }
+}
+tagged_enum! {
+    pub enum ChatMemberStatus {
+        ChatMemberStatusCreator(ChatMemberStatusCreator),
+        ChatMemberStatusAdministrator(ChatMemberStatusAdministrator),
+        ChatMemberStatusMember(ChatMemberStatusMember),
+        ChatMemberStatusRestricted(ChatMemberStatusRestricted),
+        ChatMemberStatusLeft(ChatMemberStatusLeft),
+        ChatMemberStatusBanned(ChatMemberStatusBanned),
+    }
+}
+tagged_enum! {
+    pub enum ChatMembersFilter {
+        ChatMembersFilterContacts(ChatMembersFilterContacts),
+        ChatMembersFilterAdministrators(ChatMembersFilterAdministrators),
+        ChatMembersFilterMembers(ChatMembersFilterMembers),
+        ChatMembersFilterRestricted(ChatMembersFilterRestricted),
+        ChatMembersFilterBanned(ChatMembersFilterBanned),
+        ChatMembersFilterBots(ChatMembersFilterBots),
+    }
+}
+tagged_enum! {
+    pub enum SupergroupMembersFilter {
+        SupergroupMembersFilterRecent(SupergroupMembersFilterRecent),
+        SupergroupMembersFilterContacts(SupergroupMembersFilterContacts),
+        SupergroupMembersFilterAdministrators(SupergroupMembersFilterAdministrators),
+        SupergroupMembersFilterSearch(SupergroupMembersFilterSearch),
+        SupergroupMembersFilterRestricted(SupergroupMembersFilterRestricted),
+        SupergroupMembersFilterBanned(SupergroupMembersFilterBanned),
+        SupergroupMembersFilterBots(SupergroupMembersFilterBots),
+    }
+}
+tagged_enum! {
+    pub enum SecretChatState {
+        SecretChatStatePending(SecretChatStatePending),
+        SecretChatStateReady(SecretChatStateReady),
+        SecretChatStateClosed(SecretChatStateClosed),
+    }
+}
+tagged_enum! {
+    pub enum MessageForwardOrigin {
+        MessageForwardOriginUser(MessageForwardOriginUser),
+        MessageForwardOriginHiddenUser(MessageForwardOriginHiddenUser),
+        MessageForwardOriginChannel(MessageForwardOriginChannel),
+    }
+}
+tagged_enum! {
+    pub enum MessageSendingState {
+        MessageSendingStatePending(MessageSendingStatePending),
+        MessageSendingStateFailed(MessageSendingStateFailed),
+    }
+}
+tagged_enum! {
+    pub enum NotificationSettingsScope {
+        NotificationSettingsScopePrivateChats(NotificationSettingsScopePrivateChats),
+        NotificationSettingsScopeGroupChats(NotificationSettingsScopeGroupChats),
+        NotificationSettingsScopeChannelChats(NotificationSettingsScopeChannelChats),
+    }
+}
+tagged_enum! {
+    pub enum ChatType {
+        ChatTypePrivate(ChatTypePrivate),
+        ChatTypeBasicGroup(ChatTypeBasicGroup),
+        ChatTypeSupergroup(ChatTypeSupergroup),
+        ChatTypeSecret(ChatTypeSecret),
+    }
+}
+tagged_enum! {
+    pub enum ChatList {
+        ChatListMain(ChatListMain),
+        ChatListArchive(ChatListArchive),
+    }
+}
+tagged_enum! {
+    pub enum ChatSource {
+        ChatSourceMtprotoProxy(ChatSourceMtprotoProxy),
+        ChatSourcePublicServiceAnnouncement(ChatSourcePublicServiceAnnouncement),
+    }
+}
+tagged_enum! {
+    pub enum PublicChatType {
+        PublicChatTypeHasUsername(PublicChatTypeHasUsername),
+        PublicChatTypeIsLocationBased(PublicChatTypeIsLocationBased),
+    }
+}
+tagged_enum! {
+    pub enum ChatActionBar {
+        ChatActionBarReportSpam(ChatActionBarReportSpam),
+        ChatActionBarReportUnrelatedLocation(ChatActionBarReportUnrelatedLocation),
+        ChatActionBarReportAddBlock(ChatActionBarReportAddBlock),
+        ChatActionBarAddContact(ChatActionBarAddContact),
+        ChatActionBarSharePhoneNumber(ChatActionBarSharePhoneNumber),
+    }
+}
+tagged_enum! {
+    pub enum KeyboardButtonType {
+        KeyboardButtonTypeText(KeyboardButtonTypeText),
+        KeyboardButtonTypeRequestPhoneNumber(KeyboardButtonTypeRequestPhoneNumber),
+        KeyboardButtonTypeRequestLocation(KeyboardButtonTypeRequestLocation),
+        KeyboardButtonTypeRequestPoll(KeyboardButtonTypeRequestPoll),
+    }
+}
+tagged_enum! {
+    pub enum InlineKeyboardButtonType {
+        InlineKeyboardButtonTypeUrl(InlineKeyboardButtonTypeUrl),
+        InlineKeyboardButtonTypeLoginUrl(InlineKeyboardButtonTypeLoginUrl),
+        InlineKeyboardButtonTypeCallback(InlineKeyboardButtonTypeCallback),
+        InlineKeyboardButtonTypeCallbackGame(InlineKeyboardButtonTypeCallbackGame),
+        InlineKeyboardButtonTypeSwitchInline(InlineKeyboardButtonTypeSwitchInline),
+        InlineKeyboardButtonTypeBuy(InlineKeyboardButtonTypeBuy),
+    }
+}
+tagged_enum! {
+    pub enum ReplyMarkup {
+        ReplyMarkupRemoveKeyboard(ReplyMarkupRemoveKeyboard),
+        ReplyMarkupForceReply(ReplyMarkupForceReply),
+        ReplyMarkupShowKeyboard(ReplyMarkupShowKeyboard),
+        ReplyMarkupInlineKeyboard(ReplyMarkupInlineKeyboard),
+    }
+}
+tagged_enum! {
+    pub enum LoginUrlInfo {
+        LoginUrlInfoOpen(LoginUrlInfoOpen),
+        LoginUrlInfoRequestConfirmation(LoginUrlInfoRequestConfirmation),
+    }
+}
+tagged_enum! {
+    pub enum RichText {
+        RichTextPlain(RichTextPlain),
+        RichTextBold(RichTextBold),
+        RichTextItalic(RichTextItalic),
+        RichTextUnderline(RichTextUnderline),
+        RichTextStrikethrough(RichTextStrikethrough),
+        RichTextFixed(RichTextFixed),
+        RichTextUrl(RichTextUrl),
+        RichTextEmailAddress(RichTextEmailAddress),
+        RichTextSubscript(RichTextSubscript),
+        RichTextSuperscript(RichTextSuperscript),
+        RichTextMarked(RichTextMarked),
+        RichTextPhoneNumber(RichTextPhoneNumber),
+        RichTextIcon(RichTextIcon),
+        RichTextReference(RichTextReference),
+        RichTextAnchor(RichTextAnchor),
+        RichTextAnchorLink(RichTextAnchorLink),
+        RichTexts(RichTexts),
+    }
+}
+tagged_enum! {
+    pub enum PageBlockHorizontalAlignment {
+        PageBlockHorizontalAlignmentLeft(PageBlockHorizontalAlignmentLeft),
+        PageBlockHorizontalAlignmentCenter(PageBlockHorizontalAlignmentCenter),
+        PageBlockHorizontalAlignmentRight(PageBlockHorizontalAlignmentRight),
+    }
+}
+tagged_enum! {
+    pub enum PageBlockVerticalAlignment {
+        PageBlockVerticalAlignmentTop(PageBlockVerticalAlignmentTop),
+        PageBlockVerticalAlignmentMiddle(PageBlockVerticalAlignmentMiddle),
+        PageBlockVerticalAlignmentBottom(PageBlockVerticalAlignmentBottom),
+    }
+}
+tagged_enum! {
+    pub enum PageBlock {
+        PageBlockTitle(PageBlockTitle),
+        PageBlockSubtitle(PageBlockSubtitle),
+        PageBlockAuthorDate(PageBlockAuthorDate),
+        PageBlockHeader(PageBlockHeader),
+        PageBlockSubheader(PageBlockSubheader),
+        PageBlockKicker(PageBlockKicker),
+        PageBlockParagraph(PageBlockParagraph),
+        PageBlockPreformatted(PageBlockPreformatted),
+        PageBlockFooter(PageBlockFooter),
+        PageBlockDivider(PageBlockDivider),
+        PageBlockAnchor(PageBlockAnchor),
+        PageBlockList(PageBlockList),
+        PageBlockBlockQuote(PageBlockBlockQuote),
+        PageBlockPullQuote(PageBlockPullQuote),
+        PageBlockAnimation(PageBlockAnimation),
+        PageBlockAudio(PageBlockAudio),
+        PageBlockPhoto(PageBlockPhoto),
+        PageBlockVideo(PageBlockVideo),
+        PageBlockVoiceNote(PageBlockVoiceNote),
+        PageBlockCover(PageBlockCover),
+        PageBlockEmbedded(PageBlockEmbedded),
+        PageBlockEmbeddedPost(PageBlockEmbeddedPost),
+        PageBlockCollage(PageBlockCollage),
+        PageBlockSlideshow(PageBlockSlideshow),
+        PageBlockChatLink(PageBlockChatLink),
+        PageBlockTable(PageBlockTable),
+        PageBlockDetails(PageBlockDetails),
+        PageBlockRelatedArticles(PageBlockRelatedArticles),
+        PageBlockMap(PageBlockMap),
+    }
+}
+tagged_enum! {
+    pub enum InputCredentials {
+        InputCredentialsSaved(InputCredentialsSaved),
+        InputCredentialsNew(InputCredentialsNew),
+        InputCredentialsAndroidPay(InputCredentialsAndroidPay),
+        InputCredentialsApplePay(InputCredentialsApplePay),
+    }
+}
+tagged_enum! {
+    pub enum PassportElementType {
+        PassportElementTypePersonalDetails(PassportElementTypePersonalDetails),
+        PassportElementTypePassport(PassportElementTypePassport),
+        PassportElementTypeDriverLicense(PassportElementTypeDriverLicense),
+        PassportElementTypeIdentityCard(PassportElementTypeIdentityCard),
+        PassportElementTypeInternalPassport(PassportElementTypeInternalPassport),
+        PassportElementTypeAddress(PassportElementTypeAddress),
+        PassportElementTypeUtilityBill(PassportElementTypeUtilityBill),
+        PassportElementTypeBankStatement(PassportElementTypeBankStatement),
+        PassportElementTypeRentalAgreement(PassportElementTypeRentalAgreement),
+        PassportElementTypePassportRegistration(PassportElementTypePassportRegistration),
+        PassportElementTypeTemporaryRegistration(PassportElementTypeTemporaryRegistration),
+        PassportElementTypePhoneNumber(PassportElementTypePhoneNumber),
+        PassportElementTypeEmailAddress(PassportElementTypeEmailAddress),
+    }
+}
+tagged_enum! {
+    pub enum PassportElement {
+        PassportElementPersonalDetails(PassportElementPersonalDetails),
+        PassportElementPassport(PassportElementPassport),
+        PassportElementDriverLicense(PassportElementDriverLicense),
+        PassportElementIdentityCard(PassportElementIdentityCard),
+        PassportElementInternalPassport(PassportElementInternalPassport),
+        PassportElementAddress(PassportElementAddress),
+        PassportElementUtilityBill(PassportElementUtilityBill),
+        PassportElementBankStatement(PassportElementBankStatement),
+        PassportElementRentalAgreement(PassportElementRentalAgreement),
+        PassportElementPassportRegistration(PassportElementPassportRegistration),
+        PassportElementTemporaryRegistration(PassportElementTemporaryRegistration),
+        PassportElementPhoneNumber(PassportElementPhoneNumber),
+        PassportElementEmailAddress(PassportElementEmailAddress),
+    }
+}
+tagged_enum! {
+    pub enum InputPassportElement {
+        InputPassportElementPersonalDetails(InputPassportElementPersonalDetails),
+        InputPassportElementPassport(InputPassportElementPassport),
+        InputPassportElementDriverLicense(InputPassportElementDriverLicense),
+        InputPassportElementIdentityCard(InputPassportElementIdentityCard),
+        InputPassportElementInternalPassport(InputPassportElementInternalPassport),
+        InputPassportElementAddress(InputPassportElementAddress),
+        InputPassportElementUtilityBill(InputPassportElementUtilityBill),
+        InputPassportElementBankStatement(InputPassportElementBankStatement),
+        InputPassportElementRentalAgreement(InputPassportElementRentalAgreement),
+        InputPassportElementPassportRegistration(InputPassportElementPassportRegistration),
+        InputPassportElementTemporaryRegistration(InputPassportElementTemporaryRegistration),
+        InputPassportElementPhoneNumber(InputPassportElementPhoneNumber),
+        InputPassportElementEmailAddress(InputPassportElementEmailAddress),
+    }
+}
+tagged_enum! {
+    pub enum PassportElementErrorSource {
+        PassportElementErrorSourceUnspecified(PassportElementErrorSourceUnspecified),
+        PassportElementErrorSourceDataField(PassportElementErrorSourceDataField),
+        PassportElementErrorSourceFrontSide(PassportElementErrorSourceFrontSide),
+        PassportElementErrorSourceReverseSide(PassportElementErrorSourceReverseSide),
+        PassportElementErrorSourceSelfie(PassportElementErrorSourceSelfie),
+        PassportElementErrorSourceTranslationFile(PassportElementErrorSourceTranslationFile),
+        PassportElementErrorSourceTranslationFiles(PassportElementErrorSourceTranslationFiles),
+        PassportElementErrorSourceFile(PassportElementErrorSourceFile),
+        PassportElementErrorSourceFiles(PassportElementErrorSourceFiles),
+    }
+}
+tagged_enum! {
+    pub enum InputPassportElementErrorSource {
+        InputPassportElementErrorSourceUnspecified(InputPassportElementErrorSourceUnspecified),
+        InputPassportElementErrorSourceDataField(InputPassportElementErrorSourceDataField),
+        InputPassportElementErrorSourceFrontSide(InputPassportElementErrorSourceFrontSide),
+        InputPassportElementErrorSourceReverseSide(InputPassportElementErrorSourceReverseSide),
+        InputPassportElementErrorSourceSelfie(InputPassportElementErrorSourceSelfie),
+        InputPassportElementErrorSourceTranslationFile(InputPassportElementErrorSourceTranslationFile),
+        InputPassportElementErrorSourceTranslationFiles(InputPassportElementErrorSourceTranslationFiles),
+        InputPassportElementErrorSourceFile(InputPassportElementErrorSourceFile),
+        InputPassportElementErrorSourceFiles(InputPassportElementErrorSourceFiles),
+    }
+}
+tagged_enum! {
+    pub enum MessageContent {
+        MessageText(MessageText),
+        MessageAnimation(MessageAnimation),
+        MessageAudio(MessageAudio),
+        MessageDocument(MessageDocument),
+        MessagePhoto(MessagePhoto),
+        MessageExpiredPhoto(MessageExpiredPhoto),
+        MessageSticker(MessageSticker),
+        MessageVideo(MessageVideo),
+        MessageExpiredVideo(MessageExpiredVideo),
+        MessageVideoNote(MessageVideoNote),
+        MessageVoiceNote(MessageVoiceNote),
+        MessageLocation(MessageLocation),
+        MessageVenue(MessageVenue),
+        MessageContact(MessageContact),
+        MessageDice(MessageDice),
+        MessageGame(MessageGame),
+        MessagePoll(MessagePoll),
+        MessageInvoice(MessageInvoice),
+        MessageCall(MessageCall),
+        MessageBasicGroupChatCreate(MessageBasicGroupChatCreate),
+        MessageSupergroupChatCreate(MessageSupergroupChatCreate),
+        MessageChatChangeTitle(MessageChatChangeTitle),
+        MessageChatChangePhoto(MessageChatChangePhoto),
+        MessageChatDeletePhoto(MessageChatDeletePhoto),
+        MessageChatAddMembers(MessageChatAddMembers),
+        MessageChatJoinByLink(MessageChatJoinByLink),
+        MessageChatDeleteMember(MessageChatDeleteMember),
+        MessageChatUpgradeTo(MessageChatUpgradeTo),
+        MessageChatUpgradeFrom(MessageChatUpgradeFrom),
+        MessagePinMessage(MessagePinMessage),
+        MessageScreenshotTaken(MessageScreenshotTaken),
+        MessageChatSetTtl(MessageChatSetTtl),
+        MessageCustomServiceAction(MessageCustomServiceAction),
+        MessageGameScore(MessageGameScore),
+        MessagePaymentSuccessful(MessagePaymentSuccessful),
+        MessagePaymentSuccessfulBot(MessagePaymentSuccessfulBot),
+        MessageContactRegistered(MessageContactRegistered),
+        MessageWebsiteConnected(MessageWebsiteConnected),
+        MessagePassportDataSent(MessagePassportDataSent),
+        MessagePassportDataReceived(MessagePassportDataReceived),
+        MessageUnsupported(MessageUnsupported),
+    }
+}
+tagged_enum! {
+    pub enum TextEntityType {
+        TextEntityTypeMention(TextEntityTypeMention),
+        TextEntityTypeHashtag(TextEntityTypeHashtag),
+        TextEntityTypeCashtag(TextEntityTypeCashtag),
+        TextEntityTypeBotCommand(TextEntityTypeBotCommand),
+        TextEntityTypeUrl(TextEntityTypeUrl),
+        TextEntityTypeEmailAddress(TextEntityTypeEmailAddress),
+        TextEntityTypePhoneNumber(TextEntityTypePhoneNumber),
+        TextEntityTypeBankCardNumber(TextEntityTypeBankCardNumber),
+        TextEntityTypeBold(TextEntityTypeBold),
+        TextEntityTypeItalic(TextEntityTypeItalic),
+        TextEntityTypeUnderline(TextEntityTypeUnderline),
+        TextEntityTypeStrikethrough(TextEntityTypeStrikethrough),
+        TextEntityTypeCode(TextEntityTypeCode),
+        TextEntityTypePre(TextEntityTypePre),
+        TextEntityTypePreCode(TextEntityTypePreCode),
+        TextEntityTypeTextUrl(TextEntityTypeTextUrl),
+        TextEntityTypeMentionName(TextEntityTypeMentionName),
+    }
+}
+tagged_enum! {
+    pub enum MessageSchedulingState {
+        MessageSchedulingStateSendAtDate(MessageSchedulingStateSendAtDate),
+        MessageSchedulingStateSendWhenOnline(MessageSchedulingStateSendWhenOnline),
+    }
+}
+tagged_enum! {
+    pub enum InputMessageContent {
+        InputMessageText(InputMessageText),
+        InputMessageAnimation(InputMessageAnimation),
+        InputMessageAudio(InputMessageAudio),
+        InputMessageDocument(InputMessageDocument),
+        InputMessagePhoto(InputMessagePhoto),
+        InputMessageSticker(InputMessageSticker),
+        InputMessageVideo(InputMessageVideo),
+        InputMessageVideoNote(InputMessageVideoNote),
+        InputMessageVoiceNote(InputMessageVoiceNote),
+        InputMessageLocation(InputMessageLocation),
+        InputMessageVenue(InputMessageVenue),
+        InputMessageContact(InputMessageContact),
+        InputMessageDice(InputMessageDice),
+        InputMessageGame(InputMessageGame),
+        InputMessageInvoice(InputMessageInvoice),
+        InputMessagePoll(InputMessagePoll),
+        InputMessageForwarded(InputMessageForwarded),
+    }
+}
+tagged_enum! {
+    pub enum SearchMessagesFilter {
+        SearchMessagesFilterEmpty(SearchMessagesFilterEmpty),
+        SearchMessagesFilterAnimation(SearchMessagesFilterAnimation),
+        SearchMessagesFilterAudio(SearchMessagesFilterAudio),
+        SearchMessagesFilterDocument(SearchMessagesFilterDocument),
+        SearchMessagesFilterPhoto(SearchMessagesFilterPhoto),
+        SearchMessagesFilterVideo(SearchMessagesFilterVideo),
+        SearchMessagesFilterVoiceNote(SearchMessagesFilterVoiceNote),
+        SearchMessagesFilterPhotoAndVideo(SearchMessagesFilterPhotoAndVideo),
+        SearchMessagesFilterUrl(SearchMessagesFilterUrl),
+        SearchMessagesFilterChatPhoto(SearchMessagesFilterChatPhoto),
+        SearchMessagesFilterCall(SearchMessagesFilterCall),
+        SearchMessagesFilterMissedCall(SearchMessagesFilterMissedCall),
+        SearchMessagesFilterVideoNote(SearchMessagesFilterVideoNote),
+        SearchMessagesFilterVoiceAndVideoNote(SearchMessagesFilterVoiceAndVideoNote),
+        SearchMessagesFilterMention(SearchMessagesFilterMention),
+        SearchMessagesFilterUnreadMention(SearchMessagesFilterUnreadMention),
+        SearchMessagesFilterFailedToSend(SearchMessagesFilterFailedToSend),
+    }
+}
+tagged_enum! {
+    pub enum ChatAction {
+        ChatActionTyping(ChatActionTyping),
+        ChatActionRecordingVideo(ChatActionRecordingVideo),
+        ChatActionUploadingVideo(ChatActionUploadingVideo),
+        ChatActionRecordingVoiceNote(ChatActionRecordingVoiceNote),
+        ChatActionUploadingVoiceNote(ChatActionUploadingVoiceNote),
+        ChatActionUploadingPhoto(ChatActionUploadingPhoto),
+        ChatActionUploadingDocument(ChatActionUploadingDocument),
+        ChatActionChoosingLocation(ChatActionChoosingLocation),
+        ChatActionChoosingContact(ChatActionChoosingContact),
+        ChatActionStartPlayingGame(ChatActionStartPlayingGame),
+        ChatActionRecordingVideoNote(ChatActionRecordingVideoNote),
+        ChatActionUploadingVideoNote(ChatActionUploadingVideoNote),
+        ChatActionCancel(ChatActionCancel),
+    }
+}
+tagged_enum! {
+    pub enum UserStatus {
+        UserStatusEmpty(UserStatusEmpty),
+        UserStatusOnline(UserStatusOnline),
+        UserStatusOffline(UserStatusOffline),
+        UserStatusRecently(UserStatusRecently),
+        UserStatusLastWeek(UserStatusLastWeek),
+        UserStatusLastMonth(UserStatusLastMonth),
+    }
+}
+tagged_enum! {
+    pub enum CallDiscardReason {
+        CallDiscardReasonEmpty(CallDiscardReasonEmpty),
+        CallDiscardReasonMissed(CallDiscardReasonMissed),
+        CallDiscardReasonDeclined(CallDiscardReasonDeclined),
+        CallDiscardReasonDisconnected(CallDiscardReasonDisconnected),
+        CallDiscardReasonHungUp(CallDiscardReasonHungUp),
+    }
+}
+tagged_enum! {
+    pub enum CallState {
+        CallStatePending(CallStatePending),
+        CallStateExchangingKeys(CallStateExchangingKeys),
+        CallStateReady(CallStateReady),
+        CallStateHangingUp(CallStateHangingUp),
+        CallStateDiscarded(CallStateDiscarded),
+        CallStateError(CallStateError),
+    }
+}
+tagged_enum! {
+    pub enum CallProblem {
+        CallProblemEcho(CallProblemEcho),
+        CallProblemNoise(CallProblemNoise),
+        CallProblemInterruptions(CallProblemInterruptions),
+        CallProblemDistortedSpeech(CallProblemDistortedSpeech),
+        CallProblemSilentLocal(CallProblemSilentLocal),
+        CallProblemSilentRemote(CallProblemSilentRemote),
+        CallProblemDropped(CallProblemDropped),
+    }
+}
+tagged_enum! {
+    pub enum InputInlineQueryResult {
+        InputInlineQueryResultAnimatedGif(InputInlineQueryResultAnimatedGif),
+        InputInlineQueryResultAnimatedMpeg4(InputInlineQueryResultAnimatedMpeg4),
+        InputInlineQueryResultArticle(InputInlineQueryResultArticle),
+        InputInlineQueryResultAudio(InputInlineQueryResultAudio),
+        InputInlineQueryResultContact(InputInlineQueryResultContact),
+        InputInlineQueryResultDocument(InputInlineQueryResultDocument),
+        InputInlineQueryResultGame(InputInlineQueryResultGame),
+        InputInlineQueryResultLocation(InputInlineQueryResultLocation),
+        InputInlineQueryResultPhoto(InputInlineQueryResultPhoto),
+        InputInlineQueryResultSticker(InputInlineQueryResultSticker),
+        InputInlineQueryResultVenue(InputInlineQueryResultVenue),
+        InputInlineQueryResultVideo(InputInlineQueryResultVideo),
+        InputInlineQueryResultVoiceNote(InputInlineQueryResultVoiceNote),
+    }
+}
+tagged_enum! {
+    pub enum InlineQueryResult {
+        InlineQueryResultArticle(InlineQueryResultArticle),
+        InlineQueryResultContact(InlineQueryResultContact),
+        InlineQueryResultLocation(InlineQueryResultLocation),
+        InlineQueryResultVenue(InlineQueryResultVenue),
+        InlineQueryResultGame(InlineQueryResultGame),
+        InlineQueryResultAnimation(InlineQueryResultAnimation),
+        InlineQueryResultAudio(InlineQueryResultAudio),
+        InlineQueryResultDocument(InlineQueryResultDocument),
+        InlineQueryResultPhoto(InlineQueryResultPhoto),
+        InlineQueryResultSticker(InlineQueryResultSticker),
+        InlineQueryResultVideo(InlineQueryResultVideo),
+        InlineQueryResultVoiceNote(InlineQueryResultVoiceNote),
+    }
+}
+tagged_enum! {
+    pub enum CallbackQueryPayload {
+        CallbackQueryPayloadData(CallbackQueryPayloadData),
+        CallbackQueryPayloadGame(CallbackQueryPayloadGame),
+    }
+}
+tagged_enum! {
+    pub enum ChatEventAction {
+        ChatEventMessageEdited(ChatEventMessageEdited),
+        ChatEventMessageDeleted(ChatEventMessageDeleted),
+        ChatEventPollStopped(ChatEventPollStopped),
+        ChatEventMessagePinned(ChatEventMessagePinned),
+        ChatEventMessageUnpinned(ChatEventMessageUnpinned),
+        ChatEventMemberJoined(ChatEventMemberJoined),
+        ChatEventMemberLeft(ChatEventMemberLeft),
+        ChatEventMemberInvited(ChatEventMemberInvited),
+        ChatEventMemberPromoted(ChatEventMemberPromoted),
+        ChatEventMemberRestricted(ChatEventMemberRestricted),
+        ChatEventTitleChanged(ChatEventTitleChanged),
+        ChatEventPermissionsChanged(ChatEventPermissionsChanged),
+        ChatEventDescriptionChanged(ChatEventDescriptionChanged),
+        ChatEventUsernameChanged(ChatEventUsernameChanged),
+        ChatEventPhotoChanged(ChatEventPhotoChanged),
+        ChatEventInvitesToggled(ChatEventInvitesToggled),
+        ChatEventLinkedChatChanged(ChatEventLinkedChatChanged),
+        ChatEventSlowModeDelayChanged(ChatEventSlowModeDelayChanged),
+        ChatEventSignMessagesToggled(ChatEventSignMessagesToggled),
+        ChatEventStickerSetChanged(ChatEventStickerSetChanged),
+        ChatEventLocationChanged(ChatEventLocationChanged),
+        ChatEventIsAllHistoryAvailableToggled(ChatEventIsAllHistoryAvailableToggled),
+    }
+}
+tagged_enum! {
+    pub enum LanguagePackStringValue {
+        LanguagePackStringValueOrdinary(LanguagePackStringValueOrdinary),
+        LanguagePackStringValuePluralized(LanguagePackStringValuePluralized),
+        LanguagePackStringValueDeleted(LanguagePackStringValueDeleted),
+    }
+}
+tagged_enum! {
+    pub enum DeviceToken {
+        DeviceTokenFirebaseCloudMessaging(DeviceTokenFirebaseCloudMessaging),
+        DeviceTokenApplePush(DeviceTokenApplePush),
+        DeviceTokenApplePushVoIP(DeviceTokenApplePushVoIP),
+        DeviceTokenWindowsPush(DeviceTokenWindowsPush),
+        DeviceTokenMicrosoftPush(DeviceTokenMicrosoftPush),
+        DeviceTokenMicrosoftPushVoIP(DeviceTokenMicrosoftPushVoIP),
+        DeviceTokenWebPush(DeviceTokenWebPush),
+        DeviceTokenSimplePush(DeviceTokenSimplePush),
+        DeviceTokenUbuntuPush(DeviceTokenUbuntuPush),
+        DeviceTokenBlackBerryPush(DeviceTokenBlackBerryPush),
+        DeviceTokenTizenPush(DeviceTokenTizenPush),
+    }
+}
+tagged_enum! {
+    pub enum BackgroundFill {
+        BackgroundFillSolid(BackgroundFillSolid),
+        BackgroundFillGradient(BackgroundFillGradient),
+    }
+}
+tagged_enum! {
+    pub enum BackgroundType {
+        BackgroundTypeWallpaper(BackgroundTypeWallpaper),
+        BackgroundTypePattern(BackgroundTypePattern),
+        BackgroundTypeFill(BackgroundTypeFill),
+    }
+}
+tagged_enum! {
+    pub enum InputBackground {
+        InputBackgroundLocal(InputBackgroundLocal),
+        InputBackgroundRemote(InputBackgroundRemote),
+    }
+}
+tagged_enum! {
+    pub enum CanTransferOwnershipResult {
+        CanTransferOwnershipResultOk(CanTransferOwnershipResultOk),
+        CanTransferOwnershipResultPasswordNeeded(CanTransferOwnershipResultPasswordNeeded),
+        CanTransferOwnershipResultPasswordTooFresh(CanTransferOwnershipResultPasswordTooFresh),
+        CanTransferOwnershipResultSessionTooFresh(CanTransferOwnershipResultSessionTooFresh),
+    }
+}
+tagged_enum! {
+    pub enum CheckChatUsernameResult {
+        CheckChatUsernameResultOk(CheckChatUsernameResultOk),
+        CheckChatUsernameResultUsernameInvalid(CheckChatUsernameResultUsernameInvalid),
+        CheckChatUsernameResultUsernameOccupied(CheckChatUsernameResultUsernameOccupied),
+        CheckChatUsernameResultPublicChatsTooMuch(CheckChatUsernameResultPublicChatsTooMuch),
+        CheckChatUsernameResultPublicGroupsUnavailable(CheckChatUsernameResultPublicGroupsUnavailable),
+    }
+}
+tagged_enum! {
+    pub enum PushMessageContent {
+        PushMessageContentHidden(PushMessageContentHidden),
+        PushMessageContentAnimation(PushMessageContentAnimation),
+        PushMessageContentAudio(PushMessageContentAudio),
+        PushMessageContentContact(PushMessageContentContact),
+        PushMessageContentContactRegistered(PushMessageContentContactRegistered),
+        PushMessageContentDocument(PushMessageContentDocument),
+        PushMessageContentGame(PushMessageContentGame),
+        PushMessageContentGameScore(PushMessageContentGameScore),
+        PushMessageContentInvoice(PushMessageContentInvoice),
+        PushMessageContentLocation(PushMessageContentLocation),
+        PushMessageContentPhoto(PushMessageContentPhoto),
+        PushMessageContentPoll(PushMessageContentPoll),
+        PushMessageContentScreenshotTaken(PushMessageContentScreenshotTaken),
+        PushMessageContentSticker(PushMessageContentSticker),
+        PushMessageContentText(PushMessageContentText),
+        PushMessageContentVideo(PushMessageContentVideo),
+        PushMessageContentVideoNote(PushMessageContentVideoNote),
+        PushMessageContentVoiceNote(PushMessageContentVoiceNote),
+        PushMessageContentBasicGroupChatCreate(PushMessageContentBasicGroupChatCreate),
+        PushMessageContentChatAddMembers(PushMessageContentChatAddMembers),
+        PushMessageContentChatChangePhoto(PushMessageContentChatChangePhoto),
+        PushMessageContentChatChangeTitle(PushMessageContentChatChangeTitle),
+        PushMessageContentChatDeleteMember(PushMessageContentChatDeleteMember),
+        PushMessageContentChatJoinByLink(PushMessageContentChatJoinByLink),
+        PushMessageContentMessageForwards(PushMessageContentMessageForwards),
+        PushMessageContentMediaAlbum(PushMessageContentMediaAlbum),
+    }
+}
+tagged_enum! {
+    pub enum NotificationType {
+        NotificationTypeNewMessage(NotificationTypeNewMessage),
+        NotificationTypeNewSecretChat(NotificationTypeNewSecretChat),
+        NotificationTypeNewCall(NotificationTypeNewCall),
+        NotificationTypeNewPushMessage(NotificationTypeNewPushMessage),
+    }
+}
+tagged_enum! {
+    pub enum NotificationGroupType {
+        NotificationGroupTypeMessages(NotificationGroupTypeMessages),
+        NotificationGroupTypeMentions(NotificationGroupTypeMentions),
+        NotificationGroupTypeSecretChat(NotificationGroupTypeSecretChat),
+        NotificationGroupTypeCalls(NotificationGroupTypeCalls),
+    }
+}
+tagged_enum! {
+    pub enum OptionValue {
+        OptionValueBoolean(OptionValueBoolean),
+        OptionValueEmpty(OptionValueEmpty),
+        OptionValueInteger(OptionValueInteger),
+        OptionValueString(OptionValueString),
+    }
+}
+tagged_enum! {
+    pub enum JsonValue {
+        JsonValueNull(JsonValueNull),
+        JsonValueBoolean(JsonValueBoolean),
+        JsonValueNumber(JsonValueNumber),
+        JsonValueString(JsonValueString),
+        JsonValueArray(JsonValueArray),
+        JsonValueObject(JsonValueObject),
+    }
+}
+tagged_enum! {
+    pub enum UserPrivacySettingRule {
+        UserPrivacySettingRuleAllowAll(UserPrivacySettingRuleAllowAll),
+        UserPrivacySettingRuleAllowContacts(UserPrivacySettingRuleAllowContacts),
+        UserPrivacySettingRuleAllowUsers(UserPrivacySettingRuleAllowUsers),
+        UserPrivacySettingRuleAllowChatMembers(UserPrivacySettingRuleAllowChatMembers),
+        UserPrivacySettingRuleRestrictAll(UserPrivacySettingRuleRestrictAll),
+        UserPrivacySettingRuleRestrictContacts(UserPrivacySettingRuleRestrictContacts),
+        UserPrivacySettingRuleRestrictUsers(UserPrivacySettingRuleRestrictUsers),
+        UserPrivacySettingRuleRestrictChatMembers(UserPrivacySettingRuleRestrictChatMembers),
+    }
+}
+tagged_enum! {
+    pub enum UserPrivacySetting {
+        UserPrivacySettingShowStatus(UserPrivacySettingShowStatus),
+        UserPrivacySettingShowProfilePhoto(UserPrivacySettingShowProfilePhoto),
+        UserPrivacySettingShowLinkInForwardedMessages(UserPrivacySettingShowLinkInForwardedMessages),
+        UserPrivacySettingShowPhoneNumber(UserPrivacySettingShowPhoneNumber),
+        UserPrivacySettingAllowChatInvites(UserPrivacySettingAllowChatInvites),
+        UserPrivacySettingAllowCalls(UserPrivacySettingAllowCalls),
+        UserPrivacySettingAllowPeerToPeerCalls(UserPrivacySettingAllowPeerToPeerCalls),
+        UserPrivacySettingAllowFindingByPhoneNumber(UserPrivacySettingAllowFindingByPhoneNumber),
+    }
+}
+tagged_enum! {
+    pub enum ChatReportReason {
+        ChatReportReasonSpam(ChatReportReasonSpam),
+        ChatReportReasonViolence(ChatReportReasonViolence),
+        ChatReportReasonPornography(ChatReportReasonPornography),
+        ChatReportReasonChildAbuse(ChatReportReasonChildAbuse),
+        ChatReportReasonCopyright(ChatReportReasonCopyright),
+        ChatReportReasonUnrelatedLocation(ChatReportReasonUnrelatedLocation),
+        ChatReportReasonCustom(ChatReportReasonCustom),
+    }
+}
+tagged_enum! {
+    pub enum FileType {
+        FileTypeNone(FileTypeNone),
+        FileTypeAnimation(FileTypeAnimation),
+        FileTypeAudio(FileTypeAudio),
+        FileTypeDocument(FileTypeDocument),
+        FileTypePhoto(FileTypePhoto),
+        FileTypeProfilePhoto(FileTypeProfilePhoto),
+        FileTypeSecret(FileTypeSecret),
+        FileTypeSecretThumbnail(FileTypeSecretThumbnail),
+        FileTypeSecure(FileTypeSecure),
+        FileTypeSticker(FileTypeSticker),
+        FileTypeThumbnail(FileTypeThumbnail),
+        FileTypeUnknown(FileTypeUnknown),
+        FileTypeVideo(FileTypeVideo),
+        FileTypeVideoNote(FileTypeVideoNote),
+        FileTypeVoiceNote(FileTypeVoiceNote),
+        FileTypeWallpaper(FileTypeWallpaper),
+    }
+}
+tagged_enum! {
+    pub enum NetworkType {
+        NetworkTypeNone(NetworkTypeNone),
+        NetworkTypeMobile(NetworkTypeMobile),
+        NetworkTypeMobileRoaming(NetworkTypeMobileRoaming),
+        NetworkTypeWiFi(NetworkTypeWiFi),
+        NetworkTypeOther(NetworkTypeOther),
+    }
+}
+tagged_enum! {
+    pub enum NetworkStatisticsEntry {
+        NetworkStatisticsEntryFile(NetworkStatisticsEntryFile),
+        NetworkStatisticsEntryCall(NetworkStatisticsEntryCall),
+    }
+}
+tagged_enum! {
+    pub enum ConnectionState {
+        ConnectionStateWaitingForNetwork(ConnectionStateWaitingForNetwork),
+        ConnectionStateConnectingToProxy(ConnectionStateConnectingToProxy),
+        ConnectionStateConnecting(ConnectionStateConnecting),
+        ConnectionStateUpdating(ConnectionStateUpdating),
+        ConnectionStateReady(ConnectionStateReady),
+    }
+}
+tagged_enum! {
+    pub enum TopChatCategory {
+        TopChatCategoryUsers(TopChatCategoryUsers),
+        TopChatCategoryBots(TopChatCategoryBots),
+        TopChatCategoryGroups(TopChatCategoryGroups),
+        TopChatCategoryChannels(TopChatCategoryChannels),
+        TopChatCategoryInlineBots(TopChatCategoryInlineBots),
+        TopChatCategoryCalls(TopChatCategoryCalls),
+        TopChatCategoryForwardChats(TopChatCategoryForwardChats),
+    }
+}
+tagged_enum! {
+    pub enum TMeUrlType {
+        TMeUrlTypeUser(TMeUrlTypeUser),
+        TMeUrlTypeSupergroup(TMeUrlTypeSupergroup),
+        TMeUrlTypeChatInvite(TMeUrlTypeChatInvite),
+        TMeUrlTypeStickerSet(TMeUrlTypeStickerSet),
+    }
+}
+tagged_enum! {
+    pub enum TextParseMode {
+        TextParseModeMarkdown(TextParseModeMarkdown),
+        TextParseModeHTML(TextParseModeHTML),
+    }
+}
+tagged_enum! {
+    pub enum ProxyType {
+        ProxyTypeSocks5(ProxyTypeSocks5),
+        ProxyTypeHttp(ProxyTypeHttp),
+        ProxyTypeMtproto(ProxyTypeMtproto),
+    }
+}
+tagged_enum! {
+    pub enum InputSticker {
+        InputStickerStatic(InputStickerStatic),
+        InputStickerAnimated(InputStickerAnimated),
+    }
+}
+tagged_enum! {
+    pub enum StatisticsGraph {
+        StatisticsGraphData(StatisticsGraphData),
+        StatisticsGraphAsync(StatisticsGraphAsync),
+        StatisticsGraphError(StatisticsGraphError),
+    }
+}
+tagged_enum! {
+    pub enum Update {
+        UpdateAuthorizationState(UpdateAuthorizationState),
+        UpdateNewMessage(UpdateNewMessage),
+        UpdateMessageSendAcknowledged(UpdateMessageSendAcknowledged),
+        UpdateMessageSendSucceeded(UpdateMessageSendSucceeded),
+        UpdateMessageSendFailed(UpdateMessageSendFailed),
+        UpdateMessageContent(UpdateMessageContent),
+        UpdateMessageEdited(UpdateMessageEdited),
+        UpdateMessageViews(UpdateMessageViews),
+        UpdateMessageContentOpened(UpdateMessageContentOpened),
+        UpdateMessageMentionRead(UpdateMessageMentionRead),
+        UpdateMessageLiveLocationViewed(UpdateMessageLiveLocationViewed),
+        UpdateNewChat(UpdateNewChat),
+        UpdateChatChatList(UpdateChatChatList),
+        UpdateChatTitle(UpdateChatTitle),
+        UpdateChatPhoto(UpdateChatPhoto),
+        UpdateChatPermissions(UpdateChatPermissions),
+        UpdateChatLastMessage(UpdateChatLastMessage),
+        UpdateChatOrder(UpdateChatOrder),
+        UpdateChatIsPinned(UpdateChatIsPinned),
+        UpdateChatIsMarkedAsUnread(UpdateChatIsMarkedAsUnread),
+        UpdateChatSource(UpdateChatSource),
+        UpdateChatHasScheduledMessages(UpdateChatHasScheduledMessages),
+        UpdateChatDefaultDisableNotification(UpdateChatDefaultDisableNotification),
+        UpdateChatReadInbox(UpdateChatReadInbox),
+        UpdateChatReadOutbox(UpdateChatReadOutbox),
+        UpdateChatUnreadMentionCount(UpdateChatUnreadMentionCount),
+        UpdateChatNotificationSettings(UpdateChatNotificationSettings),
+        UpdateScopeNotificationSettings(UpdateScopeNotificationSettings),
+        UpdateChatActionBar(UpdateChatActionBar),
+        UpdateChatPinnedMessage(UpdateChatPinnedMessage),
+        UpdateChatReplyMarkup(UpdateChatReplyMarkup),
+        UpdateChatDraftMessage(UpdateChatDraftMessage),
+        UpdateChatOnlineMemberCount(UpdateChatOnlineMemberCount),
+        UpdateNotification(UpdateNotification),
+        UpdateNotificationGroup(UpdateNotificationGroup),
+        UpdateActiveNotifications(UpdateActiveNotifications),
+        UpdateHavePendingNotifications(UpdateHavePendingNotifications),
+        UpdateDeleteMessages(UpdateDeleteMessages),
+        UpdateUserChatAction(UpdateUserChatAction),
+        UpdateUserStatus(UpdateUserStatus),
+        UpdateUser(UpdateUser),
+        UpdateBasicGroup(UpdateBasicGroup),
+        UpdateSupergroup(UpdateSupergroup),
+        UpdateSecretChat(UpdateSecretChat),
+        UpdateUserFullInfo(UpdateUserFullInfo),
+        UpdateBasicGroupFullInfo(UpdateBasicGroupFullInfo),
+        UpdateSupergroupFullInfo(UpdateSupergroupFullInfo),
+        UpdateServiceNotification(UpdateServiceNotification),
+        UpdateFile(UpdateFile),
+        UpdateFileGenerationStart(UpdateFileGenerationStart),
+        UpdateFileGenerationStop(UpdateFileGenerationStop),
+        UpdateCall(UpdateCall),
+        UpdateUserPrivacySettingRules(UpdateUserPrivacySettingRules),
+        UpdateUnreadMessageCount(UpdateUnreadMessageCount),
+        UpdateUnreadChatCount(UpdateUnreadChatCount),
+        UpdateOption(UpdateOption),
+        UpdateStickerSet(UpdateStickerSet),
+        UpdateInstalledStickerSets(UpdateInstalledStickerSets),
+        UpdateTrendingStickerSets(UpdateTrendingStickerSets),
+        UpdateRecentStickers(UpdateRecentStickers),
+        UpdateFavoriteStickers(UpdateFavoriteStickers),
+        UpdateSavedAnimations(UpdateSavedAnimations),
+        UpdateSelectedBackground(UpdateSelectedBackground),
+        UpdateLanguagePackStrings(UpdateLanguagePackStrings),
+        UpdateConnectionState(UpdateConnectionState),
+        UpdateTermsOfService(UpdateTermsOfService),
+        UpdateUsersNearby(UpdateUsersNearby),
+        UpdateDiceEmojis(UpdateDiceEmojis),
+        UpdateNewInlineQuery(UpdateNewInlineQuery),
+        UpdateNewChosenInlineResult(UpdateNewChosenInlineResult),
+        UpdateNewCallbackQuery(UpdateNewCallbackQuery),
+        UpdateNewInlineCallbackQuery(UpdateNewInlineCallbackQuery),
+        UpdateNewShippingQuery(UpdateNewShippingQuery),
+        UpdateNewPreCheckoutQuery(UpdateNewPreCheckoutQuery),
+        UpdateNewCustomEvent(UpdateNewCustomEvent),
+        UpdateNewCustomQuery(UpdateNewCustomQuery),
+        UpdatePoll(UpdatePoll),
+        UpdatePollAnswer(UpdatePollAnswer),
+    }
+}
+tagged_enum! {
+    pub enum LogStream {
+        LogStreamDefault(LogStreamDefault),
+        LogStreamFile(LogStreamFile),
+        LogStreamEmpty(LogStreamEmpty),
+    }
 }
@@ -1,18 +1,190 @@
-use cmake;
+use std::{
+    env,
+    fs,
+    path::{ Path, PathBuf },
+    process::Command,
+};
+
+// Libraries making up a static TDLib build, in link order
+const TDLIB_STATIC_LIBS: &[&str] = &[
+    "tdjson_static",
+    "tdjson_private",
+    "tdclient",
+    "tdcore",
+    "tdapi",
+    "tdnet",
+    "tddb",
+    "tdsqlite",
+    "tdactor",
+    "tdutils",
+];
+
+const ENV_VARS: &[&str] = &[
+    "TDJSON_LIB_DIR",
+    "TDLIB_DIR",
+    "OPENSSL_DIR",
+    "ZLIB_DIR",
+    "PKG_CONFIG_PATH",
+    "TDLIB_VERSION",
+];
+
+// `td_create_client_id`, `td_send` and `td_receive`, which `ClientManager`
+// is built on, appeared in TDLib 1.7.0
+const MULTI_CLIENT_VERSION: (u32, u32) = (1, 7);
 
 fn main() {
-    let openssl_libs = vec![
-        "/usr/local/Cellar/openssl@1.1/1.1.1g/lib/libssl.dylib",
-        "/usr/local/Cellar/openssl@1.1/1.1.1g/lib/libcrypto.dylib"
-    ].join(";");
-
-    let dst = cmake::Config::new("td")
-        .define("OPENSSL_ROOT_DIR", "/usr/local/Cellar/openssl@1.1/1.1.1g/")
-        .define("OPENSSL_LIBRARIES", openssl_libs)
-        .generator("Ninja")
-        .profile("Release")
-        .build();
-
-    println!("cargo:rustc-link-search=native={}/lib", dst.display());
-    println!("cargo:rustc-link-lib=dylib=tdjson");
+    if env::var_os("CARGO_FEATURE_TDJSON").is_none() {
+        return;
+    }
+    for var in ENV_VARS {
+        println!("cargo:rerun-if-env-changed={}", var);
+    }
+    let link_static = env::var_os("CARGO_FEATURE_STATIC").is_some();
+
+    // 1. A prebuilt library pointed to explicitly
+    // 2. An installed TDLib known to pkg-config
+    // 3. The td submodule, built with cmake
+    let lib_dir = if let Some(dir) = env::var_os("TDJSON_LIB_DIR") {
+        PathBuf::from(dir)
+    } else if let Some(dir) = env::var_os("TDLIB_DIR") {
+        PathBuf::from(dir).join("lib")
+    } else if let Some(library) = probe_tdjson(link_static) {
+        check_version(Some(library.version));
+        return;
+    } else {
+        build_tdlib()
+    };
+    check_version(installed_version(&lib_dir));
+
+    println!("cargo:rustc-link-search=native={}", lib_dir.display());
+    if link_static {
+        for lib in TDLIB_STATIC_LIBS {
+            if lib_dir.join(format!("lib{}.a", lib)).exists() {
+                println!("cargo:rustc-link-lib=static={}", lib);
+            }
+        }
+        link_static_deps();
+    } else {
+        println!("cargo:rustc-link-lib=dylib=tdjson");
+    }
+}
+
+fn probe_tdjson(link_static: bool) -> Option<pkg_config::Library> {
+    let name = if link_static { "tdjson_static" } else { "tdjson" };
+    pkg_config::Config::new()
+        .statik(link_static)
+        .probe(name)
+        .ok()
+}
+
+// Refuses to pair the multi-client functions with a TDLib lacking them.
+// TDLIB_VERSION stands in for prebuilt libraries installed without their
+// cmake or pkg-config files.
+fn check_version(found: Option<String>) {
+    if env::var_os("CARGO_FEATURE_MULTI_CLIENT").is_none() {
+        return;
+    }
+    let version = match found.or_else(|| env::var("TDLIB_VERSION").ok()) {
+        Some(version) => version,
+        None => panic!(
+            "the multi-client feature needs TDLib {}.{} or newer, but the version of \
+             the linked TDLib is unknown: set TDLIB_VERSION to it",
+            MULTI_CLIENT_VERSION.0, MULTI_CLIENT_VERSION.1,
+        ),
+    };
+    let mut parts = version.trim().split('.').map(|part| part.parse::<u32>().unwrap_or(0));
+    let major_minor = (parts.next().unwrap_or(0), parts.next().unwrap_or(0));
+    if major_minor < MULTI_CLIENT_VERSION {
+        panic!(
+            "the multi-client feature needs TDLib {}.{} or newer, found {}",
+            MULTI_CLIENT_VERSION.0, MULTI_CLIENT_VERSION.1, version,
+        );
+    }
+}
+
+// Version recorded next to an installed TDLib, by its cmake package or
+// its pkg-config file
+fn installed_version(lib_dir: &Path) -> Option<String> {
+    let from_cmake = read_tracked(&lib_dir.join("cmake/Td/TdConfigVersion.cmake")).and_then(|config| {
+        let line = config.lines().find(|line| line.trim_start().starts_with("set(PACKAGE_VERSION "))?;
+        Some(line.split('"').nth(1)?.to_owned())
+    });
+    from_cmake.or_else(|| {
+        let pc = read_tracked(&lib_dir.join("pkgconfig/tdjson.pc"))?;
+        let line = pc.lines().find(|line| line.starts_with("Version:"))?;
+        Some(line["Version:".len()..].trim().to_owned())
+    })
+}
+
+// Reads a file the build depends on, so replacing TDLib rebuilds
+fn read_tracked(path: &Path) -> Option<String> {
+    let contents = fs::read_to_string(path).ok()?;
+    println!("cargo:rerun-if-changed={}", path.display());
+    Some(contents)
+}
+
+fn build_tdlib() -> PathBuf {
+    let source = Path::new("td");
+    if !source.join("CMakeLists.txt").exists() {
+        panic!(
+            "TDLib sources not found in {}: run `git submodule update --init` \
+             or set TDJSON_LIB_DIR to a directory containing a prebuilt libtdjson",
+            source.display(),
+        );
+    }
+    println!("cargo:rerun-if-changed=td/CMakeLists.txt");
+
+    let mut config = cmake::Config::new(source);
+    config.profile("Release");
+    if let Some(openssl) = openssl_dir() {
+        config.define("OPENSSL_ROOT_DIR", openssl);
+    }
+    if let Some(zlib) = library_root("ZLIB_DIR", "zlib") {
+        config.define("ZLIB_ROOT", zlib);
+    }
+    config.build().join("lib")
+}
+
+fn openssl_dir() -> Option<PathBuf> {
+    library_root("OPENSSL_DIR", "openssl").or_else(|| {
+        // Homebrew does not put its OpenSSL on the pkg-config path
+        if env::var("CARGO_CFG_TARGET_OS").ok()? != "macos" {
+            return None;
+        }
+        let output = Command::new("brew").args(["--prefix", "openssl@1.1"]).output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        Some(PathBuf::from(String::from_utf8(output.stdout).ok()?.trim()))
+    })
+}
+
+// Installation prefix from `env_var`, or the parent of the library
+// directory pkg-config reports for `package`
+fn library_root(env_var: &str, package: &str) -> Option<PathBuf> {
+    if let Some(dir) = env::var_os(env_var) {
+        return Some(PathBuf::from(dir));
+    }
+    let library = pkg_config::Config::new()
+        .cargo_metadata(false)
+        .probe(package)
+        .ok()?;
+    library.link_paths.first()?.parent().map(Path::to_path_buf)
+}
+
+fn link_static_deps() {
+    if let Some(openssl) = openssl_dir() {
+        println!("cargo:rustc-link-search=native={}", openssl.join("lib").display());
+    }
+    println!("cargo:rustc-link-lib=ssl");
+    println!("cargo:rustc-link-lib=crypto");
+    if let Some(zlib) = library_root("ZLIB_DIR", "zlib") {
+        println!("cargo:rustc-link-search=native={}", zlib.join("lib").display());
+    }
+    println!("cargo:rustc-link-lib=z");
+    match env::var("CARGO_CFG_TARGET_OS").as_deref() {
+        Ok("macos") | Ok("ios") => println!("cargo:rustc-link-lib=c++"),
+        Ok("windows") => (),
+        _ => println!("cargo:rustc-link-lib=stdc++"),
+    }
 }
@@ -1,4 +1,3 @@
-use tokio;
 use paperplane::client;
 use paperplane::update;
 use std::env;
@@ -57,7 +56,8 @@ async fn main() {
         .ok()
         .and_then(|var| var.parse().ok());
 
-    let _tg = client::Client::new(tg_log, UpdateHandler{});
+    let tg = client::Client::new(tg_log, UpdateHandler{});
 
     std::thread::sleep(std::time::Duration::new(2, 0));
+    tg.close().await.unwrap();
 }
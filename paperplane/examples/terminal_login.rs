@@ -0,0 +1,43 @@
+use paperplane::{ auth, client::Client };
+use paperplane_types::{ methods, types };
+use std::env;
+use log::info;
+
+fn make_lib_params() -> types::TdlibParameters {
+    let cache = env::current_dir().unwrap().join("cache");
+    let make_path = |p: &str| cache.join(p).to_str().map(|p| p.to_owned()).unwrap();
+    types::TdlibParameters {
+        use_test_dc: true,
+        database_directory: make_path("database"),
+        files_directory: make_path("files"),
+        use_file_database: true,
+        use_chat_info_database: true,
+        use_message_database: true,
+        use_secret_chats: false,
+        api_id: env::var("API_ID").unwrap().parse().unwrap(),
+        api_hash: env::var("API_HASH").unwrap(),
+        system_language_code: "en".to_owned(),
+        device_model: "mbia v1".to_owned(),
+        system_version: "15".to_owned(),
+        application_version: "0.1".to_owned(),
+        enable_storage_optimizer: false,
+        ignore_file_names: true,
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    dotenv::dotenv().ok();
+    env_logger::init();
+
+    let (handler, ready) = auth::Authorization::new(
+        auth::TerminalAuthenticator::new(make_lib_params()),
+        |_: Client, upd: types::Update| async move { info!("update: {:?}", upd) },
+    );
+    let tg = Client::new(None, handler);
+
+    ready.await.unwrap();
+    let me = tg.send(methods::GetMe {}).unwrap().await.unwrap();
+    println!("logged in as {} {}", me.first_name, me.last_name);
+    tg.close().await.unwrap();
+}
@@ -0,0 +1,318 @@
+use log::{ info, warn };
+use std::{
+    env,
+    future::Future,
+    io::{ self, BufRead, Write },
+    pin::Pin,
+    sync::{ Arc, Mutex },
+    task::{ Context, Poll },
+    thread,
+};
+use futures::{ channel::oneshot, future::BoxFuture };
+use paperplane_types::{ methods, types };
+use crate::{
+    client::Client,
+    error::{ Error, Result },
+    update::{ Handler, RawUpdate },
+};
+
+/// Supplies everything TDLib asks for while authorizing.
+///
+/// Only `tdlib_parameters` is mandatory; the rest default to an
+/// `Error::Auth`, so bots implement `bot_token` and users implement
+/// `phone_number`, `code` and friends.
+pub trait Authenticator: Send + Sync + 'static {
+    fn tdlib_parameters(&self) -> BoxFuture<'static, Result<types::TdlibParameters>>;
+
+    fn encryption_key(&self) -> BoxFuture<'static, Result<String>> {
+        Box::pin(async { Ok(String::new()) })
+    }
+
+    /// Returning a token logs in as a bot instead of asking for a phone number
+    fn bot_token(&self) -> BoxFuture<'static, Result<Option<String>>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    fn phone_number(&self) -> BoxFuture<'static, Result<String>> {
+        Box::pin(async { Err(Error::Auth("phone number is not provided".to_owned())) })
+    }
+
+    fn code(&self, _: types::AuthenticationCodeInfo) -> BoxFuture<'static, Result<String>> {
+        Box::pin(async { Err(Error::Auth("authentication code is not provided".to_owned())) })
+    }
+
+    fn password(&self, _hint: String) -> BoxFuture<'static, Result<String>> {
+        Box::pin(async { Err(Error::Auth("password is not provided".to_owned())) })
+    }
+
+    /// First and last name for a new account
+    fn registration(&self, _: types::TermsOfService) -> BoxFuture<'static, Result<(String, String)>> {
+        Box::pin(async { Err(Error::Auth("registration is not supported".to_owned())) })
+    }
+
+    /// Called when a step fails; return true to ask for the same input again
+    fn retry(&self, _: &Error) -> bool {
+        false
+    }
+}
+
+struct Shared<A> {
+    authenticator: A,
+    ready: Mutex<Option<oneshot::Sender<Result<()>>>>,
+}
+
+impl<A: Authenticator> Shared<A> {
+    fn finish(&self, result: Result<()>) {
+        if let Some(tx) = self.ready.lock().unwrap().take() {
+            let _ = tx.send(result);
+        }
+    }
+
+    async fn drive(&self, client: &Client, state: &types::AuthorizationState) {
+        loop {
+            match self.step(client, state).await {
+                Ok(()) => break,
+                Err(err) => {
+                    warn!("authorization step failed: {}", err);
+                    if !self.authenticator.retry(&err) {
+                        self.finish(Err(err));
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    async fn step(&self, client: &Client, state: &types::AuthorizationState) -> Result<()> {
+        use types::AuthorizationState::*;
+
+        match state {
+            AuthorizationStateWaitTdlibParameters(_) => {
+                let parameters = self.authenticator.tdlib_parameters().await?;
+                client.send(methods::SetTdlibParameters { parameters })?.await?;
+            },
+            AuthorizationStateWaitEncryptionKey(_) => {
+                let encryption_key = self.authenticator.encryption_key().await?;
+                client.send(methods::CheckDatabaseEncryptionKey { encryption_key })?.await?;
+            },
+            AuthorizationStateWaitPhoneNumber(_) => {
+                if let Some(token) = self.authenticator.bot_token().await? {
+                    client.send(methods::CheckAuthenticationBotToken { token })?.await?;
+                } else {
+                    let phone_number = self.authenticator.phone_number().await?;
+                    client.send(methods::SetAuthenticationPhoneNumber {
+                        phone_number,
+                        settings: types::PhoneNumberAuthenticationSettings {
+                            allow_flash_call: false,
+                            is_current_phone_number: false,
+                            allow_sms_retriever_api: false,
+                        },
+                    })?.await?;
+                }
+            },
+            AuthorizationStateWaitCode(state) => {
+                let code = self.authenticator.code(state.code_info.clone()).await?;
+                client.send(methods::CheckAuthenticationCode { code })?.await?;
+            },
+            AuthorizationStateWaitOtherDeviceConfirmation(state) => {
+                info!("confirm the login on another device: {}", state.link);
+            },
+            AuthorizationStateWaitRegistration(state) => {
+                let (first_name, last_name) = self.authenticator
+                    .registration(state.terms_of_service.clone()).await?;
+                client.send(methods::RegisterUser { first_name, last_name })?.await?;
+            },
+            AuthorizationStateWaitPassword(state) => {
+                let password = self.authenticator.password(state.password_hint.clone()).await?;
+                client.send(methods::CheckAuthenticationPassword { password })?.await?;
+            },
+            AuthorizationStateReady(_) => self.finish(Ok(())),
+            AuthorizationStateClosed(_) => self.finish(Err(Error::ClientClosed)),
+            AuthorizationStateLoggingOut(_) | AuthorizationStateClosing(_) => (),
+            Unknown { type_name, .. } => warn!("unknown authorization state {}", type_name),
+        }
+        Ok(())
+    }
+}
+
+/// Handler that answers `UpdateAuthorizationState` with the data from an
+/// `Authenticator` and passes every update on to the wrapped handler.
+pub struct Authorization<A, H> {
+    shared: Arc<Shared<A>>,
+    inner: H,
+}
+
+impl<A: Authenticator, H: Handler> Authorization<A, H> {
+    pub fn new(authenticator: A, inner: H) -> (Self, Ready) {
+        let (tx, rx) = oneshot::channel();
+        let auth = Self {
+            shared: Arc::new(Shared {
+                authenticator,
+                ready: Mutex::new(Some(tx)),
+            }),
+            inner,
+        };
+        (auth, Ready { rx })
+    }
+}
+
+impl<A: Authenticator, H: Handler> Handler for Authorization<A, H> {
+    fn handle(&self, client: Client, upd: types::Update) -> BoxFuture<'static, ()> {
+        let auth = match &upd {
+            types::Update::UpdateAuthorizationState(state) => {
+                let shared = self.shared.clone();
+                let client = client.clone();
+                let state = state.authorization_state.clone();
+                Some(async move { shared.drive(&client, &state).await })
+            },
+            _ => None,
+        };
+        let inner = self.inner.handle(client, upd);
+        Box::pin(async move {
+            if let Some(auth) = auth {
+                auth.await;
+            }
+            inner.await;
+        })
+    }
+
+    fn handle_raw(&self, client: Client, upd: &RawUpdate) -> Option<BoxFuture<'static, ()>> {
+        self.inner.handle_raw(client, upd)
+    }
+}
+
+/// Resolves once the authorization state becomes `authorizationStateReady`
+pub struct Ready {
+    rx: oneshot::Receiver<Result<()>>,
+}
+
+impl Future for Ready {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        Pin::new(&mut self.rx)
+            .poll(cx)
+            .map(|res| res.unwrap_or(Err(Error::ClientClosed)))
+    }
+}
+
+/// Logs in as a bot
+pub struct BotAuthenticator {
+    parameters: types::TdlibParameters,
+    token: String,
+}
+
+impl BotAuthenticator {
+    pub fn new(parameters: types::TdlibParameters, token: String) -> Self {
+        Self { parameters, token }
+    }
+
+    /// Reads the token from the `BOT_TOKEN` environment variable
+    pub fn from_env(parameters: types::TdlibParameters) -> Result<Self> {
+        let token = env::var("BOT_TOKEN")
+            .map_err(|err| Error::Auth(format!("BOT_TOKEN: {}", err)))?;
+        Ok(Self::new(parameters, token))
+    }
+}
+
+impl Authenticator for BotAuthenticator {
+    fn tdlib_parameters(&self) -> BoxFuture<'static, Result<types::TdlibParameters>> {
+        let parameters = self.parameters.clone();
+        Box::pin(async move { Ok(parameters) })
+    }
+
+    fn bot_token(&self) -> BoxFuture<'static, Result<Option<String>>> {
+        let token = self.token.clone();
+        Box::pin(async move { Ok(Some(token)) })
+    }
+}
+
+/// Logs in as a user, asking for the credentials on stdin
+pub struct TerminalAuthenticator {
+    parameters: types::TdlibParameters,
+    encryption_key: String,
+    // Why the last answer was rejected, shown with the next prompt
+    rejected: Mutex<Option<String>>,
+}
+
+impl TerminalAuthenticator {
+    pub fn new(parameters: types::TdlibParameters) -> Self {
+        Self { parameters, encryption_key: String::new(), rejected: Mutex::new(None) }
+    }
+
+    pub fn with_encryption_key(mut self, encryption_key: String) -> Self {
+        self.encryption_key = encryption_key;
+        self
+    }
+
+    fn ask(&self, text: String) -> BoxFuture<'static, Result<String>> {
+        match self.rejected.lock().unwrap().take() {
+            Some(reason) => prompt(format!("{}, try again\n{}", reason, text)),
+            None => prompt(text),
+        }
+    }
+}
+
+// Reading stdin blocks, so it is done on a separate thread to keep
+// the executor free
+fn prompt(text: String) -> BoxFuture<'static, Result<String>> {
+    let (tx, rx) = oneshot::channel();
+    thread::spawn(move || {
+        let read = || -> io::Result<String> {
+            print!("{}: ", text);
+            io::stdout().flush()?;
+            let mut line = String::new();
+            io::stdin().lock().read_line(&mut line)?;
+            Ok(line.trim().to_owned())
+        };
+        let _ = tx.send(read().map_err(|err| Error::Auth(err.to_string())));
+    });
+    Box::pin(async move { rx.await.unwrap_or(Err(Error::ClientClosed)) })
+}
+
+impl Authenticator for TerminalAuthenticator {
+    fn tdlib_parameters(&self) -> BoxFuture<'static, Result<types::TdlibParameters>> {
+        let parameters = self.parameters.clone();
+        Box::pin(async move { Ok(parameters) })
+    }
+
+    fn encryption_key(&self) -> BoxFuture<'static, Result<String>> {
+        let key = self.encryption_key.clone();
+        Box::pin(async move { Ok(key) })
+    }
+
+    fn phone_number(&self) -> BoxFuture<'static, Result<String>> {
+        self.ask("Phone number".to_owned())
+    }
+
+    fn code(&self, info: types::AuthenticationCodeInfo) -> BoxFuture<'static, Result<String>> {
+        self.ask(format!("Code sent to {}", info.phone_number))
+    }
+
+    fn password(&self, hint: String) -> BoxFuture<'static, Result<String>> {
+        if hint.is_empty() {
+            self.ask("Password".to_owned())
+        } else {
+            self.ask(format!("Password (hint: {})", hint))
+        }
+    }
+
+    fn registration(&self, _: types::TermsOfService) -> BoxFuture<'static, Result<(String, String)>> {
+        let first_name = self.ask("First name".to_owned());
+        Box::pin(async {
+            let first_name = first_name.await?;
+            let last_name = prompt("Last name".to_owned()).await?;
+            Ok((first_name, last_name))
+        })
+    }
+
+    fn retry(&self, err: &Error) -> bool {
+        match err {
+            Error::Tdlib { code: 400, message } if message.ends_with("_INVALID") => {
+                *self.rejected.lock().unwrap() = Some(message.clone());
+                true
+            },
+            _ => false,
+        }
+    }
+}
@@ -3,7 +3,7 @@
  */
 
 use std::{
-    ffi::{ CString, CStr },
+    ffi::{ CString, CStr, NulError },
     os::raw::{ c_void, c_char, c_double, c_int, c_long },
     ptr,
 };
@@ -11,7 +11,8 @@ use std::{
 #[allow(non_camel_case_types)]
 type client_ptr = *mut c_void;
 
-#[derive(Debug, Clone)]
+// Not Clone: the instance is destroyed on drop, share it through an Arc
+#[derive(Debug)]
 pub struct Tdlib {
     instance: client_ptr,
 }
@@ -21,7 +22,7 @@ pub struct Tdlib {
 unsafe impl Send for Tdlib {}
 unsafe impl Sync for Tdlib {}
 
-#[link(name = "tdjson")]
+// build.rs links libtdjson, or the static TDLib libraries with `static`
 extern "C" {
     fn td_json_client_create() -> client_ptr;
     fn td_json_client_send(client: client_ptr, request: *const c_char);
@@ -43,7 +44,7 @@ impl Tdlib {
         unsafe { td_set_log_max_file_size(size) };
     }
 
-    pub fn set_log_file_path(path: Option<&str>) -> Result<bool, std::ffi::NulError> {
+    pub fn set_log_file_path(path: Option<&str>) -> Result<bool, NulError> {
         let result = match path {
             None => unsafe { td_set_log_file_path(ptr::null()) },
             Some(p) => {
@@ -51,10 +52,7 @@ impl Tdlib {
                 unsafe { td_set_log_file_path(cpath.as_ptr()) }
             }
         };
-        Ok(match result {
-            0 => false,
-            _ => true,
-        })
+        Ok(result != 0)
     }
 
     pub fn new() -> Self {
@@ -62,19 +60,20 @@ impl Tdlib {
         Tdlib { instance: client }
     }
 
-    pub fn send(&self, request: &str) {
-        let cstring = CString::new(request).unwrap();
-        unsafe { td_json_client_send(self.instance, cstring.as_ptr()) }
+    pub fn send(&self, request: &str) -> Result<(), NulError> {
+        let cstring = CString::new(request)?;
+        unsafe { td_json_client_send(self.instance, cstring.as_ptr()) };
+        Ok(())
     }
 
-    pub fn execute(&self, request: &str) -> Option<String> {
-        let cstring = CString::new(request).unwrap();
+    pub fn execute(&self, request: &str) -> Result<Option<String>, NulError> {
+        let cstring = CString::new(request)?;
         let result = unsafe {
             td_json_client_execute(self.instance, cstring.as_ptr())
                 .as_ref()
                 .map(|response| CStr::from_ptr(response).to_string_lossy().into_owned())
         };
-        result
+        Ok(result)
     }
 
     pub fn receive(&self, timeout: f64) -> Option<String> {
@@ -86,6 +85,71 @@ impl Tdlib {
     }
 }
 
+#[cfg(feature = "multi-client")]
+extern "C" {
+    fn td_create_client_id() -> c_int;
+    fn td_send(client_id: c_int, request: *const c_char);
+    fn td_receive(timeout: c_double) -> *const c_char;
+    fn td_execute(request: *const c_char) -> *const c_char;
+}
+
+/// Client of the shared TDLib instance (TDLib 1.7+ `td_create_client_id`
+/// API). Responses and updates of every client arrive through the single
+/// `TdClientId::receive`, tagged with `@client_id`.
+#[cfg(feature = "multi-client")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TdClientId(i32);
+
+#[cfg(feature = "multi-client")]
+impl TdClientId {
+    /// The instance is started by the first request sent to it
+    pub fn new() -> Self {
+        TdClientId(unsafe { td_create_client_id() })
+    }
+
+    pub fn id(self) -> i32 {
+        self.0
+    }
+
+    pub fn send(self, request: &str) -> Result<(), NulError> {
+        let cstring = CString::new(request)?;
+        unsafe { td_send(self.0, cstring.as_ptr()) };
+        Ok(())
+    }
+
+    /// Must not be called from more than one thread at a time
+    pub fn receive(timeout: f64) -> Option<String> {
+        unsafe {
+            td_receive(timeout)
+                .as_ref()
+                .map(|response| CStr::from_ptr(response).to_string_lossy().into_owned())
+        }
+    }
+
+    pub fn execute(request: &str) -> Result<Option<String>, NulError> {
+        let cstring = CString::new(request)?;
+        let result = unsafe {
+            td_execute(cstring.as_ptr())
+                .as_ref()
+                .map(|response| CStr::from_ptr(response).to_string_lossy().into_owned())
+        };
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "multi-client")]
+impl Default for TdClientId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for Tdlib {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Drop for Tdlib {
     fn drop(&mut self) {
         unsafe {
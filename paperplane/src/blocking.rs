@@ -0,0 +1,81 @@
+use std::time::Duration;
+use futures::executor::{ self, BlockingStream };
+use serde_json::Value as JsonValue;
+use paperplane_types::methods::{ Method, SyncMethod };
+use crate::{
+    client,
+    error::Result,
+    transport::Transport,
+    update::{ Handler, UpdateStream },
+};
+
+/// A `client::Client` whose calls block the current thread, for scripts
+/// and CLIs without an async runtime. Handlers still run on the client's
+/// spawner, and must not call a blocking client themselves.
+///
+/// ```ignore
+/// let client = blocking::Client::new(None, |_, _| async {});
+/// for upd in client.updates() {
+///     ...
+/// }
+/// let me = client.send(methods::GetMe {})?;
+/// ```
+#[derive(Clone)]
+pub struct Client {
+    inner: client::Client,
+}
+
+impl Client {
+    #[cfg(feature = "tdjson")]
+    pub fn new<H: Handler>(log_opt: Option<i32>, updater: H) -> Self {
+        client::Client::new(log_opt, updater).into()
+    }
+
+    pub fn with_transport<T: Transport, H: Handler>(transport: T, updater: H) -> Self {
+        client::Client::with_transport(transport, updater).into()
+    }
+
+    /// The async client behind this one
+    pub fn as_async(&self) -> &client::Client {
+        &self.inner
+    }
+
+    pub fn send<R: Method>(&self, req: R) -> Result<R::Response> {
+        executor::block_on(self.inner.send(req)?)
+    }
+
+    pub fn send_with_timeout<R: Method>(&self, req: R, timeout: Duration) -> Result<R::Response> {
+        executor::block_on(self.inner.send_with_timeout(req, timeout)?)
+    }
+
+    pub fn send_raw(&self, req: JsonValue) -> Result<JsonValue> {
+        executor::block_on(self.inner.send_raw(req)?)
+    }
+
+    pub fn execute<R: SyncMethod>(&self, req: R) -> Result<R::Response> {
+        self.inner.execute(req)
+    }
+
+    pub fn set_default_timeout(&self, timeout: Option<Duration>) {
+        self.inner.set_default_timeout(timeout)
+    }
+
+    /// Iterator over updates, see `client::Client::updates`
+    pub fn updates(&self) -> BlockingStream<UpdateStream> {
+        executor::block_on_stream(self.inner.updates())
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.inner.is_closed()
+    }
+
+    pub fn close(&self) -> Result<()> {
+        executor::block_on(self.inner.close())
+    }
+}
+
+impl From<client::Client> for Client {
+    fn from(inner: client::Client) -> Self {
+        Self { inner }
+    }
+}
@@ -0,0 +1,338 @@
+use log::{ info, warn };
+use std::{
+    fmt::Display,
+    future::Future,
+    str::FromStr,
+    sync::{ Arc, Mutex },
+};
+use futures::future::BoxFuture;
+use paperplane_types::{ methods, types::{ self, Message, MessageContent, TextEntityType, Update } };
+use crate::{
+    client::{ Client, RequestFuture },
+    error::Result,
+    text::{ self, FormattedTextBuilder },
+    update::{ Handler, RawUpdate },
+};
+
+/// A `/command@botname args` message
+#[derive(Debug, Clone)]
+pub struct Command {
+    /// Lowercased command without the slash
+    pub name: String,
+    /// The bot username after `@`, if the command was addressed explicitly
+    pub bot_username: Option<String>,
+    /// Text after the command, trimmed
+    pub args: String,
+    pub message: Message,
+}
+
+impl Command {
+    /// Parses the bot command entity at the start of a text message
+    pub fn parse(message: &Message) -> Option<Self> {
+        let formatted = match &message.content {
+            MessageContent::MessageText(content) => &content.text,
+            _ => return None,
+        };
+        let entity = formatted.entities.iter().find(|entity| {
+            entity.offset == 0 && matches!(entity.type_, TextEntityType::TextEntityTypeBotCommand(_))
+        })?;
+        let end = text::byte_offset(&formatted.text, entity.length)?;
+        let command = formatted.text[..end].strip_prefix('/')?;
+        let (name, bot_username) = match command.find('@') {
+            Some(at) => (&command[..at], Some(command[at + 1..].to_owned())),
+            None => (command, None),
+        };
+        Some(Self {
+            name: name.to_lowercase(),
+            bot_username,
+            args: formatted.text[end..].trim().to_owned(),
+            message: message.clone(),
+        })
+    }
+
+    pub fn chat_id(&self) -> i64 {
+        self.message.chat_id
+    }
+
+    /// Sends `text` to the chat as a reply to the command
+    pub fn reply(&self, client: &Client, text: types::FormattedText) -> Result<RequestFuture<methods::SendMessage>> {
+        let mut message = text::send_message(self.chat_id(), text);
+        message.reply_to_message_id = self.message.id;
+        client.send(message)
+    }
+}
+
+/// Arguments of a command, parsed from `Command::args`.
+///
+/// `String` takes the whole text, numbers and `bool` a single word,
+/// tuples one word per element and `Vec` any number of words.
+pub trait FromArgs: Sized {
+    fn from_args(args: &str) -> std::result::Result<Self, String>;
+}
+
+impl FromArgs for () {
+    fn from_args(_: &str) -> std::result::Result<Self, String> {
+        Ok(())
+    }
+}
+
+impl FromArgs for String {
+    fn from_args(args: &str) -> std::result::Result<Self, String> {
+        Ok(args.trim().to_owned())
+    }
+}
+
+impl<T: FromArgs> FromArgs for Option<T> {
+    fn from_args(args: &str) -> std::result::Result<Self, String> {
+        if args.trim().is_empty() {
+            Ok(None)
+        } else {
+            T::from_args(args).map(Some)
+        }
+    }
+}
+
+impl<T> FromArgs for Vec<T>
+where T: FromStr,
+      T::Err: Display {
+    fn from_args(args: &str) -> std::result::Result<Self, String> {
+        args.split_whitespace().map(parse_word).collect()
+    }
+}
+
+fn parse_word<T>(word: &str) -> std::result::Result<T, String>
+where T: FromStr,
+      T::Err: Display {
+    word.parse().map_err(|err| format!("invalid argument \"{}\": {}", word, err))
+}
+
+macro_rules! from_args_word {
+    ($($ty:ty),*) => {$(
+        impl FromArgs for $ty {
+            fn from_args(args: &str) -> std::result::Result<Self, String> {
+                match args.split_whitespace().collect::<Vec<_>>().as_slice() {
+                    [word] => parse_word(word),
+                    words => Err(format!("expected 1 argument, got {}", words.len())),
+                }
+            }
+        }
+    )*};
+}
+
+from_args_word!(i32, i64, u32, u64, f64, bool);
+
+macro_rules! from_args_tuple {
+    ($len:expr; $($ty:ident),*) => {
+        impl<$($ty),*> FromArgs for ($($ty,)*)
+        where $($ty: FromStr, $ty::Err: Display),* {
+            fn from_args(args: &str) -> std::result::Result<Self, String> {
+                let words: Vec<&str> = args.split_whitespace().collect();
+                if words.len() != $len {
+                    return Err(format!("expected {} arguments, got {}", $len, words.len()));
+                }
+                let mut words = words.into_iter();
+                Ok(($(parse_word::<$ty>(words.next().unwrap_or_default())?,)*))
+            }
+        }
+    };
+}
+
+from_args_tuple!(1; A);
+from_args_tuple!(2; A, B);
+from_args_tuple!(3; A, B, C);
+from_args_tuple!(4; A, B, C, D);
+
+/// Handler for a command with arguments `A`, see `Bot::command`
+pub trait CommandHandler<A>: Send + Sync + 'static {
+    fn handle(&self, _: Client, _: Command, _: A) -> BoxFuture<'static, ()>;
+}
+
+impl<A, C, F> CommandHandler<A> for C
+where C: Send + Sync + 'static + Fn(Client, Command, A) -> F,
+      F: Future<Output = ()> + 'static + Send {
+    fn handle(&self, client: Client, command: Command, args: A) -> BoxFuture<'static, ()> {
+        Box::pin((*self)(client, command, args))
+    }
+}
+
+fn log_reply(command: String, reply: Result<RequestFuture<methods::SendMessage>>) -> BoxFuture<'static, ()> {
+    Box::pin(async move {
+        let sent = match reply {
+            Ok(reply) => reply.await.map(drop),
+            Err(err) => Err(err),
+        };
+        if let Err(err) = sent {
+            warn!("could not reply to {}: {}", command, err);
+        }
+    })
+}
+
+type Run = Box<dyn Fn(Client, Command) -> BoxFuture<'static, ()> + Send + Sync + 'static>;
+
+struct Entry {
+    command: types::BotCommand,
+    run: Run,
+}
+
+/// Handles bot commands and passes every other update on to the fallback.
+///
+/// Once authorized, the bot publishes its commands with `SetCommands`.
+/// Commands addressed to another bot are ignored, and arguments that do
+/// not parse are answered with the error.
+///
+/// ```ignore
+/// let bot = Bot::new()
+///     .command("start", "Say hello", |client, cmd, (): ()| async move { ... })
+///     .command("roll", "Roll a die with N sides", |client, cmd, sides: u32| async move { ... })
+///     .help("Show this message")
+///     .fallback(dispatcher);
+/// ```
+pub struct Bot {
+    commands: Vec<Entry>,
+    help: Option<String>,
+    username: Arc<Mutex<Option<String>>>,
+    fallback: Option<Box<dyn Handler>>,
+}
+
+impl Bot {
+    pub fn new() -> Self {
+        Self {
+            commands: Vec::new(),
+            help: None,
+            username: Arc::new(Mutex::new(None)),
+            fallback: None,
+        }
+    }
+
+    pub fn command<A, H>(mut self, name: &str, description: &str, handler: H) -> Self
+    where A: FromArgs + Send + 'static,
+          H: CommandHandler<A> {
+        let run = move |client: Client, command: Command| -> BoxFuture<'static, ()> {
+            match A::from_args(&command.args) {
+                Ok(args) => handler.handle(client, command, args),
+                Err(err) => {
+                    let text = FormattedTextBuilder::new().text(&format!("/{}: {}", command.name, err)).build();
+                    log_reply(format!("/{}", command.name), command.reply(&client, text))
+                },
+            }
+        };
+        self.commands.push(Entry {
+            command: types::BotCommand {
+                command: name.to_lowercase(),
+                description: description.to_owned(),
+            },
+            run: Box::new(run),
+        });
+        self
+    }
+
+    /// Adds `/help`, listing every command with its description
+    pub fn help(mut self, description: &str) -> Self {
+        self.help = Some(description.to_owned());
+        self
+    }
+
+    pub fn fallback<H: Handler>(mut self, handler: H) -> Self {
+        self.fallback = Some(Box::new(handler));
+        self
+    }
+
+    /// Commands as published with `SetCommands`
+    pub fn command_list(&self) -> Vec<types::BotCommand> {
+        let mut commands: Vec<_> = self.commands.iter().map(|entry| entry.command.clone()).collect();
+        if let Some(description) = &self.help {
+            commands.push(types::BotCommand {
+                command: "help".to_owned(),
+                description: description.clone(),
+            });
+        }
+        commands
+    }
+
+    /// Remembers the bot username and publishes the command list; done
+    /// automatically on `authorizationStateReady`
+    pub fn publish(&self, client: &Client) -> BoxFuture<'static, Result<()>> {
+        let client = client.clone();
+        let username = self.username.clone();
+        let commands = self.command_list();
+        Box::pin(async move {
+            let me = client.send(methods::GetMe {})?.await?;
+            *username.lock().unwrap() = Some(me.username);
+            client.send(methods::SetCommands { commands })?.await?;
+            Ok(())
+        })
+    }
+
+    fn help_text(&self) -> types::FormattedText {
+        self.command_list()
+            .into_iter()
+            .fold(FormattedTextBuilder::new(), |text, command| {
+                let text = if text.is_empty() { text } else { text.text("\n") };
+                text.text(&format!("/{} - {}", command.command, command.description))
+            })
+            .build()
+    }
+
+    // Whether the command is for us; unknown until `publish` has run
+    fn addressed_to_us(&self, command: &Command) -> bool {
+        match (&command.bot_username, &*self.username.lock().unwrap()) {
+            (Some(target), Some(username)) => target.eq_ignore_ascii_case(username),
+            _ => true,
+        }
+    }
+
+    fn handle_command(&self, client: Client, command: Command) -> Option<BoxFuture<'static, ()>> {
+        if !self.addressed_to_us(&command) {
+            return None;
+        }
+        if self.help.is_some() && command.name == "help" {
+            return Some(log_reply("/help".to_owned(), command.reply(&client, self.help_text())));
+        }
+        self.commands
+            .iter()
+            .find(|entry| entry.command.command == command.name)
+            .map(|entry| (entry.run)(client, command))
+    }
+}
+
+impl Default for Bot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Handler for Bot {
+    fn handle(&self, client: Client, upd: Update) -> BoxFuture<'static, ()> {
+        let publish = match &upd {
+            Update::UpdateAuthorizationState(state) => match state.authorization_state {
+                types::AuthorizationState::AuthorizationStateReady(_) => Some(self.publish(&client)),
+                _ => None,
+            },
+            Update::UpdateNewMessage(upd) if !upd.message.is_outgoing => {
+                if let Some(command) = Command::parse(&upd.message) {
+                    if let Some(handled) = self.handle_command(client.clone(), command) {
+                        return handled;
+                    }
+                }
+                None
+            },
+            _ => None,
+        };
+        let fallback = self.fallback.as_ref().map(|fallback| fallback.handle(client, upd));
+        Box::pin(async move {
+            if let Some(publish) = publish {
+                match publish.await {
+                    Ok(()) => info!("bot commands published"),
+                    Err(err) => warn!("could not publish bot commands: {}", err),
+                }
+            }
+            if let Some(fallback) = fallback {
+                fallback.await;
+            }
+        })
+    }
+
+    fn handle_raw(&self, client: Client, upd: &RawUpdate) -> Option<BoxFuture<'static, ()>> {
+        self.fallback.as_ref()?.handle_raw(client, upd)
+    }
+}
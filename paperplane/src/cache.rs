@@ -0,0 +1,263 @@
+use std::{
+    collections::HashMap,
+    sync::{ Arc, RwLock },
+};
+use futures::future::BoxFuture;
+use paperplane_types::types::{
+    BasicGroup,
+    BasicGroupFullInfo,
+    Chat,
+    Message,
+    SecretChat,
+    Supergroup,
+    SupergroupFullInfo,
+    Update,
+    User,
+    UserFullInfo,
+};
+use crate::{
+    client::Client,
+    update::{ Handler, RawUpdate },
+};
+
+#[derive(Default)]
+struct State {
+    chats: HashMap<i64, Chat>,
+    users: HashMap<i32, User>,
+    basic_groups: HashMap<i32, BasicGroup>,
+    supergroups: HashMap<i32, Supergroup>,
+    secret_chats: HashMap<i32, SecretChat>,
+    user_full_info: HashMap<i32, UserFullInfo>,
+    basic_group_full_info: HashMap<i32, BasicGroupFullInfo>,
+    supergroup_full_info: HashMap<i32, SupergroupFullInfo>,
+}
+
+impl State {
+    fn chat(&mut self, chat_id: i64) -> Option<&mut Chat> {
+        self.chats.get_mut(&chat_id)
+    }
+
+    // Keeps `last_message` in line with changes to the message itself
+    fn last_message(&mut self, chat_id: i64, message_id: i64) -> Option<&mut Message> {
+        self.chat(chat_id)?
+            .last_message
+            .as_mut()
+            .filter(|message| message.id == message_id)
+    }
+
+    fn apply(&mut self, upd: &Update) {
+        match upd {
+            Update::UpdateNewChat(upd) => {
+                self.chats.insert(upd.chat.id, upd.chat.clone());
+            },
+            Update::UpdateChatChatList(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.chat_list = upd.chat_list.clone();
+            },
+            Update::UpdateChatTitle(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.title = upd.title.clone();
+            },
+            Update::UpdateChatPhoto(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.photo = upd.photo.clone();
+            },
+            Update::UpdateChatPermissions(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.permissions = upd.permissions.clone();
+            },
+            Update::UpdateChatLastMessage(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.last_message = upd.last_message.clone();
+                chat.order = upd.order;
+            },
+            Update::UpdateChatOrder(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.order = upd.order;
+            },
+            Update::UpdateChatIsPinned(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.is_pinned = upd.is_pinned;
+                chat.order = upd.order;
+            },
+            Update::UpdateChatIsMarkedAsUnread(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.is_marked_as_unread = upd.is_marked_as_unread;
+            },
+            Update::UpdateChatSource(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.source = upd.source.clone();
+                chat.order = upd.order;
+            },
+            Update::UpdateChatHasScheduledMessages(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.has_scheduled_messages = upd.has_scheduled_messages;
+            },
+            Update::UpdateChatDefaultDisableNotification(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.default_disable_notification = upd.default_disable_notification;
+            },
+            Update::UpdateChatReadInbox(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.last_read_inbox_message_id = upd.last_read_inbox_message_id;
+                chat.unread_count = upd.unread_count;
+            },
+            Update::UpdateChatReadOutbox(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.last_read_outbox_message_id = upd.last_read_outbox_message_id;
+            },
+            Update::UpdateChatUnreadMentionCount(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.unread_mention_count = upd.unread_mention_count;
+            },
+            Update::UpdateMessageMentionRead(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.unread_mention_count = upd.unread_mention_count;
+            },
+            Update::UpdateChatNotificationSettings(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.notification_settings = upd.notification_settings.clone();
+            },
+            Update::UpdateChatActionBar(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.action_bar = upd.action_bar.clone();
+            },
+            Update::UpdateChatPinnedMessage(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.pinned_message_id = upd.pinned_message_id;
+            },
+            Update::UpdateChatReplyMarkup(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.reply_markup_message_id = upd.reply_markup_message_id;
+            },
+            Update::UpdateChatDraftMessage(upd) => if let Some(chat) = self.chat(upd.chat_id) {
+                chat.draft_message = upd.draft_message.clone();
+                chat.order = upd.order;
+            },
+            Update::UpdateMessageContent(upd) => if let Some(message) = self.last_message(upd.chat_id, upd.message_id) {
+                message.content = upd.new_content.clone();
+            },
+            Update::UpdateMessageEdited(upd) => if let Some(message) = self.last_message(upd.chat_id, upd.message_id) {
+                message.edit_date = upd.edit_date;
+                message.reply_markup = upd.reply_markup.clone();
+            },
+            Update::UpdateMessageViews(upd) => if let Some(message) = self.last_message(upd.chat_id, upd.message_id) {
+                message.views = upd.views;
+            },
+            Update::UpdateUser(upd) => {
+                self.users.insert(upd.user.id, upd.user.clone());
+            },
+            Update::UpdateUserStatus(upd) => if let Some(user) = self.users.get_mut(&upd.user_id) {
+                user.status = upd.status.clone();
+            },
+            Update::UpdateBasicGroup(upd) => {
+                self.basic_groups.insert(upd.basic_group.id, upd.basic_group.clone());
+            },
+            Update::UpdateSupergroup(upd) => {
+                self.supergroups.insert(upd.supergroup.id, upd.supergroup.clone());
+            },
+            Update::UpdateSecretChat(upd) => {
+                self.secret_chats.insert(upd.secret_chat.id, upd.secret_chat.clone());
+            },
+            Update::UpdateUserFullInfo(upd) => {
+                self.user_full_info.insert(upd.user_id, upd.user_full_info.clone());
+            },
+            Update::UpdateBasicGroupFullInfo(upd) => {
+                self.basic_group_full_info.insert(upd.basic_group_id, upd.basic_group_full_info.clone());
+            },
+            Update::UpdateSupergroupFullInfo(upd) => {
+                self.supergroup_full_info.insert(upd.supergroup_id, upd.supergroup_full_info.clone());
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Copies of the objects TDLib expects clients to keep, maintained from
+/// updates. Clones share the same state.
+///
+/// Updates are applied in the order they arrive when the cache wraps the
+/// client's handler:
+///
+/// ```
+/// # use paperplane::{ cache::Cache, client::Client, testing::MockTdlib, update::Dispatcher };
+/// # let (dispatcher, chat_id) = (Dispatcher::new(), 0);
+/// let cache = Cache::new();
+/// let client = Client::with_transport(MockTdlib::new(), cache.wrap(dispatcher));
+/// // ...
+/// let title = cache.chat(chat_id).map(|chat| chat.title);
+/// ```
+#[derive(Clone, Default)]
+pub struct Cache {
+    state: Arc<RwLock<State>>,
+}
+
+impl Cache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the cached objects; ignores updates about anything else
+    pub fn apply(&self, upd: &Update) {
+        self.state.write().unwrap().apply(upd);
+    }
+
+    /// Handler applying every update to the cache before passing it on
+    pub fn wrap<H: Handler>(&self, handler: H) -> Cached<H> {
+        Cached { cache: self.clone(), handler }
+    }
+
+    pub fn chat(&self, chat_id: i64) -> Option<Chat> {
+        self.state.read().unwrap().chats.get(&chat_id).cloned()
+    }
+
+    pub fn chat_ids(&self) -> Vec<i64> {
+        self.state.read().unwrap().chats.keys().copied().collect()
+    }
+
+    pub fn user(&self, user_id: i32) -> Option<User> {
+        self.state.read().unwrap().users.get(&user_id).cloned()
+    }
+
+    pub fn basic_group(&self, basic_group_id: i32) -> Option<BasicGroup> {
+        self.state.read().unwrap().basic_groups.get(&basic_group_id).cloned()
+    }
+
+    pub fn supergroup(&self, supergroup_id: i32) -> Option<Supergroup> {
+        self.state.read().unwrap().supergroups.get(&supergroup_id).cloned()
+    }
+
+    pub fn secret_chat(&self, secret_chat_id: i32) -> Option<SecretChat> {
+        self.state.read().unwrap().secret_chats.get(&secret_chat_id).cloned()
+    }
+
+    pub fn user_full_info(&self, user_id: i32) -> Option<UserFullInfo> {
+        self.state.read().unwrap().user_full_info.get(&user_id).cloned()
+    }
+
+    pub fn basic_group_full_info(&self, basic_group_id: i32) -> Option<BasicGroupFullInfo> {
+        self.state.read().unwrap().basic_group_full_info.get(&basic_group_id).cloned()
+    }
+
+    pub fn supergroup_full_info(&self, supergroup_id: i32) -> Option<SupergroupFullInfo> {
+        self.state.read().unwrap().supergroup_full_info.get(&supergroup_id).cloned()
+    }
+
+    /// Runs `f` on a chat without cloning it
+    pub fn with_chat<R, F: FnOnce(&Chat) -> R>(&self, chat_id: i64, f: F) -> Option<R> {
+        self.state.read().unwrap().chats.get(&chat_id).map(f)
+    }
+}
+
+impl Handler for Cache {
+    fn handle(&self, _: Client, upd: Update) -> BoxFuture<'static, ()> {
+        self.apply(&upd);
+        Box::pin(async {})
+    }
+}
+
+/// A handler behind a `Cache`, see `Cache::wrap`
+pub struct Cached<H> {
+    cache: Cache,
+    handler: H,
+}
+
+impl<H> Cached<H> {
+    pub fn cache(&self) -> &Cache {
+        &self.cache
+    }
+}
+
+impl<H: Handler> Handler for Cached<H> {
+    fn handle(&self, client: Client, upd: Update) -> BoxFuture<'static, ()> {
+        // Applied here rather than in the returned future, which may run
+        // after futures of later updates
+        self.cache.apply(&upd);
+        self.handler.handle(client, upd)
+    }
+
+    fn handle_raw(&self, client: Client, upd: &RawUpdate) -> Option<BoxFuture<'static, ()>> {
+        self.handler.handle_raw(client, upd)
+    }
+}
@@ -0,0 +1,242 @@
+use std::{
+    collections::HashMap,
+    mem,
+    sync::{ Arc, Mutex },
+};
+use futures::{ channel::mpsc, future::BoxFuture };
+use paperplane_types::{ methods, types::{ ChatList, Update } };
+use crate::{
+    client::Client,
+    error::Result,
+    update::{ Handler, RawUpdate },
+};
+
+/// A change of a `ChatListView`. Indices are positions in the list right
+/// before (`from`, `Removed`) and right after (`to`, `Inserted`) the change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatListEvent {
+    Inserted { chat_id: i64, index: usize },
+    Moved { chat_id: i64, from: usize, to: usize },
+    Removed { chat_id: i64, index: usize },
+}
+
+// What decides whether and where a chat is in the list
+#[derive(Default)]
+struct Position {
+    order: i64,
+    is_pinned: bool,
+    in_list: bool,
+}
+
+#[derive(Default)]
+struct State {
+    positions: HashMap<i64, Position>,
+    // `(order, chat_id)`, largest first like TDLib sorts chats
+    sorted: Vec<(i64, i64)>,
+    complete: bool,
+    listeners: Vec<mpsc::UnboundedSender<ChatListEvent>>,
+}
+
+impl State {
+    fn index(&self, order: i64, chat_id: i64) -> std::result::Result<usize, usize> {
+        self.sorted.binary_search_by(|&(other_order, other_id)| {
+            (order, chat_id).cmp(&(other_order, other_id))
+        })
+    }
+
+    // Puts the chat where its position says, reporting what moved
+    fn place(&mut self, chat_id: i64, old_order: Option<i64>) {
+        let new_order = self.current(chat_id);
+        if old_order == new_order {
+            return;
+        }
+        let from = old_order.and_then(|order| self.index(order, chat_id).ok());
+        if let Some(from) = from {
+            self.sorted.remove(from);
+        }
+        let to = new_order.map(|order| {
+            let to = self.index(order, chat_id).unwrap_or_else(|index| index);
+            self.sorted.insert(to, (order, chat_id));
+            to
+        });
+        let event = match (from, to) {
+            (Some(from), Some(to)) if from == to => return,
+            (Some(from), Some(to)) => ChatListEvent::Moved { chat_id, from, to },
+            (None, Some(index)) => ChatListEvent::Inserted { chat_id, index },
+            (Some(index), None) => ChatListEvent::Removed { chat_id, index },
+            (None, None) => return,
+        };
+        self.listeners.retain(|tx| tx.unbounded_send(event).is_ok());
+    }
+
+    // Order of the chat if it is in the list, which locates it in `sorted`
+    fn current(&self, chat_id: i64) -> Option<i64> {
+        self.positions.get(&chat_id)
+            .filter(|position| position.in_list && position.order != 0)
+            .map(|position| position.order)
+    }
+
+    fn update<F: FnOnce(&mut Position)>(&mut self, chat_id: i64, f: F) {
+        let old_order = self.current(chat_id);
+        f(self.positions.entry(chat_id).or_default());
+        self.place(chat_id, old_order);
+    }
+}
+
+/// One `ChatList` sorted the way TDLib orders it, maintained from updates.
+///
+/// Chats appear as TDLib reports them, which it starts doing once they are
+/// requested with `load_more`. Feed it updates in order by wrapping the
+/// client's handler, like a `Cache`:
+///
+/// ```ignore
+/// let archive = ChatListView::new(ChatList::ChatListArchive(ChatListArchive {}));
+/// let client = Client::new(None, archive.wrap(dispatcher));
+/// let mut events = archive.events();
+/// archive.load_more(&client, 20).await?;
+/// ```
+#[derive(Clone)]
+pub struct ChatListView {
+    list: ChatList,
+    state: Arc<Mutex<State>>,
+}
+
+impl ChatListView {
+    pub fn new(list: ChatList) -> Self {
+        Self { list, state: Arc::new(Mutex::new(State::default())) }
+    }
+
+    pub fn list(&self) -> &ChatList {
+        &self.list
+    }
+
+    fn is_ours(&self, list: &Option<ChatList>) -> bool {
+        list.as_ref().is_some_and(|list| mem::discriminant(list) == mem::discriminant(&self.list))
+    }
+
+    pub fn apply(&self, upd: &Update) {
+        let mut state = self.state.lock().unwrap();
+        match upd {
+            Update::UpdateNewChat(upd) => {
+                let in_list = self.is_ours(&upd.chat.chat_list);
+                state.update(upd.chat.id, |position| *position = Position {
+                    order: upd.chat.order,
+                    is_pinned: upd.chat.is_pinned,
+                    in_list,
+                });
+            },
+            Update::UpdateChatChatList(upd) => {
+                let in_list = self.is_ours(&upd.chat_list);
+                state.update(upd.chat_id, |position| position.in_list = in_list);
+            },
+            Update::UpdateChatOrder(upd) => {
+                state.update(upd.chat_id, |position| position.order = upd.order);
+            },
+            Update::UpdateChatLastMessage(upd) => {
+                state.update(upd.chat_id, |position| position.order = upd.order);
+            },
+            Update::UpdateChatIsPinned(upd) => {
+                state.update(upd.chat_id, |position| {
+                    position.is_pinned = upd.is_pinned;
+                    position.order = upd.order;
+                });
+            },
+            Update::UpdateChatSource(upd) => {
+                state.update(upd.chat_id, |position| position.order = upd.order);
+            },
+            Update::UpdateChatDraftMessage(upd) => {
+                state.update(upd.chat_id, |position| position.order = upd.order);
+            },
+            _ => (),
+        }
+    }
+
+    /// Handler applying every update to the view before passing it on
+    pub fn wrap<H: Handler>(&self, handler: H) -> ChatListHandler<H> {
+        ChatListHandler { view: self.clone(), handler }
+    }
+
+    /// Changes from now on, in the order they happened
+    pub fn events(&self) -> mpsc::UnboundedReceiver<ChatListEvent> {
+        let (tx, rx) = mpsc::unbounded();
+        self.state.lock().unwrap().listeners.push(tx);
+        rx
+    }
+
+    /// Chat ids from the top of the list
+    pub fn chat_ids(&self) -> Vec<i64> {
+        self.state.lock().unwrap().sorted.iter().map(|&(_, chat_id)| chat_id).collect()
+    }
+
+    /// Pinned chats, which TDLib keeps at the top
+    pub fn pinned_chat_ids(&self) -> Vec<i64> {
+        let state = self.state.lock().unwrap();
+        state.sorted
+            .iter()
+            .map(|&(_, chat_id)| chat_id)
+            .filter(|chat_id| state.positions.get(chat_id).is_some_and(|position| position.is_pinned))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().sorted.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether `load_more` has reached the end of the list
+    pub fn is_complete(&self) -> bool {
+        self.state.lock().unwrap().complete
+    }
+
+    /// Asks TDLib for up to `limit` chats after the last one in the view
+    /// and resolves to how many new chats it returned. The chats arrive as
+    /// updates before the response, so the view already holds them by then.
+    pub fn load_more(&self, client: &Client, limit: i32) -> BoxFuture<'static, Result<usize>> {
+        let (offset_order, offset_chat_id) = self.state.lock().unwrap()
+            .sorted
+            .last()
+            .copied()
+            .unwrap_or((i64::MAX, 0));
+        let request = client.send(methods::GetChats {
+            chat_list: self.list.clone(),
+            offset_order,
+            offset_chat_id,
+            limit,
+        });
+        let state = self.state.clone();
+        Box::pin(async move {
+            let loaded = request?.await?.chat_ids.len();
+            if loaded == 0 {
+                state.lock().unwrap().complete = true;
+            }
+            Ok(loaded)
+        })
+    }
+}
+
+impl Handler for ChatListView {
+    fn handle(&self, _: Client, upd: Update) -> BoxFuture<'static, ()> {
+        self.apply(&upd);
+        Box::pin(async {})
+    }
+}
+
+/// A handler behind a `ChatListView`, see `ChatListView::wrap`
+pub struct ChatListHandler<H> {
+    view: ChatListView,
+    handler: H,
+}
+
+impl<H: Handler> Handler for ChatListHandler<H> {
+    fn handle(&self, client: Client, upd: Update) -> BoxFuture<'static, ()> {
+        self.view.apply(&upd);
+        self.handler.handle(client, upd)
+    }
+
+    fn handle_raw(&self, client: Client, upd: &RawUpdate) -> Option<BoxFuture<'static, ()>> {
+        self.handler.handle_raw(client, upd)
+    }
+}
@@ -1,122 +1,491 @@
 use log::{ info, error, warn, trace };
+#[cfg(feature = "tdjson")]
 use crate::bindings::Tdlib;
+use crate::transport::Transport;
 use std::{
+    any::Any,
     marker::PhantomData,
+    panic::{ self, AssertUnwindSafe },
     task::{ Waker, Context, Poll },
     future::Future,
     pin::Pin,
-    sync::{ Arc, Mutex },
-    thread,
+    sync::{ Arc, Weak, Mutex, PoisonError, atomic::{ AtomicBool, AtomicUsize, Ordering } },
+    thread::{ self, JoinHandle },
+    time::{ Duration, Instant },
+    cmp::Reverse,
+    mem,
 };
+use futures::{ channel::oneshot, future::BoxFuture, ready };
 use crossbeam::channel::{
     self,
     Sender,
     Receiver,
+    RecvTimeoutError,
 };
 use uuid::Uuid;
-use std::collections::HashMap;
+use std::collections::{ HashMap, BinaryHeap, VecDeque };
+use serde::Deserialize;
 use serde_json::Value as JsonValue;
-use crate::update::Handler;
-use paperplane_types::methods::Method;
+use crate::update::{ self, Handler, Broadcast, RawUpdate, UpdateStream, LagPolicy };
+use paperplane_types::{ methods::{ self, Method, SyncMethod }, types };
+use crate::error::{ Error, Result };
+use crate::limit::{ Limiter, LimitStats, RateLimits };
+use crate::spawn::{ self, Spawner };
 
 
 #[derive(Debug)]
 pub struct RequestData {
-    resp: Option<JsonValue>,
+    resp: Option<Result<JsonValue>>,
     waker: Option<Waker>,
 }
 
 type RequestDataRef = Arc<Mutex<RequestData>>;
 
 #[derive(Debug)]
-struct RequestDataToStream {
+pub(crate) struct RequestDataToStream {
+    id: Uuid,
     data: RequestDataRef,
     req: JsonValue,
+    deadline: Option<Instant>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct RequestFuture<M: Method> {
-    _response_type_holder: PhantomData<M>,
+    _response_type_holder: PhantomData<fn() -> M>,
     pub data: RequestDataRef,
+    id: Uuid,
+    sender: Sender<JoinStreams>,
+    done: bool,
 }
 
 impl<M: Method> Future for RequestFuture<M> {
-    type Output = Result<M::Response, serde_json::error::Error>;
+    type Output = Result<M::Response>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
-        let mut data = self.data.lock().unwrap();
-        if let Some(resp) = &data.resp {
-            Poll::Ready(serde_json::from_value(resp.clone()))
-        } else {
+        let this = self.get_mut();
+        let resp = ready!(poll_response(&this.data, cx));
+        this.done = true;
+        Poll::Ready(resp.and_then(parse_response))
+    }
+}
+
+impl<M: Method> Drop for RequestFuture<M> {
+    fn drop(&mut self) {
+        if !self.done {
+            cancel(&self.sender, self.id);
+        }
+    }
+}
+
+/// Future of `Client::send_raw`, resolving to the response's JSON
+#[derive(Debug)]
+pub struct RawRequestFuture {
+    data: RequestDataRef,
+    id: Uuid,
+    sender: Sender<JoinStreams>,
+    done: bool,
+}
+
+impl Future for RawRequestFuture {
+    type Output = Result<JsonValue>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let resp = ready!(poll_response(&this.data, cx));
+        this.done = true;
+        Poll::Ready(resp.and_then(parse_response))
+    }
+}
+
+impl Drop for RawRequestFuture {
+    fn drop(&mut self) {
+        if !self.done {
+            cancel(&self.sender, self.id);
+        }
+    }
+}
+
+fn poll_response(data: &RequestDataRef, cx: &mut Context) -> Poll<Result<JsonValue>> {
+    let mut data = data.lock().unwrap();
+    match data.resp.take() {
+        Some(resp) => Poll::Ready(resp),
+        None => {
             data.waker = Some(cx.waker().clone());
             Poll::Pending
-        }
+        },
+    }
+}
+
+fn cancel(sender: &Sender<JoinStreams>, id: Uuid) {
+    // The responder may be gone already, nothing to clean up then
+    let _ = sender.send(JoinStreams::Cancel(id));
+}
+
+fn parse_response<T: serde::de::DeserializeOwned>(resp: JsonValue) -> Result<T> {
+    if resp["@type"] == "error" {
+        let err: types::Error = serde_json::from_value(resp)?;
+        Err(err.into())
+    } else {
+        Ok(serde_json::from_value(resp)?)
     }
 }
 
 #[derive(Debug)]
-enum JoinStreams {
+pub(crate) enum JoinStreams {
     NewRequest(RequestDataToStream),
-    NewResponse(String),
+    NewResponse(JsonValue),
+    Cancel(Uuid),
+    SetRateLimits(RateLimits),
+    // Stops the responder as if the instance was closed
+    Stop,
+}
+
+#[derive(Debug, Default)]
+struct CloseState {
+    // Set once the responder takes no more requests, before it drains
+    // the channel; checked by `Client::enqueue` under the same lock
+    stopping: bool,
+    closed: bool,
+    wakers: Vec<Waker>,
+}
+
+type CloseStateRef = Arc<Mutex<CloseState>>;
+
+fn mark_closed(state: &CloseStateRef) {
+    let mut state = state.lock().unwrap();
+    state.closed = true;
+    for waker in state.wakers.drain(..) {
+        waker.wake();
+    }
+}
+
+/// Resolves once the responder has stopped and the TDLib instance is destroyed
+struct Closed {
+    state: CloseStateRef,
+}
+
+impl Future for Closed {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let mut state = self.state.lock().unwrap();
+        if state.closed {
+            Poll::Ready(())
+        } else {
+            state.wakers.push(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+type ErrorHook = Arc<dyn Fn(&Error) + Send + Sync + 'static>;
+
+// Where errors no request is waiting for end up, see `Client::set_error_hook`
+#[derive(Clone, Default)]
+struct ErrorReporter {
+    hook: Arc<Mutex<Option<ErrorHook>>>,
+}
+
+impl ErrorReporter {
+    fn report(&self, err: Error) {
+        let hook = self.hook.lock().unwrap_or_else(PoisonError::into_inner).clone();
+        if let Some(hook) = hook {
+            if panic::catch_unwind(AssertUnwindSafe(|| hook(&err))).is_err() {
+                error!("error hook panicked on: {}", err);
+            }
+        }
+    }
+}
+
+struct ClientInner {
+    sender: Sender<JoinStreams>,
+    close_state: CloseStateRef,
+    responder_handle: Mutex<Option<JoinHandle<()>>>,
+    default_timeout: Mutex<Option<Duration>>,
+    pending: Arc<AtomicUsize>,
+    broadcast: Arc<Broadcast>,
+    limit_stats: Arc<Mutex<LimitStats>>,
+    errors: ErrorReporter,
+    // Owned by the responder, which destroys the instance on close
+    api: Weak<dyn Transport>,
+}
+
+impl Drop for ClientInner {
+    fn drop(&mut self) {
+        if self.close_state.lock().unwrap().closed {
+            return;
+        }
+        // Last handle is gone: ask TDLib to close and let the detached
+        // threads clean up once authorizationStateClosed arrives
+        if let Ok(req) = serde_json::to_value(methods::Close {}.tag()) {
+            let data = Arc::new(Mutex::new(RequestData { resp: None, waker: None }));
+            let _ = self.sender.send(JoinStreams::NewRequest(
+                RequestDataToStream { id: Uuid::new_v4(), data, req, deadline: None }
+            ));
+        }
+    }
 }
 
 #[derive(Clone)]
 pub struct Client {
-    //waker_handle: std::thread::JoinHandle<()>,
-    sender: Sender<JoinStreams>,
+    inner: Arc<ClientInner>,
 }
 
 impl Client {
+    #[cfg(feature = "tdjson")]
     pub fn new<H: Handler>(log_opt: Option<i32>, updater: H) -> Self {
         if let Some(log) = log_opt {
             Tdlib::set_log_verbosity_level(log);
         }
+        Self::with_transport(Tdlib::new(), updater)
+    }
+
+    /// Creates a client talking to TDLib through `transport` instead of
+    /// a fresh libtdjson instance, e.g. a `testing::MockTdlib`
+    pub fn with_transport<T: Transport, H: Handler>(transport: T, updater: H) -> Self {
+        Self::start(Arc::new(transport), updater, spawn::default_spawner(), true).0
+    }
+
+    /// Like `with_transport`, running handlers with `spawner` instead of
+    /// `spawn::default_spawner()`
+    pub fn with_spawner<T, H, S>(transport: T, updater: H, spawner: S) -> Self
+    where T: Transport,
+          H: Handler,
+          S: Spawner {
+        Self::start(Arc::new(transport), updater, Arc::new(spawner), true).0
+    }
+
+    /// Spawns the responder thread, and with `listen` a listener thread
+    /// polling `api`. Without it, incoming messages must be fed through
+    /// the returned sender, as the `ClientManager` receive loop does.
+    pub(crate) fn start<H: Handler>(
+        api: Arc<dyn Transport>,
+        updater: H,
+        spawner: Arc<dyn Spawner>,
+        listen: bool,
+    ) -> (Self, Sender<JoinStreams>) {
         let (tx, rx) = channel::unbounded();
 
-        let tx_for_tg = tx.clone();
-        let api = Arc::new(Tdlib::new());
-        let api_for_listener = api.clone();
-        let api_for_responder = api.clone();
-        let sender_for_responder = tx.clone();
-        let rt = tokio::runtime::Handle::try_current().expect("Must be in runtime");
+        let close_state = CloseStateRef::default();
+        let pending = Arc::new(AtomicUsize::new(0));
+        let broadcast = Arc::new(Broadcast::default());
+        let limit_stats = Arc::new(Mutex::new(LimitStats::default()));
+        let errors = ErrorReporter::default();
 
-        let _run_handle = thread::spawn(
-            move || OneshotResponder::new(rx, api_for_responder).run(
-                updater, Self { sender: sender_for_responder }, rt
+        let inner = Arc::new(ClientInner {
+            sender: tx.clone(),
+            close_state: close_state.clone(),
+            responder_handle: Mutex::new(None),
+            default_timeout: Mutex::new(None),
+            pending: pending.clone(),
+            broadcast: broadcast.clone(),
+            limit_stats: limit_stats.clone(),
+            errors: errors.clone(),
+            api: Arc::downgrade(&api),
+        });
+        let weak_inner = Arc::downgrade(&inner);
+
+        let listener = if listen {
+            let running = Arc::new(AtomicBool::new(true));
+            let running_for_listener = running.clone();
+            let tx_for_tg = tx.clone();
+            let api_for_listener = api.clone();
+            let errors_for_listener = errors.clone();
+            let tg_handle = thread::spawn(move || Self::listen_tg(
+                tx_for_tg, api_for_listener, running_for_listener, errors_for_listener, 1.0
+            ));
+            Some((running, tg_handle))
+        } else {
+            None
+        };
+        let shutdown = Shutdown { listener, close_state };
+        let run_handle = thread::spawn(
+            move || OneshotResponder::new(rx, api, pending, broadcast, limit_stats, errors).run(
+                updater, weak_inner, spawner, shutdown
             )
         );
-        let _tg_handle = thread::spawn(
-            move || Self::listen_tg(tx_for_tg, api_for_listener, 1.0)
-        );
-        Self {
-            //waker_handle: _run_handle,
-            sender: tx,
+        *inner.responder_handle.lock().unwrap() = Some(run_handle);
+        (Self { inner }, tx)
+    }
+
+    #[cfg(feature = "multi-client")]
+    pub(crate) fn sender(&self) -> &Sender<JoinStreams> {
+        &self.inner.sender
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.inner.close_state.lock().unwrap().closed
+    }
+
+    /// Closes the TDLib instance, waits for `authorizationStateClosed`,
+    /// stops the background threads and fails every pending request
+    /// with `Error::ClientClosed`.
+    pub async fn close(&self) -> Result<()> {
+        match self.send(methods::Close {}) {
+            Ok(fut) => match fut.await {
+                Ok(_) | Err(Error::ClientClosed) => (),
+                Err(err) => return Err(err),
+            },
+            Err(Error::ClientClosed) => (),
+            Err(err) => return Err(err),
         }
+        Closed { state: self.inner.close_state.clone() }.await;
+        let handle = self.inner.responder_handle.lock().unwrap().take();
+        if let Some(handle) = handle {
+            // Joined on a thread of its own to keep the executor running
+            // while the responder winds down
+            let (tx, rx) = oneshot::channel();
+            thread::spawn(move || {
+                let _ = tx.send(handle.join().is_ok());
+            });
+            if rx.await != Ok(true) {
+                error!("responder thread panicked");
+            }
+        }
+        Ok(())
     }
 
-    pub fn send<R: Method>(&self, req: R) -> Result<RequestFuture<R>, serde_json::error::Error> {
-        let request = RequestData {
-            resp: None,
-            waker: None
-        };
-        let fut = RequestFuture {
+    /// Timeout applied by `send` to every request; `None` waits forever
+    pub fn set_default_timeout(&self, timeout: Option<Duration>) {
+        *self.inner.default_timeout.lock().unwrap() = timeout;
+    }
+
+    /// Number of requests still waiting for a response
+    pub fn pending_requests(&self) -> usize {
+        self.inner.pending.load(Ordering::Relaxed)
+    }
+
+    /// Holds requests back to stay within `limits` from now on, replacing
+    /// the previous limits
+    pub fn set_rate_limits(&self, limits: RateLimits) -> Result<()> {
+        self.inner.sender
+            .send(JoinStreams::SetRateLimits(limits))
+            .map_err(|_| Error::ClientClosed)
+    }
+
+    /// Calls `hook` with errors no request is waiting for: messages from
+    /// TDLib that make no sense, updates that fail to deserialize and
+    /// panics of the handler, the responder or the listener. Runs on the
+    /// client's background threads, so it should return quickly.
+    pub fn set_error_hook<F>(&self, hook: F)
+    where F: Fn(&Error) + Send + Sync + 'static {
+        *self.inner.errors.hook.lock().unwrap() = Some(Arc::new(hook));
+    }
+
+    /// How long the rate limiter has held requests so far
+    pub fn rate_limit_stats(&self) -> LimitStats {
+        *self.inner.limit_stats.lock().unwrap()
+    }
+
+    /// Stream of all updates in TDLib's delivery order, independent of the
+    /// `Handler`. Buffers `update::DEFAULT_CAPACITY` updates and drops the
+    /// oldest ones when the subscriber falls behind.
+    pub fn updates(&self) -> UpdateStream {
+        self.updates_with(update::DEFAULT_CAPACITY, LagPolicy::default())
+    }
+
+    pub fn updates_with(&self, capacity: usize, policy: LagPolicy) -> UpdateStream {
+        self.inner.broadcast.subscribe(capacity, policy)
+    }
+
+    pub fn send<R: Method>(&self, req: R) -> Result<RequestFuture<R>> {
+        let timeout = *self.inner.default_timeout.lock().unwrap();
+        self.send_with_deadline(req, timeout.map(|t| Instant::now() + t))
+    }
+
+    pub fn send_with_timeout<R: Method>(&self, req: R, timeout: Duration) -> Result<RequestFuture<R>> {
+        self.send_with_deadline(req, Some(Instant::now() + timeout))
+    }
+
+    /// Runs a method TDLib answers synchronously on the calling thread,
+    /// without a round-trip through the responder
+    pub fn execute<R: SyncMethod>(&self, req: R) -> Result<R::Response> {
+        let api = self.inner.api.upgrade().ok_or(Error::ClientClosed)?;
+        let request = serde_json::to_string(&req.tag())?;
+        match api.execute(&request)? {
+            Some(resp) => parse_response(serde_json::from_str(&resp)?),
+            None => Err(Error::Protocol(format!("no response to {}", R::TYPE))),
+        }
+    }
+
+    /// Sends a request given as JSON, e.g. for a method `paperplane_types`
+    /// does not have yet. `@extra` is set by the client like for `send`;
+    /// an `error` response fails the future, anything else is returned
+    /// as TDLib sent it.
+    pub fn send_raw(&self, req: JsonValue) -> Result<RawRequestFuture> {
+        if !req.is_object() {
+            return Err(Error::InvalidRequest(format!("not a JSON object: {}", req)));
+        }
+        let timeout = *self.inner.default_timeout.lock().unwrap();
+        let (id, data) = self.enqueue(req, timeout.map(|t| Instant::now() + t))?;
+        Ok(RawRequestFuture {
+            data,
+            id,
+            sender: self.inner.sender.clone(),
+            done: false,
+        })
+    }
+
+    // Sends `req` without waiting for the response, which is dropped when
+    // it arrives. Unlike dropping a `RequestFuture` this does not cancel
+    // the request, so it still goes out when the rate limiter holds it.
+    #[cfg(any(test, feature = "multi-client"))]
+    pub(crate) fn send_detached<R: Method>(&self, req: R) -> Result<()> {
+        self.enqueue(serde_json::to_value(req.tag())?, None).map(drop)
+    }
+
+    fn send_with_deadline<R: Method>(&self, req: R, deadline: Option<Instant>) -> Result<RequestFuture<R>> {
+        let (id, data) = self.enqueue(serde_json::to_value(req.tag())?, deadline)?;
+        Ok(RequestFuture {
             _response_type_holder: PhantomData,
-            data: Arc::new(Mutex::new(request))
-        };
+            data,
+            id,
+            sender: self.inner.sender.clone(),
+            done: false,
+        })
+    }
 
-        self.sender.send(JoinStreams::NewRequest(
-            RequestDataToStream {
-                data: fut.data.clone(),
-                req: serde_json::to_value(req.tag())?,
-            }
-        )).unwrap();
-        Ok(fut)
+    // Hands the request to the responder, which fills in the returned data
+    fn enqueue(&self, req: JsonValue, deadline: Option<Instant>) -> Result<(Uuid, RequestDataRef)> {
+        let id = Uuid::new_v4();
+        let data = Arc::new(Mutex::new(RequestData { resp: None, waker: None }));
+        let close_state = self.inner.close_state.lock().unwrap();
+        if close_state.stopping {
+            return Err(Error::ClientClosed);
+        }
+        self.inner.sender
+            .send(JoinStreams::NewRequest(RequestDataToStream { id, data: data.clone(), req, deadline }))
+            .map_err(|_| Error::ClientClosed)?;
+        Ok((id, data))
     }
 
-    fn listen_tg(tx: Sender<JoinStreams>, api: Arc<Tdlib>, timeout: f64) {
-        loop {
+    fn listen_tg(
+        tx: Sender<JoinStreams>,
+        api: Arc<dyn Transport>,
+        running: Arc<AtomicBool>,
+        errors: ErrorReporter,
+        timeout: f64,
+    ) {
+        let listened = panic::catch_unwind(AssertUnwindSafe(|| Self::receive_loop(&tx, &*api, &running, timeout)));
+        if let Err(payload) = listened {
+            let reason = format!("listener thread: {}", panic_message(&*payload));
+            error!("{}, closing client", reason);
+            errors.report(Error::Panic(reason));
+            // Nothing answers pending requests anymore, so fail them
+            let _ = tx.send(JoinStreams::Stop);
+        }
+    }
+
+    fn receive_loop(tx: &Sender<JoinStreams>, api: &dyn Transport, running: &AtomicBool, timeout: f64) {
+        while running.load(Ordering::Acquire) {
             if let Some(msg) = api.receive(timeout) {
-                tx.send(JoinStreams::NewResponse(msg)).unwrap();
+                match serde_json::from_str(&msg) {
+                    Ok(val) => if tx.send(JoinStreams::NewResponse(val)).is_err() {
+                        warn!("responder is gone, stopping listener");
+                        break;
+                    },
+                    Err(e) => warn!("ignoring invalid response. err: {}, resp: {}", e, msg),
+                }
             } else {
                 info!("receive timed out");
             }
@@ -124,63 +493,273 @@ impl Client {
     }
 }
 
-#[derive(Debug)]
+struct Shutdown {
+    // Absent when the receive loop is driven by a `ClientManager`
+    listener: Option<(Arc<AtomicBool>, JoinHandle<()>)>,
+    close_state: CloseStateRef,
+}
+
+// A request the rate limiter has not let through yet
+struct Held {
+    id: Uuid,
+    request: JsonValue,
+    since: Instant,
+    // Set for flood-waited requests, which must not be resent earlier
+    not_before: Option<Instant>,
+}
+
 struct OneshotResponder {
-    api: Arc<Tdlib>,
+    api: Arc<dyn Transport>,
     wakers_map: HashMap<Uuid, RequestDataRef>,
+    // Lazily cleaned: entries whose request is already gone are skipped
+    deadlines: BinaryHeap<Reverse<(Instant, Uuid)>>,
+    pending: Arc<AtomicUsize>,
+    broadcast: Arc<Broadcast>,
     rx: Receiver<JoinStreams>,
+    limiter: Limiter,
+    held: VecDeque<Held>,
+    // When a held request may get through next
+    next_release: Option<Instant>,
+    // Requests in flight, kept while 429s are retried
+    sent: HashMap<Uuid, (JsonValue, Instant)>,
+    limit_stats: Arc<Mutex<LimitStats>>,
+    errors: ErrorReporter,
 }
 
 impl OneshotResponder {
-    fn new(rx: Receiver<JoinStreams>, api: Arc<Tdlib>) -> Self {
+    fn new(
+        rx: Receiver<JoinStreams>,
+        api: Arc<dyn Transport>,
+        pending: Arc<AtomicUsize>,
+        broadcast: Arc<Broadcast>,
+        limit_stats: Arc<Mutex<LimitStats>>,
+        errors: ErrorReporter,
+    ) -> Self {
         Self {
-            api: api,
+            api,
             wakers_map: HashMap::new(),
-            rx: rx
+            deadlines: BinaryHeap::new(),
+            pending,
+            broadcast,
+            rx,
+            limiter: Limiter::default(),
+            held: VecDeque::new(),
+            next_release: None,
+            sent: HashMap::new(),
+            limit_stats,
+            errors,
+        }
+    }
+
+    fn recv(&mut self) -> std::result::Result<JoinStreams, channel::RecvError> {
+        loop {
+            let now = Instant::now();
+            // Checked before receiving, as a busy channel never times out
+            if matches!(self.deadlines.peek(), Some(Reverse((deadline, _))) if *deadline <= now) {
+                self.expire(now);
+            }
+            if matches!(self.next_release, Some(release) if release <= now) {
+                self.release(now);
+            }
+            let next_deadline = self.deadlines.peek().map(|Reverse((deadline, _))| *deadline);
+            let wake_at = match (next_deadline, self.next_release) {
+                (Some(deadline), Some(release)) => deadline.min(release),
+                (Some(at), None) | (None, Some(at)) => at,
+                (None, None) => return self.rx.recv(),
+            };
+            match self.rx.recv_timeout(wake_at.saturating_duration_since(now)) {
+                Ok(msg) => return Ok(msg),
+                Err(RecvTimeoutError::Timeout) => (),
+                Err(RecvTimeoutError::Disconnected) => return Err(channel::RecvError),
+            }
+        }
+    }
+
+    // Sends the request now if no limit applies, otherwise queues it
+    fn submit(&mut self, id: Uuid, request: JsonValue, not_before: Option<Instant>) {
+        if self.limiter.is_unlimited() && self.held.is_empty() && not_before.is_none() {
+            self.send_now(id, request);
+            return;
+        }
+        let now = Instant::now();
+        self.held.push_back(Held { id, request, since: now, not_before });
+        self.release(now);
+    }
+
+    // Sends every held request the limiter lets through, in order
+    fn release(&mut self, now: Instant) {
+        self.next_release = None;
+        for held in mem::take(&mut self.held) {
+            if !self.wakers_map.contains_key(&held.id) {
+                // Timed out or cancelled while held
+                continue;
+            }
+            let admitted = match held.not_before {
+                Some(not_before) if not_before > now => Err(not_before - now),
+                _ => self.limiter.admit(&held.request, now),
+            };
+            match admitted {
+                Ok(()) => {
+                    let held_for = now.saturating_duration_since(held.since);
+                    if held_for > Duration::from_secs(0) {
+                        self.limit_stats.lock().unwrap().record_hold(held_for);
+                    }
+                    self.send_now(held.id, held.request);
+                },
+                Err(wait) => {
+                    let at = now + wait;
+                    self.next_release = Some(self.next_release.map_or(at, |next| next.min(at)));
+                    self.held.push_back(held);
+                },
+            }
+        }
+    }
+
+    fn send_now(&mut self, id: Uuid, request: JsonValue) {
+        trace!("new req:\n{:#}", request);
+        if let Err(err) = self.api.send(request.to_string().as_ref()) {
+            warn!("could not send request {}: {}", id, err);
+            if let Some(data) = self.wakers_map.remove(&id) {
+                self.update_pending();
+                fail_request(&data, err);
+            }
+            return;
         }
+        if self.limiter.retries() {
+            self.sent.insert(id, (request, Instant::now()));
+        }
+    }
+
+    // Queues a request that failed with 429 again, if retries are on
+    fn retry_flood_wait(&mut self, id: Uuid, resp: &JsonValue) -> bool {
+        let (request, since) = match self.sent.remove(&id) {
+            Some(sent) => sent,
+            None => return false,
+        };
+        let err = match serde_json::from_value::<types::Error>(resp.clone()) {
+            Ok(err) => Error::from(err),
+            Err(_) => return false,
+        };
+        if !err.is_flood_wait() {
+            return false;
+        }
+        let advised = err.retry_after().unwrap_or_else(|| Duration::from_secs(1));
+        let delay = match self.limiter.retry_delay(advised) {
+            Some(delay) => delay,
+            None => return false,
+        };
+        info!("request {} flood-waited, retrying in {:?}", id, delay);
+        self.limit_stats.lock().unwrap().retried += 1;
+        let now = Instant::now();
+        self.held.push_back(Held { id, request, since, not_before: Some(now + delay) });
+        self.release(now);
+        true
+    }
+
+    fn expire(&mut self, now: Instant) {
+        while let Some(Reverse((deadline, id))) = self.deadlines.peek().cloned() {
+            if deadline > now {
+                break;
+            }
+            self.deadlines.pop();
+            self.sent.remove(&id);
+            if let Some(data) = self.wakers_map.remove(&id) {
+                trace!("request {} timed out", id);
+                self.update_pending();
+                fail_request(&data, Error::Timeout);
+            }
+        }
+    }
+
+    fn update_pending(&self) {
+        self.pending.store(self.wakers_map.len(), Ordering::Relaxed);
     }
     
-    fn run<H: Handler>(&mut self, updater: H, client: Client, rt: tokio::runtime::Handle) {
+    fn run<H: Handler>(
+        mut self,
+        updater: H,
+        client: Weak<ClientInner>,
+        spawner: Arc<dyn Spawner>,
+        shutdown: Shutdown,
+    ) {
+        // A panic ends the client as if it was closed, instead of leaving
+        // every pending request waiting for a thread that is gone
+        let served = panic::catch_unwind(AssertUnwindSafe(|| self.serve(&updater, &client, &*spawner)));
+        if let Err(payload) = served {
+            let reason = format!("responder thread: {}", panic_message(&*payload));
+            error!("{}, closing client", reason);
+            self.errors.report(Error::Panic(reason));
+        }
+        shutdown.close_state.lock().unwrap().stopping = true;
+
+        if let Some((running, tg_handle)) = shutdown.listener {
+            running.store(false, Ordering::Release);
+            if tg_handle.join().is_err() {
+                error!("listener thread panicked");
+            }
+        }
+        // Both threads are done with the instance, so this destroys it
+        drop(self.api);
+        for (_, data) in self.wakers_map.drain() {
+            fail_request(&data, Error::ClientClosed);
+        }
+        self.pending.store(0, Ordering::Relaxed);
+        while let Ok(msg) = self.rx.try_recv() {
+            if let JoinStreams::NewRequest(req_data) = msg {
+                fail_request(&req_data.data, Error::ClientClosed);
+            }
+        }
+        drop(self.rx);
+        self.broadcast.close();
+        mark_closed(&shutdown.close_state);
+    }
+
+    // Runs until the instance is closed or every sender is gone
+    fn serve<H: Handler>(&mut self, updater: &H, client: &Weak<ClientInner>, spawner: &dyn Spawner) {
         loop {
-            match self.rx.recv() {
+            match self.recv() {
                 Ok(JoinStreams::NewRequest(req_data)) => {
-                    let id = loop {
-                        let id = Uuid::new_v4();
-                        if self.wakers_map.contains_key(&id) {
-                            continue;
-                        } else {
-                            break id;
-                        }
-                    };
+                    let id = req_data.id;
                     let mut request = req_data.req;
                     if !request["@extra"].is_null() {
                         warn!("overwriting @extra in request");
                     }
                     request["@extra"] = id.to_hyphenated().to_string().into();
-                    self.api.send(request.to_string().as_ref());
                     self.wakers_map.insert(id, req_data.data);
-                    trace!("new req:\n{:#}", request);
+                    if let Some(deadline) = req_data.deadline {
+                        self.deadlines.push(Reverse((deadline, id)));
+                    }
+                    self.update_pending();
+                    self.submit(id, request, None);
                 },
-                Ok(JoinStreams::NewResponse(resp)) => {
-                    match serde_json::from_str::<JsonValue>(resp.as_ref()) {
-                        Ok(val) => {
-                            let typ = val["@type"].as_str().unwrap();
-                            if typ.starts_with("update") {
-                                match serde_json::from_value(val.clone()) {
-                                    Ok(upd) => { rt.spawn(updater.handle(client.clone(), upd)); },
-                                    Err(err) => {
-                                        error!("Could not deser update: {}, was: {}", err, val);
-                                    }
-                                };
-                            } else {
-                                self.handle_response(val);
-                            }
-                        },
-                        Err(e) => {
-                            warn!("ignoring invalid response. err: {}, resp: {}", e, resp);
-                        }
+                Ok(JoinStreams::SetRateLimits(limits)) => {
+                    self.limiter.set_limits(limits);
+                    self.release(Instant::now());
+                },
+                Ok(JoinStreams::Stop) => {
+                    info!("listener stopped, closing client");
+                    break;
+                },
+                Ok(JoinStreams::Cancel(id)) => {
+                    self.sent.remove(&id);
+                    if self.wakers_map.remove(&id).is_some() {
+                        trace!("request {} cancelled", id);
+                        self.update_pending();
                     }
                 },
+                Ok(JoinStreams::NewResponse(val)) => match val["@type"].as_str() {
+                    Some(typ) if typ.starts_with("update") => {
+                        if self.handle_update(val, updater, client, spawner) {
+                            info!("tdlib instance closed");
+                            break;
+                        }
+                    },
+                    Some(_) => self.handle_response(val),
+                    None => {
+                        warn!("ignoring message without @type: {}", val);
+                        self.errors.report(Error::Protocol(format!("message without @type: {}", val)));
+                    },
+                },
                 Err(e) => {
                     error!("stream closed: {}", e);
                     error!("closing thread");
@@ -190,21 +769,145 @@ impl OneshotResponder {
         }
     }
 
+    // Passes the update on, telling whether it closed the instance
+    fn handle_update<H: Handler>(
+        &self,
+        val: JsonValue,
+        updater: &H,
+        client: &Weak<ClientInner>,
+        spawner: &dyn Spawner,
+    ) -> bool {
+        let client = client.upgrade().map(|inner| Client { inner });
+        let raw = RawUpdate(val);
+        if let Some(client) = &client {
+            self.run_handler(spawner, || updater.handle_raw(client.clone(), &raw));
+        }
+        let upd = match types::Update::deserialize(&raw.0) {
+            Ok(upd) => upd,
+            Err(err) => {
+                error!("Could not deser update: {}, was: {}", err, raw.0);
+                self.errors.report(Error::Deserialize(err));
+                return false;
+            }
+        };
+        let closed = is_closed_update(&upd);
+        if self.broadcast.has_subscribers() {
+            self.broadcast.publish(&upd);
+        }
+        if let Some(client) = client {
+            self.run_handler(spawner, || Some(updater.handle(client, upd)));
+        }
+        closed
+    }
+
+    // Spawns what the handler returns, reporting a panic of the handler
+    fn run_handler<F>(&self, spawner: &dyn Spawner, handle: F)
+    where F: FnOnce() -> Option<BoxFuture<'static, ()>> {
+        match panic::catch_unwind(AssertUnwindSafe(handle)) {
+            Ok(Some(fut)) => spawner.spawn(fut),
+            Ok(None) => (),
+            Err(payload) => {
+                let reason = format!("update handler: {}", panic_message(&*payload));
+                error!("{}", reason);
+                self.errors.report(Error::Panic(reason));
+            },
+        }
+    }
+
     fn handle_response(&mut self, resp: JsonValue) {
-        if let Some(id_str) = resp["@extra"].as_str() {
-            if let Ok(id) = Uuid::parse_str(id_str) {
-                let fut_extracted = self.wakers_map
-                    .remove(&id)
-                    .unwrap();
-
-                let mut fut_data = fut_extracted.lock().unwrap();
-                fut_data.resp = Some(resp);
-                fut_data
-                    .waker.as_ref()
-                    .and_then(|waker: &Waker| { waker.clone().wake(); Some(()) });
+        let id = match resp["@extra"].as_str().map(Uuid::parse_str) {
+            Some(Ok(id)) => id,
+            _ => {
+                warn!("response has invalid @extra: {}", resp);
+                self.errors.report(Error::Protocol(format!("response with invalid @extra: {}", resp)));
+                return;
             }
-        } else {
-            warn!("update has invalid @extra: {}", resp);
+        };
+        if resp["@type"] == "error" && self.retry_flood_wait(id, &resp) {
+            return;
+        }
+        self.sent.remove(&id);
+        let fut_extracted = match self.wakers_map.remove(&id) {
+            Some(data) => data,
+            None => {
+                // Timed out or dropped before TDLib answered
+                trace!("dropping response to abandoned request {}", id);
+                return;
+            }
+        };
+        self.update_pending();
+
+        let mut fut_data = fut_extracted.lock().unwrap();
+        fut_data.resp = Some(Ok(resp));
+        if let Some(waker) = fut_data.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+fn fail_request(data: &RequestDataRef, err: Error) {
+    // Also used after a panic, which may have poisoned the lock
+    let mut data = data.lock().unwrap_or_else(PoisonError::into_inner);
+    data.resp = Some(Err(err));
+    if let Some(waker) = data.waker.take() {
+        waker.wake();
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload.downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
+}
+
+fn is_closed_update(upd: &types::Update) -> bool {
+    match upd {
+        types::Update::UpdateAuthorizationState(state) => matches!(
+            state.authorization_state,
+            types::AuthorizationState::AuthorizationStateClosed(_)
+        ),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use paperplane_types::types::Update;
+    use crate::{
+        limit::{ Rate, RateLimits },
+        testing::MockTdlib,
+    };
+    use super::*;
+
+    fn get_option(name: &str) -> methods::GetOption {
+        methods::GetOption { name: name.to_owned() }
+    }
+
+    fn sent_options(mock: &MockTdlib) -> Vec<String> {
+        mock.sent_of::<methods::GetOption>().into_iter().map(|req| req.name).collect()
+    }
+
+    // `ClientManager` starts instances this way, where a request that is
+    // dropped right away would be cancelled while the limiter holds it
+    #[test]
+    fn detached_requests_are_not_cancelled() {
+        let mock = MockTdlib::new();
+        mock.ignore::<methods::GetOption>();
+        let client = Client::with_transport(mock.clone(), |_: Client, _: Update| async {});
+        let limits = RateLimits::new().method("getOption", Rate::new(1, Duration::from_millis(100)));
+        client.set_rate_limits(limits).unwrap();
+
+        client.send_detached(get_option("first")).unwrap();
+        drop(client.send(get_option("dropped")).unwrap());
+        client.send_detached(get_option("detached")).unwrap();
+        let deadline = Instant::now() + Duration::from_secs(1);
+        while sent_options(&mock).len() < 2 && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
         }
+        thread::sleep(Duration::from_millis(200));
+        assert_eq!(sent_options(&mock), ["first", "detached"]);
+        futures::executor::block_on(client.close()).unwrap();
     }
 }
@@ -0,0 +1,301 @@
+use log::{ trace, warn };
+use std::{
+    collections::{ HashMap, VecDeque },
+    fmt::Display,
+    fs,
+    future::Future,
+    io,
+    marker::PhantomData,
+    path::{ Path, PathBuf },
+    sync::{ Arc, Mutex },
+    thread,
+    time::{ Duration, SystemTime, UNIX_EPOCH },
+};
+use futures::{ channel::oneshot, future::BoxFuture, lock::Mutex as AsyncMutex };
+use serde::{ Serialize, Deserialize, de::DeserializeOwned };
+use paperplane_types::types::UpdateNewMessage;
+use crate::{
+    client::Client,
+    error::{ Error, Result },
+    update::TypedHandler,
+};
+
+/// Whose dialogue a message belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DialogueKey {
+    pub chat_id: i64,
+    pub user_id: i32,
+}
+
+/// A dialogue state with the unix time it was last saved at
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Stored<S> {
+    pub state: S,
+    pub updated_at: u64,
+}
+
+/// Keeps dialogue states between updates
+pub trait Storage<S>: Send + Sync + 'static {
+    fn load(&self, key: DialogueKey) -> BoxFuture<'static, Result<Option<Stored<S>>>>;
+    fn save(&self, key: DialogueKey, stored: Stored<S>) -> BoxFuture<'static, Result<()>>;
+    fn remove(&self, key: DialogueKey) -> BoxFuture<'static, Result<()>>;
+}
+
+/// Storage lost on restart
+pub struct InMemoryStorage<S> {
+    states: Arc<Mutex<HashMap<DialogueKey, Stored<S>>>>,
+}
+
+impl<S> InMemoryStorage<S> {
+    pub fn new() -> Self {
+        Self { states: Arc::new(Mutex::new(HashMap::new())) }
+    }
+}
+
+impl<S> Default for InMemoryStorage<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Clone + Send + 'static> Storage<S> for InMemoryStorage<S> {
+    fn load(&self, key: DialogueKey) -> BoxFuture<'static, Result<Option<Stored<S>>>> {
+        let stored = self.states.lock().unwrap().get(&key).cloned();
+        Box::pin(async move { Ok(stored) })
+    }
+
+    fn save(&self, key: DialogueKey, stored: Stored<S>) -> BoxFuture<'static, Result<()>> {
+        self.states.lock().unwrap().insert(key, stored);
+        Box::pin(async { Ok(()) })
+    }
+
+    fn remove(&self, key: DialogueKey) -> BoxFuture<'static, Result<()>> {
+        self.states.lock().unwrap().remove(&key);
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Storage keeping every dialogue as a JSON file in a directory
+pub struct FileStorage<S> {
+    dir: PathBuf,
+    _state: PhantomData<fn() -> S>,
+}
+
+impl<S> FileStorage<S> {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).map_err(|err| storage_error(&dir, err))?;
+        Ok(Self { dir, _state: PhantomData })
+    }
+
+    fn path(&self, key: DialogueKey) -> PathBuf {
+        self.dir.join(format!("{}_{}.json", key.chat_id, key.user_id))
+    }
+}
+
+impl<S: Serialize + DeserializeOwned + Send + 'static> Storage<S> for FileStorage<S> {
+    fn load(&self, key: DialogueKey) -> BoxFuture<'static, Result<Option<Stored<S>>>> {
+        let path = self.path(key);
+        blocking(move || match fs::read(&path) {
+            Ok(data) => serde_json::from_slice(&data).map(Some).map_err(|err| storage_error(&path, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(storage_error(&path, err)),
+        })
+    }
+
+    fn save(&self, key: DialogueKey, stored: Stored<S>) -> BoxFuture<'static, Result<()>> {
+        let path = self.path(key);
+        blocking(move || {
+            // Written next to the target and renamed, so a crash never
+            // leaves half a file behind
+            let tmp = path.with_extension("json.tmp");
+            let data = serde_json::to_vec(&stored).map_err(|err| storage_error(&path, err))?;
+            fs::write(&tmp, data).map_err(|err| storage_error(&tmp, err))?;
+            fs::rename(&tmp, &path).map_err(|err| storage_error(&path, err))
+        })
+    }
+
+    fn remove(&self, key: DialogueKey) -> BoxFuture<'static, Result<()>> {
+        let path = self.path(key);
+        blocking(move || match fs::remove_file(&path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(storage_error(&path, err)),
+            _ => Ok(()),
+        })
+    }
+}
+
+// File I/O blocks, so it is done on a separate thread to keep the
+// executor free
+fn blocking<T, F>(f: F) -> BoxFuture<'static, Result<T>>
+where T: Send + 'static,
+      F: FnOnce() -> Result<T> + Send + 'static {
+    let (tx, rx) = oneshot::channel();
+    thread::spawn(move || {
+        let _ = tx.send(f());
+    });
+    Box::pin(async move {
+        rx.await.unwrap_or_else(|_| Err(Error::Storage("storage thread panicked".to_owned())))
+    })
+}
+
+fn storage_error<E: Display>(path: &Path, err: E) -> Error {
+    Error::Storage(format!("{}: {}", path.display(), err))
+}
+
+/// What a dialogue handler decided to do with the state
+#[derive(Debug, Clone)]
+pub enum Transition<S> {
+    /// Move to another state
+    Next(S),
+    /// Keep the current state
+    Stay,
+    /// End the dialogue; the next message starts from `S::default()`
+    Exit,
+}
+
+/// One step of a dialogue: gets the current state and a message
+pub trait DialogueHandler<S>: Send + Sync + 'static {
+    fn handle(&self, _: Client, _: S, _: UpdateNewMessage) -> BoxFuture<'static, Transition<S>>;
+}
+
+impl<S, C, F> DialogueHandler<S> for C
+where C: Send + Sync + 'static + Fn(Client, S, UpdateNewMessage) -> F,
+      F: Future<Output = Transition<S>> + 'static + Send {
+    fn handle(&self, client: Client, state: S, msg: UpdateNewMessage) -> BoxFuture<'static, Transition<S>> {
+        Box::pin((*self)(client, state, msg))
+    }
+}
+
+// Messages of one dialogue waiting for their turn
+#[derive(Default)]
+struct Queue {
+    pending: Mutex<VecDeque<UpdateNewMessage>>,
+    turn: AsyncMutex<()>,
+}
+
+struct Shared<S, St, H> {
+    storage: St,
+    handler: H,
+    timeout: Option<Duration>,
+    queues: Mutex<HashMap<DialogueKey, Arc<Queue>>>,
+    _state: PhantomData<fn() -> S>,
+}
+
+/// Runs a state machine per `(chat_id, user_id)` over incoming messages.
+///
+/// Register it for `UpdateNewMessage` on a `Dispatcher`. Messages of one
+/// dialogue are handled one at a time in the order they arrived, while
+/// different dialogues run concurrently. Outgoing messages are ignored.
+///
+/// ```ignore
+/// #[derive(Clone, Default, Serialize, Deserialize)]
+/// enum Order { #[default] Start, WaitPhoto, WaitCaption(i32) }
+///
+/// let dialogue = Dialogue::new(InMemoryStorage::new(), |client, state, msg| async move {
+///     match state {
+///         Order::Start => Transition::Next(Order::WaitPhoto),
+///         ...
+///     }
+/// }).timeout(Duration::from_secs(600));
+/// let dispatcher = Dispatcher::new().on(dialogue);
+/// ```
+pub struct Dialogue<S, St, H> {
+    shared: Arc<Shared<S, St, H>>,
+}
+
+impl<S, St, H> Dialogue<S, St, H>
+where S: Clone + Default + Send + 'static,
+      St: Storage<S>,
+      H: DialogueHandler<S> {
+    pub fn new(storage: St, handler: H) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                storage,
+                handler,
+                timeout: None,
+                queues: Mutex::new(HashMap::new()),
+                _state: PhantomData,
+            }),
+        }
+    }
+
+    /// Restarts dialogues idle for longer than `timeout` from `S::default()`
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        if let Some(shared) = Arc::get_mut(&mut self.shared) {
+            shared.timeout = Some(timeout);
+        }
+        self
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|since| since.as_secs())
+        .unwrap_or_default()
+}
+
+impl<S, St, H> Shared<S, St, H>
+where S: Clone + Default + Send + 'static,
+      St: Storage<S>,
+      H: DialogueHandler<S> {
+    async fn step(&self, client: Client, key: DialogueKey, msg: UpdateNewMessage) -> Result<()> {
+        let state = match self.storage.load(key).await? {
+            Some(stored) if self.is_stale(&stored) => {
+                trace!("dialogue {:?} timed out", key);
+                S::default()
+            },
+            Some(stored) => stored.state,
+            None => S::default(),
+        };
+        let state = match self.handler.handle(client, state.clone(), msg).await {
+            Transition::Next(next) => next,
+            // Saved anyway, the dialogue is no longer idle
+            Transition::Stay => state,
+            Transition::Exit => return self.storage.remove(key).await,
+        };
+        self.storage.save(key, Stored { state, updated_at: now() }).await
+    }
+
+    fn is_stale(&self, stored: &Stored<S>) -> bool {
+        self.timeout.is_some_and(|timeout| now().saturating_sub(stored.updated_at) > timeout.as_secs())
+    }
+
+    async fn run_turn(&self, client: Client, key: DialogueKey, queue: Arc<Queue>) {
+        {
+            let _turn = queue.turn.lock().await;
+            // Whoever gets the turn takes the oldest message, keeping order
+            // even if the tasks were scheduled out of order
+            let msg = queue.pending.lock().unwrap().pop_front();
+            if let Some(msg) = msg {
+                if let Err(err) = self.step(client, key, msg).await {
+                    warn!("dialogue {:?} failed: {}", key, err);
+                }
+            }
+        }
+        let mut queues = self.queues.lock().unwrap();
+        if queue.pending.lock().unwrap().is_empty() && Arc::strong_count(&queue) == 2 {
+            queues.remove(&key);
+        }
+    }
+}
+
+impl<S, St, H> TypedHandler<UpdateNewMessage> for Dialogue<S, St, H>
+where S: Clone + Default + Send + 'static,
+      St: Storage<S>,
+      H: DialogueHandler<S> {
+    fn handle(&self, client: Client, upd: UpdateNewMessage) -> BoxFuture<'static, ()> {
+        if upd.message.is_outgoing {
+            return Box::pin(async {});
+        }
+        let key = DialogueKey {
+            chat_id: upd.message.chat_id,
+            user_id: upd.message.sender_user_id,
+        };
+        // Queued right away, as handlers are called in update order
+        let queue = self.shared.queues.lock().unwrap().entry(key).or_default().clone();
+        queue.pending.lock().unwrap().push_back(upd);
+        let shared = self.shared.clone();
+        Box::pin(async move { shared.run_turn(client, key, queue).await })
+    }
+}
@@ -0,0 +1,118 @@
+use std::{ fmt, time::Duration };
+use paperplane_types::types;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// TDLib answered the request with an `error` object
+    Tdlib { code: i32, message: String },
+    /// The response could not be deserialized into the expected type
+    Deserialize(serde_json::Error),
+    /// The client was closed before the response arrived
+    ClientClosed,
+    /// No response arrived within the request timeout
+    Timeout,
+    /// An `auth::Authenticator` could not provide the requested data
+    Auth(String),
+    /// A file transfer stopped or produced unusable data
+    File(String),
+    /// A keyboard or its callback data is invalid
+    Markup(String),
+    /// A dialogue state could not be loaded or saved
+    Storage(String),
+    /// The request could not be handed to TDLib, e.g. as it contains a NUL byte
+    Transport(String),
+    /// The request is not something TDLib could accept
+    InvalidRequest(String),
+    /// TDLib sent a message the client could not make sense of
+    Protocol(String),
+    /// An update handler, the responder or the listener thread panicked
+    Panic(String),
+}
+
+impl Error {
+    pub fn code(&self) -> Option<i32> {
+        match self {
+            Error::Tdlib { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            Error::Tdlib { message, .. } => Some(message),
+            _ => None,
+        }
+    }
+
+    pub fn is_flood_wait(&self) -> bool {
+        match self {
+            Error::Tdlib { code, message } => {
+                *code == 429 || message.starts_with("FLOOD_WAIT_")
+            },
+            _ => false,
+        }
+    }
+
+    /// Delay advised by Telegram, parsed from either
+    /// `Too Many Requests: retry after N` or `FLOOD_WAIT_N`
+    pub fn retry_after(&self) -> Option<Duration> {
+        let message = self.message()?;
+        let tail = if let Some(pos) = message.find("retry after ") {
+            &message[pos + "retry after ".len()..]
+        } else if let Some(pos) = message.find("FLOOD_WAIT_") {
+            &message[pos + "FLOOD_WAIT_".len()..]
+        } else {
+            return None;
+        };
+        let digits: String = tail.chars().take_while(|c| c.is_ascii_digit()).collect();
+        digits.parse().ok().map(Duration::from_secs)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Tdlib { code, message } => write!(f, "tdlib error {}: {}", code, message),
+            Error::Deserialize(err) => write!(f, "could not deserialize response: {}", err),
+            Error::ClientClosed => write!(f, "client is closed"),
+            Error::Timeout => write!(f, "request timed out"),
+            Error::Auth(reason) => write!(f, "authorization failed: {}", reason),
+            Error::File(reason) => write!(f, "file transfer failed: {}", reason),
+            Error::Markup(reason) => write!(f, "invalid reply markup: {}", reason),
+            Error::Storage(reason) => write!(f, "dialogue storage failed: {}", reason),
+            Error::Transport(reason) => write!(f, "could not send request: {}", reason),
+            Error::InvalidRequest(reason) => write!(f, "invalid request: {}", reason),
+            Error::Protocol(reason) => write!(f, "unexpected message from tdlib: {}", reason),
+            Error::Panic(reason) => write!(f, "panic in {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Deserialize(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Deserialize(err)
+    }
+}
+
+impl From<std::ffi::NulError> for Error {
+    fn from(err: std::ffi::NulError) -> Self {
+        Error::Transport(err.to_string())
+    }
+}
+
+impl From<types::Error> for Error {
+    fn from(err: types::Error) -> Self {
+        Error::Tdlib { code: err.code, message: err.message }
+    }
+}
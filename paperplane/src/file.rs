@@ -0,0 +1,423 @@
+use log::warn;
+use std::{
+    future::Future,
+    io,
+    pin::Pin,
+    task::{ Context, Poll },
+};
+use futures::{
+    channel::mpsc,
+    future::{ self, BoxFuture, Either },
+    io::{ AsyncRead, AsyncReadExt },
+    stream::{ Stream, StreamExt },
+};
+use uuid::Uuid;
+use paperplane_types::{ methods, types::{ self, File } };
+use crate::{
+    client::{ Client, RequestFuture },
+    error::{ Error, Result },
+    update::UpdateStream,
+};
+
+/// Largest piece `FileReader` asks TDLib for at once
+pub const READ_CHUNK_SIZE: i32 = 512 * 1024;
+
+const WRITE_CHUNK_SIZE: usize = 512 * 1024;
+const UPLOAD_PRIORITY: i32 = 1;
+
+impl Client {
+    /// Downloads a file with `priority` from 1 to 32; resolves to the
+    /// `File` once the download has completed
+    pub fn download(&self, file_id: i32, priority: i32) -> Result<Download> {
+        // Subscribed first, so progress does not miss the earliest updates
+        let progress = FileProgress::new(self, file_id);
+        let response = self.send(methods::DownloadFile {
+            file_id,
+            priority,
+            offset: 0,
+            limit: 0,
+            synchronous: true,
+        })?;
+        Ok(Download {
+            client: self.clone(),
+            file_id,
+            response,
+            progress: Some(progress),
+        })
+    }
+
+    /// Reads a file while it is being downloaded, waiting for bytes that
+    /// have not arrived yet. The file must be downloading, e.g. through
+    /// `download`, or be downloaded already.
+    pub fn read_file(&self, file_id: i32) -> FileReader {
+        FileReader {
+            state: ReadState::Idle(Reader {
+                client: self.clone(),
+                file_id,
+                offset: 0,
+                updates: self.updates(),
+            }),
+            buffer: Vec::new(),
+            position: 0,
+        }
+    }
+
+    /// Uploads everything `reader` produces as a file called `name`,
+    /// resolving to the `File` once the upload has completed. TDLib asks
+    /// for the contents through a generated file, which is fed from the
+    /// reader while the returned future is polled. Fails with `Error::File`
+    /// if TDLib stops the generation or gives up on the upload.
+    pub fn upload_from_reader<R>(&self, reader: R, file_type: types::FileType, name: &str) -> Upload
+    where R: AsyncRead + Unpin + Send + 'static {
+        let (progress_tx, progress_rx) = mpsc::unbounded();
+        let generated = types::InputFileGenerated {
+            original_path: name.to_owned(),
+            conversion: format!("paperplane-upload-{}", Uuid::new_v4()),
+            expected_size: 0,
+        };
+        let client = self.clone();
+        let updates = self.updates();
+        Upload {
+            future: Box::pin(upload(client, updates, generated, file_type, reader, progress_tx)),
+            progress: Some(progress_rx),
+        }
+    }
+}
+
+async fn upload<R>(
+    client: Client,
+    mut updates: UpdateStream,
+    generated: types::InputFileGenerated,
+    file_type: types::FileType,
+    reader: R,
+    progress: mpsc::UnboundedSender<File>,
+) -> Result<File>
+where R: AsyncRead + Unpin + Send + 'static {
+    let conversion = generated.conversion.clone();
+    let file = client.send(methods::UploadFile {
+        file: types::InputFile::InputFileGenerated(generated),
+        file_type,
+        priority: UPLOAD_PRIORITY,
+    })?.await?;
+    if file.remote.is_uploading_completed {
+        // Uploaded before, so no update is coming
+        let _ = progress.unbounded_send(file.clone());
+        return Ok(file);
+    }
+    // The generation feeding TDLib, run alongside the updates so that
+    // TDLib can stop it while it is written
+    let mut generation: Option<(i64, BoxFuture<'static, Result<()>>)> = None;
+    let mut reader = Some(reader);
+    loop {
+        let update = match generation.as_mut() {
+            Some((_, running)) => match future::select(running, updates.next()).await {
+                Either::Left((result, _)) => {
+                    let (generation_id, _) = generation.take().unwrap();
+                    result?;
+                    client.send(methods::FinishFileGeneration { generation_id, error: None })?.await?;
+                    continue;
+                },
+                Either::Right((update, _)) => update,
+            },
+            None => updates.next().await,
+        };
+        match update {
+            Some(types::Update::UpdateFileGenerationStart(start)) if start.conversion == conversion => {
+                let reader = match reader.take() {
+                    Some(reader) => reader,
+                    None => {
+                        // The reader is used up, there is nothing to generate from
+                        let error = types::Error { code: 400, message: "file can be generated only once".to_owned() };
+                        client.send(methods::FinishFileGeneration { generation_id: start.generation_id, error: Some(error) })?.await?;
+                        return Err(Error::File(format!("file {} was requested again", file.id)));
+                    },
+                };
+                generation = Some((start.generation_id, Box::pin(write_generated(client.clone(), start.generation_id, reader))));
+            },
+            // Stops of finished generations are only TDLib cleaning up
+            Some(types::Update::UpdateFileGenerationStop(stop))
+                if matches!(generation, Some((generation_id, _)) if generation_id == stop.generation_id) => {
+                return Err(Error::File(format!("generation of file {} was stopped", file.id)));
+            },
+            Some(types::Update::UpdateFile(upd)) if upd.file.id == file.id => {
+                let completed = upd.file.remote.is_uploading_completed;
+                let failed = !completed && !upd.file.remote.is_uploading_active;
+                let _ = progress.unbounded_send(upd.file.clone());
+                if completed {
+                    return Ok(upd.file);
+                }
+                if failed {
+                    return Err(Error::File(format!("upload of file {} was interrupted", file.id)));
+                }
+            },
+            Some(_) => (),
+            None => return Err(Error::ClientClosed),
+        }
+    }
+}
+
+// Writes the whole reader into the generated file, leaving it to the
+// caller to finish the generation unless reading fails
+async fn write_generated<R>(client: Client, generation_id: i64, mut reader: R) -> Result<()>
+where R: AsyncRead + Unpin {
+    let mut buffer = vec![0; WRITE_CHUNK_SIZE];
+    let mut offset = 0;
+    loop {
+        let len = match reader.read(&mut buffer).await {
+            Ok(0) => break,
+            Ok(len) => len,
+            Err(err) => {
+                let error = types::Error { code: 400, message: err.to_string() };
+                client.send(methods::FinishFileGeneration { generation_id, error: Some(error) })?.await?;
+                return Err(Error::File(format!("could not read the upload: {}", err)));
+            }
+        };
+        client.send(methods::WriteGeneratedFilePart {
+            generation_id,
+            offset,
+            data: base64::encode(&buffer[..len]),
+        })?.await?;
+        offset += len as i32;
+        client.send(methods::SetFileGenerationProgress {
+            generation_id,
+            expected_size: 0,
+            local_prefix_size: offset,
+        })?.await?;
+    }
+    Ok(())
+}
+
+/// A running upload, resolving to the uploaded `File`
+pub struct Upload {
+    future: BoxFuture<'static, Result<File>>,
+    progress: Option<mpsc::UnboundedReceiver<File>>,
+}
+
+impl Upload {
+    /// File states reported while uploading. Only the first call gets
+    /// them, later ones return a stream that ends right away.
+    pub fn progress(&mut self) -> FileProgress {
+        let rx = self.progress.take().unwrap_or_else(|| mpsc::unbounded().1);
+        FileProgress { source: ProgressSource::Upload(rx) }
+    }
+}
+
+impl Future for Upload {
+    type Output = Result<File>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        self.future.as_mut().poll(cx)
+    }
+}
+
+/// A running download, resolving to the completed `File`
+pub struct Download {
+    client: Client,
+    file_id: i32,
+    response: RequestFuture<methods::DownloadFile>,
+    progress: Option<FileProgress>,
+}
+
+impl Download {
+    pub fn file_id(&self) -> i32 {
+        self.file_id
+    }
+
+    /// File states reported while downloading. The first call sees every
+    /// update since the download was started, later ones only newer updates.
+    pub fn progress(&mut self) -> FileProgress {
+        match self.progress.take() {
+            Some(progress) => progress,
+            None => FileProgress::new(&self.client, self.file_id),
+        }
+    }
+}
+
+impl Future for Download {
+    type Output = Result<File>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let file = match Pin::new(&mut self.response).poll(cx) {
+            Poll::Ready(file) => file?,
+            Poll::Pending => return Poll::Pending,
+        };
+        // Nobody asked for progress, stop buffering updates for it
+        self.progress = None;
+        if file.local.is_downloading_completed {
+            Poll::Ready(Ok(file))
+        } else {
+            Poll::Ready(Err(Error::File(format!("download of file {} was interrupted", file.id))))
+        }
+    }
+}
+
+/// `UpdateFile` states of one file during a transfer. Ends once the
+/// transfer completes or, for downloads, stops after having started.
+/// Downloads start with the state of the file when the stream was made,
+/// and end right after it if the file is downloaded already.
+pub struct FileProgress {
+    source: ProgressSource,
+}
+
+enum ProgressSource {
+    Download {
+        // No updates come for a file that is downloaded already, so the
+        // stream looks at the file itself first
+        initial: Option<RequestFuture<methods::GetFile>>,
+        updates: UpdateStream,
+        file_id: i32,
+        started: bool,
+        done: bool,
+    },
+    // Forwarded by the `Upload` future, which is the only one to know the id
+    Upload(mpsc::UnboundedReceiver<File>),
+}
+
+impl FileProgress {
+    fn new(client: &Client, file_id: i32) -> Self {
+        let updates = client.updates();
+        // Without a client there are no updates either, so the stream ends
+        let initial = client.send(methods::GetFile { file_id }).ok();
+        Self { source: ProgressSource::Download { initial, updates, file_id, started: false, done: false } }
+    }
+}
+
+impl Stream for FileProgress {
+    type Item = File;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<File>> {
+        let (initial, updates, file_id, started, done) = match &mut self.source {
+            ProgressSource::Download { initial, updates, file_id, started, done } => {
+                (initial, updates, *file_id, started, done)
+            },
+            ProgressSource::Upload(rx) => return rx.poll_next_unpin(cx),
+        };
+        if let Some(request) = initial {
+            let file = match Pin::new(request).poll(cx) {
+                Poll::Ready(file) => file,
+                Poll::Pending => return Poll::Pending,
+            };
+            *initial = None;
+            match file {
+                Ok(file) => {
+                    // Not started by this download, so only completion ends it
+                    *done = file.local.is_downloading_completed;
+                    return Poll::Ready(Some(file));
+                },
+                Err(err) => warn!("could not get file {}: {}", file_id, err),
+            }
+        }
+        while !*done {
+            let file = match updates.poll_next_unpin(cx) {
+                Poll::Ready(Some(types::Update::UpdateFile(upd))) if upd.file.id == file_id => upd.file,
+                Poll::Ready(Some(_)) => continue,
+                Poll::Ready(None) => break,
+                Poll::Pending => return Poll::Pending,
+            };
+            let local = &file.local;
+            *started |= local.is_downloading_active;
+            *done = local.is_downloading_completed || (*started && !local.is_downloading_active);
+            return Poll::Ready(Some(file));
+        }
+        Poll::Ready(None)
+    }
+}
+
+// Everything a pending read needs, moved into the read future and back
+struct Reader {
+    client: Client,
+    file_id: i32,
+    offset: i32,
+    updates: UpdateStream,
+}
+
+impl Reader {
+    // Next bytes at `offset`, waiting for them to be downloaded; empty at
+    // the end of the file
+    async fn next_chunk(&mut self) -> Result<Vec<u8>> {
+        loop {
+            let available = self.client.send(methods::GetFileDownloadedPrefixSize {
+                file_id: self.file_id,
+                offset: self.offset,
+            })?.await?.count;
+            if available > 0 {
+                let part = self.client.send(methods::ReadFilePart {
+                    file_id: self.file_id,
+                    offset: self.offset,
+                    count: available.min(READ_CHUNK_SIZE),
+                })?.await?;
+                let data = base64::decode(&part.data)
+                    .map_err(|err| Error::File(format!("invalid file part: {}", err)))?;
+                self.offset += data.len() as i32;
+                return Ok(data);
+            }
+
+            let file = self.client.send(methods::GetFile { file_id: self.file_id })?.await?;
+            if file.local.is_downloading_completed {
+                return Ok(Vec::new());
+            }
+            if !file.local.is_downloading_active {
+                return Err(Error::File(format!("file {} is not being downloaded", self.file_id)));
+            }
+            // Any change of the file may mean more bytes
+            loop {
+                match self.updates.next().await {
+                    Some(types::Update::UpdateFile(upd)) if upd.file.id == self.file_id => break,
+                    Some(_) => (),
+                    None => return Err(Error::ClientClosed),
+                }
+            }
+        }
+    }
+}
+
+enum ReadState {
+    Idle(Reader),
+    Reading(BoxFuture<'static, (Reader, Result<Vec<u8>>)>),
+    Done,
+}
+
+/// `AsyncRead` over a file that may still be downloading
+pub struct FileReader {
+    state: ReadState,
+    buffer: Vec<u8>,
+    position: usize,
+}
+
+impl AsyncRead for FileReader {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+        loop {
+            if this.position < this.buffer.len() {
+                let len = buf.len().min(this.buffer.len() - this.position);
+                buf[..len].copy_from_slice(&this.buffer[this.position..this.position + len]);
+                this.position += len;
+                return Poll::Ready(Ok(len));
+            }
+            match std::mem::replace(&mut this.state, ReadState::Done) {
+                ReadState::Idle(mut reader) => {
+                    this.state = ReadState::Reading(Box::pin(async move {
+                        let chunk = reader.next_chunk().await;
+                        (reader, chunk)
+                    }));
+                },
+                ReadState::Reading(mut fut) => match fut.as_mut().poll(cx) {
+                    Poll::Ready((_, Ok(chunk))) if chunk.is_empty() => return Poll::Ready(Ok(0)),
+                    Poll::Ready((reader, Ok(chunk))) => {
+                        this.state = ReadState::Idle(reader);
+                        this.buffer = chunk;
+                        this.position = 0;
+                    },
+                    Poll::Ready((_, Err(err))) => {
+                        return Poll::Ready(Err(io::Error::other(err)));
+                    },
+                    Poll::Pending => {
+                        this.state = ReadState::Reading(fut);
+                        return Poll::Pending;
+                    },
+                },
+                ReadState::Done => return Poll::Ready(Ok(0)),
+            }
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use std::{ future::Future, sync::Arc };
+use futures::{
+    future,
+    stream::{ self, BoxStream, Stream, StreamExt },
+};
+use paperplane_types::{ methods, types::Message };
+use crate::{ client::Client, error::Result };
+
+/// Largest page TDLib accepts
+pub const MAX_PAGE_SIZE: i32 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// From newer to older messages, the order TDLib returns them in
+    Backward,
+    /// From older to newer messages; needs a starting message
+    Forward,
+}
+
+type StopPredicate = Arc<dyn Fn(&Message) -> bool + Send + Sync>;
+
+/// Where a history or search stream starts, which way it goes and when
+/// it stops.
+#[derive(Clone)]
+pub struct HistoryOptions {
+    from_message_id: i64,
+    direction: Direction,
+    only_local: bool,
+    min_date: Option<i32>,
+    max_date: Option<i32>,
+    page_size: i32,
+    stop: Option<StopPredicate>,
+}
+
+impl HistoryOptions {
+    /// Backwards from the last message, in pages of `MAX_PAGE_SIZE`
+    pub fn new() -> Self {
+        Self {
+            from_message_id: 0,
+            direction: Direction::Backward,
+            only_local: false,
+            min_date: None,
+            max_date: None,
+            page_size: MAX_PAGE_SIZE,
+            stop: None,
+        }
+    }
+
+    /// Starts at this message, which is included; 0 means the last message
+    pub fn from_message(mut self, message_id: i64) -> Self {
+        self.from_message_id = message_id;
+        self
+    }
+
+    /// Walks towards newer messages instead. Starting from 0 yields nothing,
+    /// as there is nothing newer than the last message.
+    pub fn forward(mut self) -> Self {
+        self.direction = Direction::Forward;
+        self
+    }
+
+    /// Only messages TDLib has locally; `chat_history` only
+    pub fn only_local(mut self, only_local: bool) -> Self {
+        self.only_local = only_local;
+        self
+    }
+
+    /// Skips messages sent before `date` (unix time) and ends the stream
+    /// once the history moves past it
+    pub fn min_date(mut self, date: i32) -> Self {
+        self.min_date = Some(date);
+        self
+    }
+
+    /// Skips messages sent after `date` (unix time) and ends the stream
+    /// once the history moves past it
+    pub fn max_date(mut self, date: i32) -> Self {
+        self.max_date = Some(date);
+        self
+    }
+
+    /// Messages requested per call, clamped to `1..=MAX_PAGE_SIZE`
+    pub fn page_size(mut self, page_size: i32) -> Self {
+        self.page_size = page_size.clamp(1, MAX_PAGE_SIZE);
+        self
+    }
+
+    /// Ends the stream at the first message `stop` returns true for,
+    /// without yielding it
+    pub fn stop_when<F>(mut self, stop: F) -> Self
+    where F: Fn(&Message) -> bool + Send + Sync + 'static {
+        self.stop = Some(Arc::new(stop));
+        self
+    }
+
+    // Skips messages before the date range, then yields until the
+    // range or the stop predicate ends it
+    fn bound<S>(&self, messages: S) -> BoxStream<'static, Result<Message>>
+    where S: Stream<Item = Result<Message>> + Send + 'static {
+        let (min_date, max_date) = (self.min_date, self.max_date);
+        let too_old = move |msg: &Message| min_date.is_some_and(|min| msg.date < min);
+        let too_new = move |msg: &Message| max_date.is_some_and(|max| msg.date > max);
+        let forward = self.direction == Direction::Forward;
+        let stop = self.stop.clone();
+        messages
+            .skip_while(move |msg| future::ready(match msg {
+                Ok(msg) if forward => too_old(msg),
+                Ok(msg) => too_new(msg),
+                Err(_) => false,
+            }))
+            .take_while(move |msg| future::ready(match msg {
+                Ok(msg) if forward && too_new(msg) => false,
+                Ok(msg) if !forward && too_old(msg) => false,
+                Ok(msg) => !stop.as_ref().is_some_and(|stop| stop(msg)),
+                Err(_) => true,
+            }))
+            .boxed()
+    }
+}
+
+impl Default for HistoryOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Pages through a message list: `fetch` gets the cursor and returns the
+// next page along with the next cursor, or `None` at the end. The stream
+// ends after the first error.
+fn paginate<C, F, Fut>(cursor: C, mut fetch: F) -> impl Stream<Item = Result<Message>>
+where C: Send + 'static,
+      F: FnMut(C) -> Fut + Send + 'static,
+      Fut: Future<Output = Result<Option<(Vec<Message>, C)>>> + Send {
+    stream::unfold(Some(cursor), move |cursor| {
+        let page = cursor.map(&mut fetch);
+        async move {
+            let result = match page {
+                Some(page) => page.await,
+                None => return None,
+            };
+            Some(match result {
+                Ok(Some((messages, next))) => (messages.into_iter().map(Ok).collect(), Some(next)),
+                Ok(None) => (Vec::new(), None),
+                Err(err) => (vec![Err(err)], None),
+            })
+        }
+    })
+    .flat_map(stream::iter)
+}
+
+// Tracks the last message seen by a `from_message_id`/`offset` scheme
+#[derive(Clone, Copy)]
+struct IdCursor {
+    from_message_id: i64,
+    first: bool,
+}
+
+impl IdCursor {
+    fn new(from_message_id: i64) -> Self {
+        Self { from_message_id, first: true }
+    }
+
+    // Pages include the from message, so they ask for one more, as far as
+    // TDLib allows
+    fn limit(&self, limit: i32) -> i32 {
+        (limit + 1).min(MAX_PAGE_SIZE)
+    }
+
+    // Everything after the from message, which TDLib wants below the limit
+    fn offset(&self, direction: Direction, limit: i32) -> i32 {
+        match direction {
+            Direction::Backward => 0,
+            Direction::Forward => -(self.limit(limit) - 1),
+        }
+    }
+
+    /// Keeps only unseen messages, ordered in `direction`. TDLib may return
+    /// fewer messages than asked for, or repeat the from message, so only
+    /// an empty page means the end.
+    fn advance(self, direction: Direction, messages: Option<Vec<Message>>) -> Option<(Vec<Message>, Self)> {
+        let from = self.from_message_id;
+        let inclusive = self.first && from != 0;
+        let mut messages: Vec<Message> = messages
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|msg| match direction {
+                _ if from == 0 => direction == Direction::Backward,
+                _ if inclusive && msg.id == from => true,
+                Direction::Backward => msg.id < from,
+                Direction::Forward => msg.id > from,
+            })
+            .collect();
+        match direction {
+            Direction::Backward => messages.sort_by_key(|msg| std::cmp::Reverse(msg.id)),
+            Direction::Forward => messages.sort_by_key(|msg| msg.id),
+        }
+        let last = messages.last()?.id;
+        Some((messages, Self { from_message_id: last, first: false }))
+    }
+}
+
+impl Client {
+    /// Messages of a chat, newest first unless `options` say `forward`
+    pub fn chat_history(&self, chat_id: i64, options: HistoryOptions) -> BoxStream<'static, Result<Message>> {
+        let client = self.clone();
+        let (direction, limit, only_local) = (options.direction, options.page_size, options.only_local);
+        let messages = paginate(IdCursor::new(options.from_message_id), move |cursor: IdCursor| {
+            let page = client.send(methods::GetChatHistory {
+                chat_id,
+                from_message_id: cursor.from_message_id,
+                offset: cursor.offset(direction, limit),
+                limit: cursor.limit(limit),
+                only_local,
+            });
+            async move { Ok(cursor.advance(direction, page?.await?.messages)) }
+        });
+        options.bound(messages)
+    }
+
+    /// All results of a `SearchChatMessages` request. Paging starts at
+    /// `options`' message; `from_message_id`, `offset` and `limit` of
+    /// `request` are ignored.
+    pub fn search_chat_messages(
+        &self,
+        request: methods::SearchChatMessages,
+        options: HistoryOptions,
+    ) -> BoxStream<'static, Result<Message>> {
+        let client = self.clone();
+        let (direction, limit) = (options.direction, options.page_size);
+        let messages = paginate(IdCursor::new(options.from_message_id), move |cursor: IdCursor| {
+            let page = client.send(methods::SearchChatMessages {
+                from_message_id: cursor.from_message_id,
+                offset: cursor.offset(direction, limit),
+                limit: cursor.limit(limit),
+                ..request.clone()
+            });
+            async move { Ok(cursor.advance(direction, page?.await?.messages)) }
+        });
+        options.bound(messages)
+    }
+
+    /// All results of a `SearchMessages` request across chats, newest
+    /// first; `forward` and the starting message of `options` do not apply.
+    /// The offsets of `request` give the starting point.
+    pub fn search_messages(
+        &self,
+        request: methods::SearchMessages,
+        options: HistoryOptions,
+    ) -> BoxStream<'static, Result<Message>> {
+        let client = self.clone();
+        let limit = options.page_size;
+        let start = (request.offset_date, request.offset_chat_id, request.offset_message_id);
+        let messages = paginate(start, move |(offset_date, offset_chat_id, offset_message_id)| {
+            let page = client.send(methods::SearchMessages {
+                offset_date,
+                offset_chat_id,
+                offset_message_id,
+                limit,
+                ..request.clone()
+            });
+            async move {
+                let messages = page?.await?.messages.unwrap_or_default();
+                let next = match messages.last() {
+                    Some(last) => (last.date, last.chat_id, last.id),
+                    None => return Ok(None),
+                };
+                // A page ending where the previous one did would loop forever
+                if next == (offset_date, offset_chat_id, offset_message_id) {
+                    return Ok(None);
+                }
+                Ok(Some((messages, next)))
+            }
+        });
+        HistoryOptions { direction: Direction::Backward, ..options }.bound(messages)
+    }
+}
@@ -0,0 +1,296 @@
+use serde::{ Serialize, de::DeserializeOwned };
+use paperplane_types::types::{
+    self,
+    CallbackQueryPayload,
+    InlineKeyboardButton,
+    InlineKeyboardButtonType,
+    KeyboardButton,
+    KeyboardButtonType,
+    ReplyMarkup,
+};
+use crate::error::{ Error, Result };
+
+/// Most bytes Telegram allows in the data of a callback button
+pub const MAX_CALLBACK_DATA: usize = 64;
+
+// Rows of buttons, starting a new row on `row()` or once the current one
+// has `columns` buttons
+#[derive(Debug, Clone)]
+struct Layout<B> {
+    rows: Vec<Vec<B>>,
+    columns: Option<usize>,
+}
+
+impl<B> Layout<B> {
+    fn new() -> Self {
+        Self { rows: vec![Vec::new()], columns: None }
+    }
+
+    fn push(&mut self, button: B) {
+        let full = match (self.rows.last(), self.columns) {
+            (Some(row), Some(columns)) => row.len() >= columns,
+            (Some(_), None) => false,
+            (None, _) => true,
+        };
+        if full {
+            self.rows.push(Vec::new());
+        }
+        if let Some(row) = self.rows.last_mut() {
+            row.push(button);
+        }
+    }
+
+    fn row(&mut self) {
+        if self.rows.last().is_some_and(|row| !row.is_empty()) {
+            self.rows.push(Vec::new());
+        }
+    }
+
+    fn into_rows(mut self) -> Vec<Vec<B>> {
+        self.rows.retain(|row| !row.is_empty());
+        self.rows
+    }
+}
+
+/// Builds a `ReplyMarkupInlineKeyboard`.
+///
+/// ```ignore
+/// let markup = InlineKeyboard::new()
+///     .callback("Yes", b"yes")
+///     .callback("No", b"no")
+///     .row()
+///     .url("Docs", "https://core.telegram.org")
+///     .build()?;
+/// ```
+#[derive(Debug)]
+pub struct InlineKeyboard {
+    layout: Layout<InlineKeyboardButton>,
+    // First invalid button, reported by `build`
+    error: Option<Error>,
+}
+
+impl InlineKeyboard {
+    pub fn new() -> Self {
+        Self { layout: Layout::new(), error: None }
+    }
+
+    /// Wraps to a new row after every `columns` buttons
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.layout.columns = Some(columns.max(1));
+        self
+    }
+
+    /// Starts a new row
+    pub fn row(mut self) -> Self {
+        self.layout.row();
+        self
+    }
+
+    pub fn button(mut self, button: InlineKeyboardButton) -> Self {
+        self.layout.push(button);
+        self
+    }
+
+    /// Button sending `data` back in an `UpdateNewCallbackQuery`, at most
+    /// `MAX_CALLBACK_DATA` bytes
+    pub fn callback<D: AsRef<[u8]>>(self, text: &str, data: D) -> Self {
+        let data = data.as_ref();
+        if data.len() > MAX_CALLBACK_DATA {
+            let error = Error::Markup(format!(
+                "callback data of \"{}\" is {} bytes, at most {} are allowed",
+                text, data.len(), MAX_CALLBACK_DATA,
+            ));
+            return self.fail(error);
+        }
+        self.typed(text, InlineKeyboardButtonType::InlineKeyboardButtonTypeCallback(
+            types::InlineKeyboardButtonTypeCallback { data: base64::encode(data) }
+        ))
+    }
+
+    /// Callback button carrying `data` encoded by `encode_callback`
+    pub fn callback_with<T: Serialize>(self, text: &str, data: &T) -> Self {
+        match encode_callback(data) {
+            Ok(data) => self.callback(text, data),
+            Err(err) => self.fail(err),
+        }
+    }
+
+    pub fn url(self, text: &str, url: &str) -> Self {
+        self.typed(text, InlineKeyboardButtonType::InlineKeyboardButtonTypeUrl(
+            types::InlineKeyboardButtonTypeUrl { url: url.to_owned() }
+        ))
+    }
+
+    /// Button authorizing the user on a website through Telegram Login
+    pub fn login_url(self, text: &str, url: &str, id: i32, forward_text: &str) -> Self {
+        self.typed(text, InlineKeyboardButtonType::InlineKeyboardButtonTypeLoginUrl(
+            types::InlineKeyboardButtonTypeLoginUrl {
+                url: url.to_owned(),
+                id,
+                forward_text: forward_text.to_owned(),
+            }
+        ))
+    }
+
+    /// Button inserting an inline query to the bot, in the current chat or
+    /// one the user picks
+    pub fn switch_inline(self, text: &str, query: &str, in_current_chat: bool) -> Self {
+        self.typed(text, InlineKeyboardButtonType::InlineKeyboardButtonTypeSwitchInline(
+            types::InlineKeyboardButtonTypeSwitchInline { query: query.to_owned(), in_current_chat }
+        ))
+    }
+
+    pub fn build(self) -> Result<ReplyMarkup> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        Ok(ReplyMarkup::ReplyMarkupInlineKeyboard(types::ReplyMarkupInlineKeyboard {
+            rows: self.layout.into_rows(),
+        }))
+    }
+
+    fn typed(self, text: &str, type_: InlineKeyboardButtonType) -> Self {
+        self.button(InlineKeyboardButton { text: text.to_owned(), type_ })
+    }
+
+    fn fail(mut self, error: Error) -> Self {
+        self.error.get_or_insert(error);
+        self
+    }
+}
+
+impl Default for InlineKeyboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builds a `ReplyMarkupShowKeyboard`, the keyboard replacing the
+/// user's keyboard
+#[derive(Debug, Clone)]
+pub struct ReplyKeyboard {
+    layout: Layout<KeyboardButton>,
+    resize_keyboard: bool,
+    one_time: bool,
+    is_personal: bool,
+}
+
+impl ReplyKeyboard {
+    pub fn new() -> Self {
+        Self {
+            layout: Layout::new(),
+            resize_keyboard: false,
+            one_time: false,
+            is_personal: false,
+        }
+    }
+
+    /// Wraps to a new row after every `columns` buttons
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.layout.columns = Some(columns.max(1));
+        self
+    }
+
+    /// Starts a new row
+    pub fn row(mut self) -> Self {
+        self.layout.row();
+        self
+    }
+
+    pub fn button(mut self, button: KeyboardButton) -> Self {
+        self.layout.push(button);
+        self
+    }
+
+    /// Button sending its text as a message
+    pub fn text(self, text: &str) -> Self {
+        self.typed(text, KeyboardButtonType::KeyboardButtonTypeText(types::KeyboardButtonTypeText {}))
+    }
+
+    pub fn request_phone_number(self, text: &str) -> Self {
+        self.typed(text, KeyboardButtonType::KeyboardButtonTypeRequestPhoneNumber(
+            types::KeyboardButtonTypeRequestPhoneNumber {}
+        ))
+    }
+
+    pub fn request_location(self, text: &str) -> Self {
+        self.typed(text, KeyboardButtonType::KeyboardButtonTypeRequestLocation(
+            types::KeyboardButtonTypeRequestLocation {}
+        ))
+    }
+
+    /// Button asking the user to create a poll; private chats only
+    pub fn request_poll(self, text: &str, force_regular: bool, force_quiz: bool) -> Self {
+        self.typed(text, KeyboardButtonType::KeyboardButtonTypeRequestPoll(
+            types::KeyboardButtonTypeRequestPoll { force_regular, force_quiz }
+        ))
+    }
+
+    /// Lets clients shrink the keyboard to fit its buttons
+    pub fn resize(mut self) -> Self {
+        self.resize_keyboard = true;
+        self
+    }
+
+    /// Hides the keyboard once a button is used
+    pub fn one_time(mut self) -> Self {
+        self.one_time = true;
+        self
+    }
+
+    /// Shows the keyboard only to mentioned users and the replied-to sender
+    pub fn personal(mut self) -> Self {
+        self.is_personal = true;
+        self
+    }
+
+    pub fn build(self) -> ReplyMarkup {
+        ReplyMarkup::ReplyMarkupShowKeyboard(types::ReplyMarkupShowKeyboard {
+            rows: self.layout.into_rows(),
+            resize_keyboard: self.resize_keyboard,
+            one_time: self.one_time,
+            is_personal: self.is_personal,
+        })
+    }
+
+    fn typed(self, text: &str, type_: KeyboardButtonType) -> Self {
+        self.button(KeyboardButton { text: text.to_owned(), type_ })
+    }
+}
+
+impl Default for ReplyKeyboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Makes clients show a reply interface to the user
+pub fn force_reply(is_personal: bool) -> ReplyMarkup {
+    ReplyMarkup::ReplyMarkupForceReply(types::ReplyMarkupForceReply { is_personal })
+}
+
+/// Removes the keyboard set by a `ReplyKeyboard`
+pub fn remove_keyboard(is_personal: bool) -> ReplyMarkup {
+    ReplyMarkup::ReplyMarkupRemoveKeyboard(types::ReplyMarkupRemoveKeyboard { is_personal })
+}
+
+/// Compact JSON of `data`, for `InlineKeyboard::callback_with`
+pub fn encode_callback<T: Serialize>(data: &T) -> Result<Vec<u8>> {
+    serde_json::to_vec(data).map_err(|err| Error::Markup(format!("could not encode callback data: {}", err)))
+}
+
+/// Raw data of a callback query, `None` for game callbacks
+pub fn callback_data(query: &types::UpdateNewCallbackQuery) -> Option<Vec<u8>> {
+    match &query.payload {
+        CallbackQueryPayload::CallbackQueryPayloadData(payload) => base64::decode(&payload.data).ok(),
+        _ => None,
+    }
+}
+
+/// Data of a callback query from a button made by
+/// `InlineKeyboard::callback_with`
+pub fn decode_callback<T: DeserializeOwned>(query: &types::UpdateNewCallbackQuery) -> Result<T> {
+    let data = callback_data(query)
+        .ok_or_else(|| Error::Markup("callback query carries no data".to_owned()))?;
+    Ok(serde_json::from_slice(&data)?)
+}
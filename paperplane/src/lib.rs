@@ -1,3 +1,23 @@
+pub mod error;
 pub mod update;
 pub mod client;
+pub mod spawn;
+pub mod blocking;
+#[cfg(feature = "tdjson")]
 pub mod bindings;
+pub mod transport;
+pub mod testing;
+pub mod auth;
+pub mod cache;
+pub mod chat_list;
+pub mod history;
+pub mod file;
+pub mod text;
+pub mod keyboard;
+pub mod limit;
+pub mod bot;
+pub mod dialogue;
+#[cfg(feature = "multi-client")]
+pub mod manager;
+
+pub use error::{ Error, Result };
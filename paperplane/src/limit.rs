@@ -0,0 +1,355 @@
+use std::{
+    collections::{ HashMap, HashSet },
+    time::{ Duration, Instant },
+};
+use serde_json::Value as JsonValue;
+
+/// Methods sending messages, which Telegram's bot limits are about
+pub const SENDING_METHODS: &[&str] = &[
+    "sendMessage",
+    "sendMessageAlbum",
+    "sendInlineQueryResultMessage",
+    "forwardMessages",
+    "resendMessages",
+];
+
+// Past this many buckets, full ones are forgotten as they equal new ones
+const BUCKET_CACHE_SIZE: usize = 1024;
+
+/// `requests` per `per`, allowing bursts of up to `requests`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rate {
+    pub requests: u32,
+    pub per: Duration,
+}
+
+impl Rate {
+    pub fn new(requests: u32, per: Duration) -> Self {
+        Self { requests: requests.max(1), per }
+    }
+
+    pub fn per_second(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(1))
+    }
+
+    pub fn per_minute(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(60))
+    }
+}
+
+/// Limits the responder applies before handing requests to TDLib.
+/// Requests over a limit are held back in order, still subject to their
+/// timeout, and sent as soon as every bucket they need has room.
+///
+/// The global, per-chat and per-group limits cover `limited` methods,
+/// by default `SENDING_METHODS`; per-method limits cover their method.
+///
+/// ```ignore
+/// client.set_rate_limits(RateLimits::bot().method("getChatMember", Rate::per_second(10)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimits {
+    global: Option<Rate>,
+    per_chat: Option<Rate>,
+    per_group: Option<Rate>,
+    methods: HashMap<String, Rate>,
+    limited: HashSet<String>,
+    retry_flood_wait: bool,
+    max_retry_delay: Duration,
+}
+
+impl RateLimits {
+    /// No limits and no retries, as a client starts
+    pub fn new() -> Self {
+        Self {
+            global: None,
+            per_chat: None,
+            per_group: None,
+            methods: HashMap::new(),
+            limited: SENDING_METHODS.iter().map(|method| (*method).to_owned()).collect(),
+            retry_flood_wait: false,
+            max_retry_delay: Duration::from_secs(60),
+        }
+    }
+
+    /// Telegram's documented bot limits: 30 messages a second overall,
+    /// one a second per chat and 20 a minute per group, retrying 429s
+    pub fn bot() -> Self {
+        Self::new()
+            .global(Rate::per_second(30))
+            .per_chat(Rate::per_second(1))
+            .per_group(Rate::per_minute(20))
+            .retry_flood_wait(true)
+    }
+
+    pub fn global(mut self, rate: Rate) -> Self {
+        self.global = Some(rate);
+        self
+    }
+
+    /// Limit for each chat, by the request's `chat_id`
+    pub fn per_chat(mut self, rate: Rate) -> Self {
+        self.per_chat = Some(rate);
+        self
+    }
+
+    /// Extra limit for groups and channels, whose chat ids are negative
+    pub fn per_group(mut self, rate: Rate) -> Self {
+        self.per_group = Some(rate);
+        self
+    }
+
+    /// Limit for every request of `method`, e.g. `"getChatMember"`
+    pub fn method(mut self, method: &str, rate: Rate) -> Self {
+        self.methods.insert(method.to_owned(), rate);
+        self
+    }
+
+    /// Methods the global, per-chat and per-group limits apply to
+    pub fn limited<I, S>(mut self, methods: I) -> Self
+    where I: IntoIterator<Item = S>,
+          S: Into<String> {
+        self.limited = methods.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Resends requests failing with 429 after the delay Telegram asks for
+    pub fn retry_flood_wait(mut self, retry: bool) -> Self {
+        self.retry_flood_wait = retry;
+        self
+    }
+
+    /// Longest advised delay still retried; longer waits fail the request
+    pub fn max_retry_delay(mut self, delay: Duration) -> Self {
+        self.max_retry_delay = delay;
+        self
+    }
+
+    fn is_unlimited(&self) -> bool {
+        self.global.is_none() && self.per_chat.is_none() && self.per_group.is_none() && self.methods.is_empty()
+    }
+}
+
+impl Default for RateLimits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How much the rate limiter held requests back, see `Client::rate_limit_stats`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LimitStats {
+    /// Requests sent later than asked for
+    pub held: u64,
+    /// Total time those requests waited
+    pub held_for: Duration,
+    /// Longest single wait
+    pub max_held_for: Duration,
+    /// Requests resent after a 429
+    pub retried: u64,
+}
+
+impl LimitStats {
+    pub(crate) fn record_hold(&mut self, held_for: Duration) {
+        self.held += 1;
+        self.held_for += held_for;
+        self.max_held_for = self.max_held_for.max(held_for);
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    capacity: f64,
+    // Tokens per second
+    refill: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(rate: Rate, now: Instant) -> Self {
+        let capacity = f64::from(rate.requests);
+        Self {
+            tokens: capacity,
+            capacity,
+            refill: capacity / rate.per.as_secs_f64().max(f64::EPSILON),
+            updated: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill).min(self.capacity);
+        self.updated = now;
+    }
+
+    // Time until a token is available, zero if one is
+    fn wait(&mut self, now: Instant) -> Duration {
+        self.refill(now);
+        if self.tokens >= 1.0 {
+            Duration::from_secs(0)
+        } else {
+            Duration::from_secs_f64((1.0 - self.tokens) / self.refill)
+        }
+    }
+
+    fn is_full(&mut self, now: Instant) -> bool {
+        self.refill(now);
+        self.tokens >= self.capacity
+    }
+}
+
+fn bucket<K>(buckets: &mut HashMap<K, Bucket>, key: K, rate: Rate, now: Instant) -> &mut Bucket
+where K: std::hash::Hash + Eq {
+    if buckets.len() >= BUCKET_CACHE_SIZE {
+        buckets.retain(|_, bucket| !bucket.is_full(now));
+    }
+    buckets.entry(key).or_insert_with(|| Bucket::new(rate, now))
+}
+
+/// Token buckets for `RateLimits`, owned by the responder thread
+#[derive(Debug, Default)]
+pub(crate) struct Limiter {
+    limits: RateLimits,
+    global: Option<Bucket>,
+    chats: HashMap<i64, Bucket>,
+    groups: HashMap<i64, Bucket>,
+    methods: HashMap<String, Bucket>,
+}
+
+impl Limiter {
+    pub(crate) fn set_limits(&mut self, limits: RateLimits) {
+        *self = Self { limits, ..Self::default() };
+    }
+
+    pub(crate) fn is_unlimited(&self) -> bool {
+        self.limits.is_unlimited()
+    }
+
+    /// Delay to retry a flood-waited request after, if it should be retried
+    pub(crate) fn retry_delay(&self, advised: Duration) -> Option<Duration> {
+        if self.limits.retry_flood_wait && advised <= self.limits.max_retry_delay {
+            Some(advised)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn retries(&self) -> bool {
+        self.limits.retry_flood_wait
+    }
+
+    /// Takes a token from every bucket `request` needs, or tells how long
+    /// to wait for all of them to have one
+    pub(crate) fn admit(&mut self, request: &JsonValue, now: Instant) -> Result<(), Duration> {
+        let Self { limits, global, chats, groups, methods } = self;
+        let method = request["@type"].as_str().unwrap_or_default();
+        let limited = limits.limited.contains(method);
+        let chat_id = request["chat_id"]
+            .as_i64()
+            .or_else(|| request["chat_id"].as_str()?.parse().ok())
+            .filter(|_| limited);
+
+        let mut buckets = Vec::new();
+        if let Some(rate) = limits.methods.get(method) {
+            buckets.push(bucket(methods, method.to_owned(), *rate, now));
+        }
+        if let (true, Some(rate)) = (limited, limits.global) {
+            buckets.push(global.get_or_insert_with(|| Bucket::new(rate, now)));
+        }
+        if let (Some(chat_id), Some(rate)) = (chat_id, limits.per_chat) {
+            buckets.push(bucket(chats, chat_id, rate, now));
+        }
+        if let (Some(chat_id), Some(rate)) = (chat_id.filter(|chat_id| *chat_id < 0), limits.per_group) {
+            buckets.push(bucket(groups, chat_id, rate, now));
+        }
+
+        let wait = buckets.iter_mut().map(|bucket| bucket.wait(now)).max().unwrap_or_default();
+        if wait > Duration::from_secs(0) {
+            return Err(wait);
+        }
+        for bucket in buckets {
+            bucket.tokens -= 1.0;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    fn limiter(limits: RateLimits) -> Limiter {
+        let mut limiter = Limiter::default();
+        limiter.set_limits(limits);
+        limiter
+    }
+
+    fn send_message(chat_id: i64) -> JsonValue {
+        json!({ "@type": "sendMessage", "chat_id": chat_id })
+    }
+
+    #[test]
+    fn admits_bursts_then_waits_for_a_refill() {
+        let mut limiter = limiter(RateLimits::new().per_chat(Rate::new(2, Duration::from_secs(1))));
+        let now = Instant::now();
+        assert_eq!(limiter.admit(&send_message(5), now), Ok(()));
+        assert_eq!(limiter.admit(&send_message(5), now), Ok(()));
+        assert_eq!(limiter.admit(&send_message(5), now), Err(Duration::from_millis(500)));
+        // Other chats have buckets of their own
+        assert_eq!(limiter.admit(&send_message(6), now), Ok(()));
+
+        let later = now + Duration::from_millis(500);
+        assert_eq!(limiter.admit(&send_message(5), later), Ok(()));
+        assert!(limiter.admit(&send_message(5), later).is_err());
+    }
+
+    #[test]
+    fn waits_for_every_bucket() {
+        let limits = RateLimits::new()
+            .global(Rate::new(10, Duration::from_secs(1)))
+            .per_chat(Rate::new(10, Duration::from_secs(1)))
+            .per_group(Rate::new(1, Duration::from_secs(2)));
+        let mut limiter = limiter(limits);
+        let now = Instant::now();
+        assert_eq!(limiter.admit(&send_message(-100), now), Ok(()));
+        assert_eq!(limiter.admit(&send_message(-100), now), Err(Duration::from_secs(2)));
+        // A refused request takes no tokens, so the other buckets still
+        // have 9 left
+        for _ in 0..9 {
+            assert_eq!(limiter.admit(&send_message(7), now), Ok(()));
+        }
+        assert_eq!(limiter.admit(&send_message(8), now), Err(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn limits_only_the_limited_methods() {
+        let limits = RateLimits::new()
+            .global(Rate::new(1, Duration::from_secs(1)))
+            .method("getChatMember", Rate::new(1, Duration::from_secs(1)));
+        let mut limiter = limiter(limits);
+        let now = Instant::now();
+        let get_me = json!({ "@type": "getMe" });
+        let get_chat_member = json!({ "@type": "getChatMember", "chat_id": 5 });
+        for _ in 0..3 {
+            assert_eq!(limiter.admit(&get_me, now), Ok(()));
+        }
+        assert_eq!(limiter.admit(&get_chat_member, now), Ok(()));
+        assert!(limiter.admit(&get_chat_member, now).is_err());
+        assert_eq!(limiter.admit(&send_message(5), now), Ok(()));
+        assert!(limiter.admit(&send_message(6), now).is_err());
+    }
+
+    #[test]
+    fn retries_flood_waits_up_to_the_limit() {
+        let never = limiter(RateLimits::new());
+        assert_eq!(never.retry_delay(Duration::from_secs(1)), None);
+
+        let retrying = limiter(RateLimits::new()
+            .retry_flood_wait(true)
+            .max_retry_delay(Duration::from_secs(10)));
+        assert_eq!(retrying.retry_delay(Duration::from_secs(3)), Some(Duration::from_secs(3)));
+        assert_eq!(retrying.retry_delay(Duration::from_secs(11)), None);
+    }
+}
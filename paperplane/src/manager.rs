@@ -0,0 +1,141 @@
+use log::{ info, trace, warn };
+use std::{
+    collections::HashMap,
+    sync::{ Arc, Mutex, atomic::{ AtomicBool, Ordering } },
+    thread,
+};
+use crossbeam::channel::Sender;
+use serde_json::Value as JsonValue;
+use paperplane_types::methods::{ self, Method };
+use crate::{
+    bindings::TdClientId,
+    client::{ Client, JoinStreams },
+    error::Result,
+    spawn,
+    update::Handler,
+};
+
+struct Route {
+    td: TdClientId,
+    tx: Sender<JoinStreams>,
+}
+
+#[derive(Default)]
+struct Shared {
+    routes: Mutex<HashMap<i32, Route>>,
+    closing: AtomicBool,
+}
+
+/// Runs many `Client`s on a single TDLib receive loop.
+///
+/// Every account added gets its own handler and responder thread, while
+/// one thread receives for all of them and routes by `@client_id`. The
+/// database directory of an account is whatever its handler passes in
+/// `TdlibParameters`, e.g. through `auth::Authorization`.
+pub struct ClientManager {
+    shared: Arc<Shared>,
+}
+
+impl ClientManager {
+    pub fn new() -> Self {
+        let shared = Arc::new(Shared::default());
+        let shared_for_loop = shared.clone();
+        thread::spawn(move || receive_loop(&shared_for_loop));
+        Self { shared }
+    }
+
+    /// Starts a new account, its handler receives the authorization updates
+    pub fn add<H: Handler>(&self, updater: H) -> Client {
+        let td = TdClientId::new();
+        let (client, tx) = Client::start(Arc::new(td), updater, spawn::default_spawner(), false);
+        self.shared.routes.lock().unwrap().insert(td.id(), Route { td, tx });
+        // TDLib only starts an instance once it gets the first request
+        if let Err(err) = client.send_detached(methods::GetOption { name: "version".to_owned() }) {
+            warn!("could not start client {}: {}", td.id(), err);
+        }
+        client
+    }
+
+    /// Closes the account and stops routing to it
+    pub async fn remove(&self, client: &Client) -> Result<()> {
+        let result = client.close().await;
+        self.shared.routes.lock().unwrap()
+            .retain(|_, route| !route.tx.same_channel(client.sender()));
+        result
+    }
+
+    /// Number of accounts not closed yet
+    pub fn len(&self) -> usize {
+        self.shared.routes.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for ClientManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for ClientManager {
+    // Closes the remaining accounts; the receive loop keeps running until
+    // all of them report authorizationStateClosed
+    fn drop(&mut self) {
+        self.shared.closing.store(true, Ordering::Release);
+        let request = match serde_json::to_string(&methods::Close {}.tag()) {
+            Ok(request) => request,
+            Err(_) => return,
+        };
+        for route in self.shared.routes.lock().unwrap().values() {
+            let _ = route.td.send(&request);
+        }
+    }
+}
+
+fn receive_loop(shared: &Shared) {
+    loop {
+        if shared.closing.load(Ordering::Acquire) && shared.routes.lock().unwrap().is_empty() {
+            info!("all clients closed, stopping receive loop");
+            break;
+        }
+        let msg = match TdClientId::receive(1.0) {
+            Some(msg) => msg,
+            None => continue,
+        };
+        let val: JsonValue = match serde_json::from_str(&msg) {
+            Ok(val) => val,
+            Err(e) => {
+                warn!("ignoring invalid response. err: {}, resp: {}", e, msg);
+                continue;
+            }
+        };
+        let client_id = match val["@client_id"].as_i64() {
+            Some(id) => id as i32,
+            None => {
+                trace!("ignoring response without @client_id: {}", val);
+                continue;
+            }
+        };
+        let closed = is_closed(&val);
+
+        let mut routes = shared.routes.lock().unwrap();
+        let delivered = match routes.get(&client_id) {
+            Some(route) => route.tx.send(JoinStreams::NewResponse(val)).is_ok(),
+            None => {
+                trace!("ignoring response for unknown client {}", client_id);
+                continue;
+            }
+        };
+        if closed || !delivered {
+            routes.remove(&client_id);
+        }
+    }
+}
+
+fn is_closed(val: &JsonValue) -> bool {
+    val["@type"] == "updateAuthorizationState"
+        && val["authorization_state"]["@type"] == "authorizationStateClosed"
+}
@@ -0,0 +1,96 @@
+use std::sync::{ Arc, OnceLock };
+use futures::{ executor::ThreadPool, future::BoxFuture };
+
+/// Runs the futures returned by update handlers.
+///
+/// Any `Fn(BoxFuture<'static, ()>)` is a spawner, so executors without
+/// built-in support can be plugged in with a closure.
+pub trait Spawner: Send + Sync + 'static {
+    fn spawn(&self, _: BoxFuture<'static, ()>);
+}
+
+impl<F> Spawner for F
+where F: Fn(BoxFuture<'static, ()>) + Send + Sync + 'static {
+    fn spawn(&self, fut: BoxFuture<'static, ()>) {
+        (*self)(fut)
+    }
+}
+
+/// `futures` thread pool, used when no other executor is available
+#[derive(Clone)]
+pub struct ThreadPoolSpawner(pub ThreadPool);
+
+impl ThreadPoolSpawner {
+    /// Pool shared by every client, started on first use
+    pub fn shared() -> Self {
+        static POOL: OnceLock<ThreadPool> = OnceLock::new();
+        let pool = POOL.get_or_init(|| {
+            ThreadPool::builder()
+                .name_prefix("paperplane-handler-")
+                .create()
+                .expect("could not start the handler thread pool")
+        });
+        ThreadPoolSpawner(pool.clone())
+    }
+}
+
+impl Spawner for ThreadPoolSpawner {
+    fn spawn(&self, fut: BoxFuture<'static, ()>) {
+        self.0.spawn_ok(fut);
+    }
+}
+
+/// Spawns on a tokio 1.x runtime
+#[cfg(feature = "tokio")]
+#[derive(Clone)]
+pub struct TokioSpawner(pub tokio::runtime::Handle);
+
+#[cfg(feature = "tokio")]
+impl TokioSpawner {
+    /// The runtime the caller runs on, if any
+    pub fn current() -> Option<Self> {
+        tokio::runtime::Handle::try_current().ok().map(TokioSpawner)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Spawner for TokioSpawner {
+    fn spawn(&self, fut: BoxFuture<'static, ()>) {
+        self.0.spawn(fut);
+    }
+}
+
+/// Spawns on the global async-std executor
+#[cfg(feature = "async-std")]
+#[derive(Clone, Copy, Default)]
+pub struct AsyncStdSpawner;
+
+#[cfg(feature = "async-std")]
+impl Spawner for AsyncStdSpawner {
+    fn spawn(&self, fut: BoxFuture<'static, ()>) {
+        async_std::task::spawn(fut);
+    }
+}
+
+/// Spawner used by clients created without one: the current tokio runtime
+/// with the `tokio` feature, else async-std with the `async-std` feature,
+/// else `ThreadPoolSpawner::shared()`
+pub fn default_spawner() -> Arc<dyn Spawner> {
+    #[cfg(feature = "tokio")]
+    {
+        if let Some(spawner) = TokioSpawner::current() {
+            return Arc::new(spawner);
+        }
+    }
+    fallback_spawner()
+}
+
+#[cfg(feature = "async-std")]
+fn fallback_spawner() -> Arc<dyn Spawner> {
+    Arc::new(AsyncStdSpawner)
+}
+
+#[cfg(not(feature = "async-std"))]
+fn fallback_spawner() -> Arc<dyn Spawner> {
+    Arc::new(ThreadPoolSpawner::shared())
+}
@@ -0,0 +1,183 @@
+use std::{
+    collections::{ HashMap, HashSet, VecDeque },
+    sync::{ Arc, Mutex, Condvar },
+    time::Duration,
+};
+use serde::{ Serialize, de::DeserializeOwned };
+use serde_json::{ json, Value as JsonValue };
+use paperplane_types::{ methods::Method, types };
+use crate::{
+    error::{ Error, Result },
+    transport::Transport,
+};
+
+type Responder = Arc<dyn Fn(&JsonValue) -> JsonValue + Send + Sync + 'static>;
+
+#[derive(Default)]
+struct MockState {
+    responders: HashMap<String, Responder>,
+    ignored: HashSet<String>,
+    sent: Vec<JsonValue>,
+    outbox: VecDeque<String>,
+}
+
+#[derive(Default)]
+struct MockInner {
+    state: Mutex<MockState>,
+    incoming: Condvar,
+}
+
+/// In-memory stand-in for libtdjson.
+///
+/// Responses are scripted per method `TYPE`; requests without a script get
+/// a 400 error so tests fail instead of hanging. `close` is answered like
+/// TDLib does, with `ok` followed by `authorizationStateClosed`. Clones
+/// share state, so keep one to inspect after handing another to
+/// `Client::with_transport`.
+#[derive(Clone, Default)]
+pub struct MockTdlib {
+    inner: Arc<MockInner>,
+}
+
+impl MockTdlib {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answers every `M` with the value returned by `f`, tagged with
+    /// `M::RESPONSE_TYPE` if it serializes without `@type`
+    pub fn respond<M, R, F>(&self, f: F) -> &Self
+    where M: Method + DeserializeOwned,
+          R: Serialize,
+          F: Fn(M) -> R + Send + Sync + 'static {
+        self.respond_json(M::TYPE, move |req| {
+            let method = match serde_json::from_value::<M>(req.clone()) {
+                Ok(method) => method,
+                Err(err) => return error_json(400, &format!("mock could not parse request: {}", err)),
+            };
+            let mut resp = match serde_json::to_value(f(method)) {
+                Ok(resp) => resp,
+                Err(err) => return error_json(500, &format!("mock response is not serializable: {}", err)),
+            };
+            if let (Some(fields), Some(typ)) = (resp.as_object_mut(), M::RESPONSE_TYPE) {
+                fields.entry("@type").or_insert_with(|| typ.into());
+            }
+            // The client would drop it and leave the request hanging
+            if resp["@type"].is_null() {
+                return error_json(500, "mock response has no @type");
+            }
+            resp
+        })
+    }
+
+    pub fn respond_error<M: Method>(&self, code: i32, message: &str) -> &Self {
+        let message = message.to_owned();
+        self.respond_json(M::TYPE, move |_| error_json(code, &message))
+    }
+
+    /// Answers requests of type `method_type` with raw JSON built from the request
+    pub fn respond_json<F>(&self, method_type: &str, f: F) -> &Self
+    where F: Fn(&JsonValue) -> JsonValue + Send + Sync + 'static {
+        self.inner.state.lock().unwrap()
+            .responders
+            .insert(method_type.to_owned(), Arc::new(f));
+        self
+    }
+
+    /// Never answers `M`, leaving requests to time out or be cancelled
+    pub fn ignore<M: Method>(&self) -> &Self {
+        self.inner.state.lock().unwrap().ignored.insert(M::TYPE.to_owned());
+        self
+    }
+
+    /// Delivers an update to the client as if TDLib sent it
+    pub fn push_update(&self, upd: types::Update) {
+        match serde_json::to_value(upd) {
+            Ok(val) => self.push_json(val),
+            Err(err) => panic!("update is not serializable: {}", err),
+        }
+    }
+
+    pub fn push_json(&self, val: JsonValue) {
+        self.inner.state.lock().unwrap().outbox.push_back(val.to_string());
+        self.inner.incoming.notify_all();
+    }
+
+    /// Every request sent so far, including `@extra`
+    pub fn sent(&self) -> Vec<JsonValue> {
+        self.inner.state.lock().unwrap().sent.clone()
+    }
+
+    /// Requests of type `M` sent so far
+    pub fn sent_of<M: Method + DeserializeOwned>(&self) -> Vec<M> {
+        self.sent()
+            .into_iter()
+            .filter(|req| req["@type"] == M::TYPE)
+            .filter_map(|req| serde_json::from_value(req).ok())
+            .collect()
+    }
+
+    fn answer(&self, req: &JsonValue) -> JsonValue {
+        let typ = req["@type"].as_str().unwrap_or_default();
+        // Cloned out so responders may call back into the mock
+        let responder = self.inner.state.lock().unwrap().responders.get(typ).cloned();
+        match responder {
+            Some(responder) => responder(req),
+            None if typ == "close" => json!({ "@type": "ok" }),
+            None => error_json(400, &format!("no mock response for {}", typ)),
+        }
+    }
+}
+
+fn error_json(code: i32, message: &str) -> JsonValue {
+    json!({ "@type": "error", "code": code, "message": message })
+}
+
+impl Transport for MockTdlib {
+    fn send(&self, request: &str) -> Result<()> {
+        // Refused like libtdjson, which takes C strings
+        if request.contains('\0') {
+            return Err(Error::Transport("request contains a NUL byte".to_owned()));
+        }
+        let req: JsonValue = serde_json::from_str(request)
+            .map_err(|err| Error::Transport(format!("invalid request: {}", err)))?;
+        {
+            let mut state = self.inner.state.lock().unwrap();
+            state.sent.push(req.clone());
+            if req["@type"].as_str().is_some_and(|typ| state.ignored.contains(typ)) {
+                return Ok(());
+            }
+        }
+
+        let mut resp = self.answer(&req);
+        resp["@extra"] = req["@extra"].clone();
+        self.push_json(resp);
+        if req["@type"] == "close" {
+            self.push_json(json!({
+                "@type": "updateAuthorizationState",
+                "authorization_state": { "@type": "authorizationStateClosed" },
+            }));
+        }
+        Ok(())
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> {
+        let state = self.inner.state.lock().unwrap();
+        let (mut state, _) = self.inner.incoming
+            .wait_timeout_while(state, Duration::from_secs_f64(timeout), |state| state.outbox.is_empty())
+            .unwrap();
+        state.outbox.pop_front()
+    }
+
+    fn execute(&self, request: &str) -> Result<Option<String>> {
+        let req: JsonValue = match serde_json::from_str(request) {
+            Ok(req) => req,
+            Err(_) => return Ok(None),
+        };
+        let mut resp = self.answer(&req);
+        if !req["@extra"].is_null() {
+            resp["@extra"] = req["@extra"].clone();
+        }
+        Ok(Some(resp.to_string()))
+    }
+}
@@ -0,0 +1,241 @@
+use std::ops::Range;
+use paperplane_types::{
+    methods,
+    types::{ self, FormattedText, TextEntity, TextEntityType },
+};
+
+/// Longest text Telegram accepts in a single message, in UTF-16 code units
+pub const MAX_MESSAGE_LENGTH: i32 = 4096;
+
+/// Length of `text` in UTF-16 code units, the unit of entity offsets
+pub fn utf16_len(text: &str) -> i32 {
+    text.chars().map(char::len_utf16).sum::<usize>() as i32
+}
+
+// Byte index of the UTF-16 `offset`, if it lies on a char boundary
+pub(crate) fn byte_offset(text: &str, offset: i32) -> Option<usize> {
+    let mut length = 0;
+    for (index, ch) in text.char_indices() {
+        if length == offset {
+            return Some(index);
+        }
+        length += ch.len_utf16() as i32;
+    }
+    if length == offset { Some(text.len()) } else { None }
+}
+
+/// Builds a `FormattedText` from fragments, keeping entity offsets in
+/// UTF-16 code units as TDLib expects.
+///
+/// ```ignore
+/// let text = FormattedTextBuilder::new()
+///     .bold("Hello")
+///     .text(", ")
+///     .mention("friend", user_id)
+///     .text(" 👋")
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FormattedTextBuilder {
+    text: String,
+    length: i32,
+    entities: Vec<TextEntity>,
+}
+
+impl FormattedTextBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Plain text
+    pub fn text(self, text: &str) -> Self {
+        self.push(text, None)
+    }
+
+    /// Text covered by a single entity of type `type_`
+    pub fn entity(self, text: &str, type_: TextEntityType) -> Self {
+        self.push(text, Some(type_))
+    }
+
+    pub fn bold(self, text: &str) -> Self {
+        self.entity(text, TextEntityType::TextEntityTypeBold(types::TextEntityTypeBold {}))
+    }
+
+    pub fn italic(self, text: &str) -> Self {
+        self.entity(text, TextEntityType::TextEntityTypeItalic(types::TextEntityTypeItalic {}))
+    }
+
+    pub fn underline(self, text: &str) -> Self {
+        self.entity(text, TextEntityType::TextEntityTypeUnderline(types::TextEntityTypeUnderline {}))
+    }
+
+    pub fn strikethrough(self, text: &str) -> Self {
+        self.entity(text, TextEntityType::TextEntityTypeStrikethrough(types::TextEntityTypeStrikethrough {}))
+    }
+
+    /// Inline monospace text
+    pub fn code(self, text: &str) -> Self {
+        self.entity(text, TextEntityType::TextEntityTypeCode(types::TextEntityTypeCode {}))
+    }
+
+    /// Monospace block
+    pub fn pre(self, text: &str) -> Self {
+        self.entity(text, TextEntityType::TextEntityTypePre(types::TextEntityTypePre {}))
+    }
+
+    /// Monospace block of code in `language`
+    pub fn pre_code(self, text: &str, language: &str) -> Self {
+        self.entity(text, TextEntityType::TextEntityTypePreCode(types::TextEntityTypePreCode {
+            language: language.to_owned(),
+        }))
+    }
+
+    pub fn link(self, text: &str, url: &str) -> Self {
+        self.entity(text, TextEntityType::TextEntityTypeTextUrl(types::TextEntityTypeTextUrl {
+            url: url.to_owned(),
+        }))
+    }
+
+    /// Mention of a user without a username
+    pub fn mention(self, text: &str, user_id: i32) -> Self {
+        self.entity(text, TextEntityType::TextEntityTypeMentionName(types::TextEntityTypeMentionName {
+            user_id,
+        }))
+    }
+
+    /// Appends already formatted text, e.g. a fragment built elsewhere
+    pub fn append(mut self, formatted: FormattedText) -> Self {
+        let offset = self.length;
+        self.entities.extend(formatted.entities.into_iter().map(|entity| TextEntity {
+            offset: entity.offset + offset,
+            ..entity
+        }));
+        self.length += utf16_len(&formatted.text);
+        self.text.push_str(&formatted.text);
+        self
+    }
+
+    /// Appends formatted text covered by another entity, to nest entities
+    /// like a bold part of a link
+    pub fn wrap(mut self, formatted: FormattedText, type_: TextEntityType) -> Self {
+        let length = utf16_len(&formatted.text);
+        if length > 0 {
+            self.entities.push(TextEntity { offset: self.length, length, type_ });
+        }
+        self.append(formatted)
+    }
+
+    /// Length of the text so far in UTF-16 code units
+    pub fn len(&self) -> i32 {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    pub fn build(self) -> FormattedText {
+        FormattedText { text: self.text, entities: self.entities }
+    }
+
+    fn push(mut self, text: &str, type_: Option<TextEntityType>) -> Self {
+        let length = utf16_len(text);
+        if let Some(type_) = type_ {
+            if length > 0 {
+                self.entities.push(TextEntity { offset: self.length, length, type_ });
+            }
+        }
+        self.length += length;
+        self.text.push_str(text);
+        self
+    }
+}
+
+/// The part of `formatted` within the byte range `range`, with entities
+/// cut to fit. Panics if the range does not lie on `char` boundaries,
+/// like slicing a `str` does.
+pub fn slice(formatted: &FormattedText, range: Range<usize>) -> FormattedText {
+    let text = &formatted.text[range.clone()];
+    let start = utf16_len(&formatted.text[..range.start]);
+    let end = start + utf16_len(text);
+    let entities = formatted.entities
+        .iter()
+        .filter_map(|entity| {
+            let from = entity.offset.max(start);
+            let to = (entity.offset + entity.length).min(end);
+            if from < to {
+                Some(TextEntity { offset: from - start, length: to - from, type_: entity.type_.clone() })
+            } else {
+                None
+            }
+        })
+        .collect();
+    FormattedText { text: text.to_owned(), entities }
+}
+
+/// Joins texts one after another, shifting their entities
+pub fn concat<I: IntoIterator<Item = FormattedText>>(parts: I) -> FormattedText {
+    parts.into_iter()
+        .fold(FormattedTextBuilder::new(), FormattedTextBuilder::append)
+        .build()
+}
+
+/// Splits `formatted` into parts of at most `max_length` UTF-16 code units,
+/// e.g. `MAX_MESSAGE_LENGTH`, to send as several messages. Cuts after a
+/// line break if possible, otherwise after whitespace; entities crossing
+/// a cut are split between both parts.
+pub fn split(formatted: &FormattedText, max_length: i32) -> Vec<FormattedText> {
+    let text = &formatted.text;
+    let mut parts = Vec::new();
+    let mut start = 0;
+    while start < text.len() {
+        let rest = &text[start..];
+        // Byte index where the part reaches `max_length`
+        let mut limit = rest.len();
+        let mut length = 0;
+        for (index, ch) in rest.char_indices() {
+            length += ch.len_utf16() as i32;
+            if length > max_length {
+                limit = index;
+                break;
+            }
+        }
+        let end = if limit == rest.len() {
+            limit
+        } else if limit == 0 {
+            // Not even one character fits, take it anyway to make progress
+            rest.chars().next().map_or(rest.len(), char::len_utf8)
+        } else {
+            // Breaks in leading whitespace would cut off a part with
+            // nothing to send
+            let lead = rest[..limit].len() - rest[..limit].trim_start().len();
+            let window = &rest[lead..limit];
+            window.rfind('\n')
+                .or_else(|| window.rfind(char::is_whitespace))
+                .map(|index| lead + index + window[index..].chars().next().map_or(1, char::len_utf8))
+                .unwrap_or(limit)
+        };
+        parts.push(slice(formatted, start..start + end));
+        start += end;
+    }
+    parts
+}
+
+/// `sendMessage` of `text` to `chat_id`, with TDLib's default options
+pub fn send_message(chat_id: i64, text: FormattedText) -> methods::SendMessage {
+    methods::SendMessage {
+        chat_id,
+        reply_to_message_id: 0,
+        options: types::SendMessageOptions {
+            disable_notification: false,
+            from_background: false,
+            scheduling_state: None,
+        },
+        reply_markup: None,
+        input_message_content: types::InputMessageContent::InputMessageText(types::InputMessageText {
+            text,
+            disable_web_page_preview: false,
+            clear_draft: false,
+        }),
+    }
+}
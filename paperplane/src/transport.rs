@@ -0,0 +1,45 @@
+use crate::error::Result;
+
+/// The JSON interface of a TDLib instance as used by `Client`.
+///
+/// `bindings::Tdlib` is the real implementation; `testing::MockTdlib`
+/// scripts responses instead. `receive` is only ever called from one
+/// thread at a time. Requests that cannot be passed on fail with
+/// `Error::Transport`.
+pub trait Transport: Send + Sync + 'static {
+    fn send(&self, request: &str) -> Result<()>;
+    fn receive(&self, timeout: f64) -> Option<String>;
+    fn execute(&self, request: &str) -> Result<Option<String>>;
+}
+
+#[cfg(feature = "tdjson")]
+impl Transport for crate::bindings::Tdlib {
+    fn send(&self, request: &str) -> Result<()> {
+        Ok(crate::bindings::Tdlib::send(self, request)?)
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> {
+        crate::bindings::Tdlib::receive(self, timeout)
+    }
+
+    fn execute(&self, request: &str) -> Result<Option<String>> {
+        Ok(crate::bindings::Tdlib::execute(self, request)?)
+    }
+}
+
+// Everything is received through the `ClientManager` loop, which never
+// polls the per-client transport
+#[cfg(feature = "multi-client")]
+impl Transport for crate::bindings::TdClientId {
+    fn send(&self, request: &str) -> Result<()> {
+        Ok(crate::bindings::TdClientId::send(*self, request)?)
+    }
+
+    fn receive(&self, _timeout: f64) -> Option<String> {
+        None
+    }
+
+    fn execute(&self, request: &str) -> Result<Option<String>> {
+        Ok(crate::bindings::TdClientId::execute(request)?)
+    }
+}
@@ -0,0 +1,150 @@
+use std::{ future::Future, marker::PhantomData };
+use futures::future::{ self, BoxFuture };
+use paperplane_types::types::Update;
+use crate::client::Client;
+use super::{
+    Handler,
+    RawUpdate,
+    filter::Filter,
+    variant::UpdateVariant,
+};
+
+/// Handler for a single update variant, see `Dispatcher::on`
+pub trait TypedHandler<T>: Send + Sync + 'static {
+    fn handle(&self, _: Client, _: T) -> BoxFuture<'static, ()>;
+}
+
+impl<T, C, F> TypedHandler<T> for C
+where C: Send + Sync + 'static + Fn(Client, T) -> F,
+      F: Future<Output = ()> + 'static + Send {
+    fn handle(&self, client: Client, upd: T) -> BoxFuture<'static, ()> {
+        Box::pin((*self)(client, upd))
+    }
+}
+
+trait Route: Send + Sync + 'static {
+    fn accepts(&self, upd: &Update) -> bool;
+    fn handle(&self, client: Client, upd: Update) -> BoxFuture<'static, ()>;
+}
+
+struct TypedRoute<T, F, H> {
+    filter: F,
+    handler: H,
+    _variant: PhantomData<fn(T)>,
+}
+
+impl<T, F, H> Route for TypedRoute<T, F, H>
+where T: UpdateVariant,
+      F: Filter<T>,
+      H: TypedHandler<T> {
+    fn accepts(&self, upd: &Update) -> bool {
+        T::from_update_ref(upd).is_some_and(|variant| self.filter.matches(variant))
+    }
+
+    fn handle(&self, client: Client, upd: Update) -> BoxFuture<'static, ()> {
+        match T::from_update(upd) {
+            Some(variant) => self.handler.handle(client, variant),
+            None => Box::pin(async {}),
+        }
+    }
+}
+
+type Middleware = Box<dyn Fn(&Client, &Update) -> bool + Send + Sync + 'static>;
+
+/// Routes updates to handlers registered per `Update` variant.
+///
+/// Middleware runs first, in registration order; any of it returning
+/// false drops the update. Routes are then tried in registration order
+/// and the first one whose variant and filter match handles the update.
+/// Updates no route accepted go to the fallback, if any.
+///
+/// Raw handlers get the JSON of every update, before and regardless of
+/// middleware and routes.
+///
+/// ```ignore
+/// let dispatcher = Dispatcher::new()
+///     .middleware(|_, upd| { trace!("{:?}", upd); true })
+///     .on_filtered(filter::and(filter::chat(chat_id), filter::content::<types::MessageText>()), on_text)
+///     .on(|client, upd: types::UpdateFile| async move { ... })
+///     .fallback(|_, _| async {});
+/// let client = Client::new(None, dispatcher);
+/// ```
+#[derive(Default)]
+pub struct Dispatcher {
+    middleware: Vec<Middleware>,
+    routes: Vec<Box<dyn Route>>,
+    fallback: Option<Box<dyn Handler>>,
+    raw: Vec<Box<dyn TypedHandler<RawUpdate>>>,
+}
+
+impl Dispatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn middleware<M>(mut self, middleware: M) -> Self
+    where M: Fn(&Client, &Update) -> bool + Send + Sync + 'static {
+        self.middleware.push(Box::new(middleware));
+        self
+    }
+
+    pub fn on<T, H>(self, handler: H) -> Self
+    where T: UpdateVariant,
+          H: TypedHandler<T> {
+        self.on_filtered(|_: &T| true, handler)
+    }
+
+    pub fn on_filtered<T, F, H>(mut self, filter: F, handler: H) -> Self
+    where T: UpdateVariant,
+          F: Filter<T>,
+          H: TypedHandler<T> {
+        self.routes.push(Box::new(TypedRoute {
+            filter,
+            handler,
+            _variant: PhantomData,
+        }));
+        self
+    }
+
+    pub fn fallback<H: Handler>(mut self, handler: H) -> Self {
+        self.fallback = Some(Box::new(handler));
+        self
+    }
+
+    /// Also passes every update's JSON to `handler`, see `Handler::handle_raw`
+    pub fn on_raw<H: TypedHandler<RawUpdate>>(mut self, handler: H) -> Self {
+        self.raw.push(Box::new(handler));
+        self
+    }
+}
+
+impl Handler for Dispatcher {
+    fn handle(&self, client: Client, upd: Update) -> BoxFuture<'static, ()> {
+        if !self.middleware.iter().all(|middleware| middleware(&client, &upd)) {
+            return Box::pin(async {});
+        }
+        if let Some(route) = self.routes.iter().find(|route| route.accepts(&upd)) {
+            return route.handle(client, upd);
+        }
+        match &self.fallback {
+            Some(fallback) => fallback.handle(client, upd),
+            None => Box::pin(async {}),
+        }
+    }
+
+    fn handle_raw(&self, client: Client, upd: &RawUpdate) -> Option<BoxFuture<'static, ()>> {
+        match self.raw.as_slice() {
+            [] => None,
+            [handler] => Some(handler.handle(client, upd.clone())),
+            handlers => {
+                let handled: Vec<_> = handlers
+                    .iter()
+                    .map(|handler| handler.handle(client.clone(), upd.clone()))
+                    .collect();
+                Some(Box::pin(async move {
+                    future::join_all(handled).await;
+                }))
+            },
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::marker::PhantomData;
+use super::variant::{ ChatUpdate, SenderUpdate, MessageUpdate, ContentVariant };
+
+/// Predicate deciding whether a `Dispatcher` route accepts an update.
+/// Implemented for plain closures `Fn(&T) -> bool`.
+pub trait Filter<T>: Send + Sync + 'static {
+    fn matches(&self, upd: &T) -> bool;
+}
+
+impl<T, C> Filter<T> for C
+where C: Fn(&T) -> bool + Send + Sync + 'static {
+    fn matches(&self, upd: &T) -> bool {
+        (*self)(upd)
+    }
+}
+
+pub struct And<A, B>(A, B);
+
+pub fn and<A, B>(a: A, b: B) -> And<A, B> {
+    And(a, b)
+}
+
+impl<T, A: Filter<T>, B: Filter<T>> Filter<T> for And<A, B> {
+    fn matches(&self, upd: &T) -> bool {
+        self.0.matches(upd) && self.1.matches(upd)
+    }
+}
+
+pub struct Or<A, B>(A, B);
+
+pub fn or<A, B>(a: A, b: B) -> Or<A, B> {
+    Or(a, b)
+}
+
+impl<T, A: Filter<T>, B: Filter<T>> Filter<T> for Or<A, B> {
+    fn matches(&self, upd: &T) -> bool {
+        self.0.matches(upd) || self.1.matches(upd)
+    }
+}
+
+pub struct Not<A>(A);
+
+pub fn not<A>(a: A) -> Not<A> {
+    Not(a)
+}
+
+impl<T, A: Filter<T>> Filter<T> for Not<A> {
+    fn matches(&self, upd: &T) -> bool {
+        !self.0.matches(upd)
+    }
+}
+
+pub struct Chat(i64);
+
+/// Updates from the chat with the given id
+pub fn chat(chat_id: i64) -> Chat {
+    Chat(chat_id)
+}
+
+impl<T: ChatUpdate> Filter<T> for Chat {
+    fn matches(&self, upd: &T) -> bool {
+        upd.chat_id() == self.0
+    }
+}
+
+pub struct Sender(i32);
+
+/// Updates caused by the user with the given id
+pub fn sender(user_id: i32) -> Sender {
+    Sender(user_id)
+}
+
+impl<T: SenderUpdate> Filter<T> for Sender {
+    fn matches(&self, upd: &T) -> bool {
+        upd.sender_user_id() == self.0
+    }
+}
+
+pub struct Content<C>(PhantomData<fn() -> C>);
+
+/// Messages whose content is of the given kind, e.g. `content::<types::MessageText>()`
+pub fn content<C: ContentVariant>() -> Content<C> {
+    Content(PhantomData)
+}
+
+impl<T: MessageUpdate, C: ContentVariant> Filter<T> for Content<C> {
+    fn matches(&self, upd: &T) -> bool {
+        C::from_content(&upd.message().content).is_some()
+    }
+}
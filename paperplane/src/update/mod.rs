@@ -0,0 +1,53 @@
+use std::future::Future;
+use futures::future::BoxFuture;
+use serde_json::Value as JsonValue;
+use crate::client::Client;
+use paperplane_types::types::Update;
+
+mod stream;
+mod dispatcher;
+mod variant;
+pub mod filter;
+pub use stream::{ UpdateStream, LagPolicy, DEFAULT_CAPACITY };
+pub(crate) use stream::Broadcast;
+pub use dispatcher::{ Dispatcher, TypedHandler };
+pub use variant::{ UpdateVariant, ContentVariant, ChatUpdate, SenderUpdate, MessageUpdate };
+
+pub trait Handler: Send + Sync + 'static {
+    fn handle(&self, _: Client, _: Update) -> BoxFuture<'static, ()>;
+
+    /// Sees every update as TDLib sent it, right before `handle` gets it
+    /// deserialized, including updates that fail to deserialize. Does
+    /// nothing unless overridden, e.g. by `Dispatcher::on_raw`.
+    fn handle_raw(&self, _: Client, _: &RawUpdate) -> Option<BoxFuture<'static, ()>> {
+        None
+    }
+}
+
+/// An update's JSON, untouched, for fields or updates `paperplane_types`
+/// does not know yet
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawUpdate(pub JsonValue);
+
+impl RawUpdate {
+    /// The update's `@type`, e.g. `"updateNewMessage"`
+    pub fn type_name(&self) -> &str {
+        self.0["@type"].as_str().unwrap_or_default()
+    }
+
+    pub fn json(&self) -> &JsonValue {
+        &self.0
+    }
+
+    pub fn into_json(self) -> JsonValue {
+        self.0
+    }
+}
+
+impl<C, F> Handler for C
+where C: Send + Sync + 'static + Fn(Client, Update) -> F,
+      F: Future<Output = ()> + 'static + Send {
+    fn handle(&self, client: Client, req: Update) -> BoxFuture<'static, ()> {
+        Box::pin((*self)(client, req))
+    }
+}
@@ -0,0 +1,152 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{ Arc, Weak, Mutex },
+    task::{ Context, Poll, Waker },
+};
+use futures::stream::Stream;
+use paperplane_types::types::Update;
+
+/// What to do when a subscriber falls `capacity` updates behind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LagPolicy {
+    /// Discard the oldest buffered update to make room for the new one
+    #[default]
+    DropOldest,
+    /// Discard the incoming update
+    DropNewest,
+    /// End the stream; the subscriber must resubscribe and resync
+    Disconnect,
+}
+
+pub const DEFAULT_CAPACITY: usize = 1024;
+
+#[derive(Debug)]
+struct Subscriber {
+    queue: VecDeque<Update>,
+    capacity: usize,
+    policy: LagPolicy,
+    lagged: u64,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+impl Subscriber {
+    fn push(&mut self, upd: Update) {
+        if self.closed {
+            return;
+        }
+        if self.queue.len() >= self.capacity {
+            self.lagged += 1;
+            match self.policy {
+                LagPolicy::DropOldest => { self.queue.pop_front(); },
+                LagPolicy::DropNewest => return,
+                LagPolicy::Disconnect => {
+                    self.closed = true;
+                    self.wake();
+                    return;
+                },
+            }
+        }
+        self.queue.push_back(upd);
+        self.wake();
+    }
+
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+// Subscribers and whether the broadcast was closed, under one lock so
+// nobody can subscribe in between closing and notifying the others
+#[derive(Debug, Default)]
+struct Subscribers {
+    list: Vec<Weak<Mutex<Subscriber>>>,
+    closed: bool,
+}
+
+/// Fan-out of updates to every live `UpdateStream`, fed by the responder
+/// thread in the order TDLib delivered them
+#[derive(Debug, Default)]
+pub(crate) struct Broadcast {
+    subscribers: Mutex<Subscribers>,
+}
+
+impl Broadcast {
+    pub(crate) fn subscribe(&self, capacity: usize, policy: LagPolicy) -> UpdateStream {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        let subscriber = Arc::new(Mutex::new(Subscriber {
+            queue: VecDeque::new(),
+            capacity: capacity.max(1),
+            policy,
+            lagged: 0,
+            closed: subscribers.closed,
+            waker: None,
+        }));
+        if !subscribers.closed {
+            subscribers.list.push(Arc::downgrade(&subscriber));
+        }
+        UpdateStream { subscriber }
+    }
+
+    pub(crate) fn has_subscribers(&self) -> bool {
+        !self.subscribers.lock().unwrap().list.is_empty()
+    }
+
+    pub(crate) fn publish(&self, upd: &Update) {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        subscribers.list.retain(|weak| match weak.upgrade() {
+            Some(subscriber) => {
+                let mut subscriber = subscriber.lock().unwrap();
+                subscriber.push(upd.clone());
+                !subscriber.closed
+            },
+            None => false,
+        });
+    }
+
+    pub(crate) fn close(&self) {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        subscribers.closed = true;
+        for weak in subscribers.list.drain(..) {
+            if let Some(subscriber) = weak.upgrade() {
+                let mut subscriber = subscriber.lock().unwrap();
+                subscriber.closed = true;
+                subscriber.wake();
+            }
+        }
+    }
+}
+
+/// Ordered stream of updates, see `Client::updates`.
+/// Ends when the client is closed or, with `LagPolicy::Disconnect`,
+/// when the subscriber falls behind.
+#[derive(Debug)]
+pub struct UpdateStream {
+    subscriber: Arc<Mutex<Subscriber>>,
+}
+
+impl UpdateStream {
+    /// Number of updates lost to the lag policy so far
+    pub fn lagged(&self) -> u64 {
+        self.subscriber.lock().unwrap().lagged
+    }
+}
+
+impl Stream for UpdateStream {
+    type Item = Update;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Update>> {
+        let mut subscriber = self.subscriber.lock().unwrap();
+        if let Some(upd) = subscriber.queue.pop_front() {
+            Poll::Ready(Some(upd))
+        } else if subscriber.closed {
+            Poll::Ready(None)
+        } else {
+            subscriber.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use paperplane_types::types::{ self, Update, MessageContent };
+
+/// A concrete `Update` variant that the `Dispatcher` can route to
+pub trait UpdateVariant: Sized + Send + 'static {
+    fn from_update(upd: Update) -> Option<Self>;
+    fn from_update_ref(upd: &Update) -> Option<&Self>;
+}
+
+/// A concrete `MessageContent` variant, e.g. `types::MessageText`
+pub trait ContentVariant: Sized + Send + 'static {
+    fn from_content(content: &MessageContent) -> Option<&Self>;
+}
+
+/// Updates that concern a single chat
+pub trait ChatUpdate {
+    fn chat_id(&self) -> i64;
+}
+
+/// Updates caused by a particular user
+pub trait SenderUpdate {
+    fn sender_user_id(&self) -> i32;
+}
+
+/// Updates carrying a whole `Message`
+pub trait MessageUpdate {
+    fn message(&self) -> &types::Message;
+}
+
+macro_rules! update_variants {
+    ($($name:ident,)*) => {
+        $(
+            impl UpdateVariant for types::$name {
+                fn from_update(upd: Update) -> Option<Self> {
+                    match upd {
+                        Update::$name(inner) => Some(inner),
+                        _ => None,
+                    }
+                }
+
+                fn from_update_ref(upd: &Update) -> Option<&Self> {
+                    match upd {
+                        Update::$name(inner) => Some(inner),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! content_variants {
+    ($($name:ident,)*) => {
+        $(
+            impl ContentVariant for types::$name {
+                fn from_content(content: &MessageContent) -> Option<&Self> {
+                    match content {
+                        MessageContent::$name(inner) => Some(inner),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! chat_updates {
+    ($($name:ident,)*) => {
+        $(
+            impl ChatUpdate for types::$name {
+                fn chat_id(&self) -> i64 {
+                    self.chat_id
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! sender_updates {
+    ($($name:ident,)*) => {
+        $(
+            impl SenderUpdate for types::$name {
+                fn sender_user_id(&self) -> i32 {
+                    self.sender_user_id
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! message_updates {
+    ($($name:ident,)*) => {
+        $(
+            impl MessageUpdate for types::$name {
+                fn message(&self) -> &types::Message {
+                    &self.message
+                }
+            }
+
+            impl ChatUpdate for types::$name {
+                fn chat_id(&self) -> i64 {
+                    self.message.chat_id
+                }
+            }
+
+            impl SenderUpdate for types::$name {
+                fn sender_user_id(&self) -> i32 {
+                    self.message.sender_user_id
+                }
+            }
+        )*
+    };
+}
+
+impl ChatUpdate for types::UpdateNewChat {
+    fn chat_id(&self) -> i64 {
+        self.chat.id
+    }
+}
+
+update_variants! {
+    UpdateAuthorizationState, UpdateNewMessage, UpdateMessageSendAcknowledged,
+    UpdateMessageSendSucceeded, UpdateMessageSendFailed, UpdateMessageContent,
+    UpdateMessageEdited, UpdateMessageViews, UpdateMessageContentOpened,
+    UpdateMessageMentionRead, UpdateMessageLiveLocationViewed, UpdateNewChat,
+    UpdateChatChatList, UpdateChatTitle, UpdateChatPhoto, UpdateChatPermissions,
+    UpdateChatLastMessage, UpdateChatOrder, UpdateChatIsPinned,
+    UpdateChatIsMarkedAsUnread, UpdateChatSource, UpdateChatHasScheduledMessages,
+    UpdateChatDefaultDisableNotification, UpdateChatReadInbox, UpdateChatReadOutbox,
+    UpdateChatUnreadMentionCount, UpdateChatNotificationSettings,
+    UpdateScopeNotificationSettings, UpdateChatActionBar, UpdateChatPinnedMessage,
+    UpdateChatReplyMarkup, UpdateChatDraftMessage, UpdateChatOnlineMemberCount,
+    UpdateNotification, UpdateNotificationGroup, UpdateActiveNotifications,
+    UpdateHavePendingNotifications, UpdateDeleteMessages, UpdateUserChatAction,
+    UpdateUserStatus, UpdateUser, UpdateBasicGroup, UpdateSupergroup, UpdateSecretChat,
+    UpdateUserFullInfo, UpdateBasicGroupFullInfo, UpdateSupergroupFullInfo,
+    UpdateServiceNotification, UpdateFile, UpdateFileGenerationStart,
+    UpdateFileGenerationStop, UpdateCall, UpdateUserPrivacySettingRules,
+    UpdateUnreadMessageCount, UpdateUnreadChatCount, UpdateOption, UpdateStickerSet,
+    UpdateInstalledStickerSets, UpdateTrendingStickerSets, UpdateRecentStickers,
+    UpdateFavoriteStickers, UpdateSavedAnimations, UpdateSelectedBackground,
+    UpdateLanguagePackStrings, UpdateConnectionState, UpdateTermsOfService,
+    UpdateUsersNearby, UpdateDiceEmojis, UpdateNewInlineQuery,
+    UpdateNewChosenInlineResult, UpdateNewCallbackQuery, UpdateNewInlineCallbackQuery,
+    UpdateNewShippingQuery, UpdateNewPreCheckoutQuery, UpdateNewCustomEvent,
+    UpdateNewCustomQuery, UpdatePoll, UpdatePollAnswer,
+}
+
+content_variants! {
+    MessageText, MessageAnimation, MessageAudio, MessageDocument, MessagePhoto,
+    MessageExpiredPhoto, MessageSticker, MessageVideo, MessageExpiredVideo,
+    MessageVideoNote, MessageVoiceNote, MessageLocation, MessageVenue, MessageContact,
+    MessageDice, MessageGame, MessagePoll, MessageInvoice, MessageCall,
+    MessageBasicGroupChatCreate, MessageSupergroupChatCreate, MessageChatChangeTitle,
+    MessageChatChangePhoto, MessageChatDeletePhoto, MessageChatAddMembers,
+    MessageChatJoinByLink, MessageChatDeleteMember, MessageChatUpgradeTo,
+    MessageChatUpgradeFrom, MessagePinMessage, MessageScreenshotTaken, MessageChatSetTtl,
+    MessageCustomServiceAction, MessageGameScore, MessagePaymentSuccessful,
+    MessagePaymentSuccessfulBot, MessageContactRegistered, MessageWebsiteConnected,
+    MessagePassportDataSent, MessagePassportDataReceived, MessageUnsupported,
+}
+
+chat_updates! {
+    UpdateMessageSendAcknowledged, UpdateMessageContent, UpdateMessageEdited,
+    UpdateMessageViews, UpdateMessageContentOpened, UpdateMessageMentionRead,
+    UpdateMessageLiveLocationViewed, UpdateChatChatList, UpdateChatTitle,
+    UpdateChatPhoto, UpdateChatPermissions, UpdateChatLastMessage, UpdateChatOrder,
+    UpdateChatIsPinned, UpdateChatIsMarkedAsUnread, UpdateChatSource,
+    UpdateChatHasScheduledMessages, UpdateChatDefaultDisableNotification,
+    UpdateChatReadInbox, UpdateChatReadOutbox, UpdateChatUnreadMentionCount,
+    UpdateChatNotificationSettings, UpdateChatActionBar, UpdateChatPinnedMessage,
+    UpdateChatReplyMarkup, UpdateChatDraftMessage, UpdateChatOnlineMemberCount,
+    UpdateNotificationGroup, UpdateDeleteMessages, UpdateUserChatAction,
+    UpdateNewCallbackQuery,
+}
+
+sender_updates! {
+    UpdateNewInlineQuery, UpdateNewChosenInlineResult, UpdateNewCallbackQuery,
+    UpdateNewInlineCallbackQuery, UpdateNewShippingQuery, UpdateNewPreCheckoutQuery,
+}
+
+message_updates! {
+    UpdateNewMessage, UpdateMessageSendSucceeded, UpdateMessageSendFailed,
+}
@@ -0,0 +1,161 @@
+mod common;
+
+use std::{ env, sync::{ Arc, Mutex } };
+use futures::future::BoxFuture;
+use serde_json::{ json, Value as JsonValue };
+use paperplane::{
+    auth::{ Authenticator, Authorization, BotAuthenticator, Ready },
+    client::Client,
+    testing::MockTdlib,
+    Error,
+    Result,
+};
+use paperplane_types::{ methods::{ self, Method }, types::{ self, Update } };
+use common::authorization_state;
+
+fn parameters() -> types::TdlibParameters {
+    types::TdlibParameters {
+        use_test_dc: true,
+        database_directory: "database".to_owned(),
+        files_directory: "files".to_owned(),
+        use_file_database: false,
+        use_chat_info_database: false,
+        use_message_database: false,
+        use_secret_chats: false,
+        api_id: 1,
+        api_hash: "hash".to_owned(),
+        system_language_code: "en".to_owned(),
+        device_model: "test".to_owned(),
+        system_version: "1".to_owned(),
+        application_version: "0.1".to_owned(),
+        enable_storage_optimizer: false,
+        ignore_file_names: true,
+    }
+}
+
+// Answers `M` with `ok`, moving TDLib on to `next`
+fn advance<M: Method>(mock: &MockTdlib, next: JsonValue) {
+    let tdlib = mock.clone();
+    mock.respond_json(M::TYPE, move |_| {
+        tdlib.push_json(next.clone());
+        json!({ "@type": "ok" })
+    });
+}
+
+fn wait_code() -> JsonValue {
+    json!({
+        "@type": "updateAuthorizationState",
+        "authorization_state": {
+            "@type": "authorizationStateWaitCode",
+            "code_info": {
+                "@type": "authenticationCodeInfo",
+                "phone_number": "+10000000000",
+                "type": { "@type": "authenticationCodeTypeSms", "length": 5 },
+                "timeout": 60,
+            },
+        },
+    })
+}
+
+fn client<A: Authenticator>(mock: &MockTdlib, authenticator: A) -> (Client, Ready) {
+    let (handler, ready) = Authorization::new(authenticator, |_: Client, _: Update| async {});
+    (Client::with_transport(mock.clone(), handler), ready)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn logs_in_as_a_bot() {
+    let mock = MockTdlib::new();
+    advance::<methods::SetTdlibParameters>(&mock, authorization_state("authorizationStateWaitEncryptionKey"));
+    advance::<methods::CheckDatabaseEncryptionKey>(&mock, authorization_state("authorizationStateWaitPhoneNumber"));
+    advance::<methods::CheckAuthenticationBotToken>(&mock, authorization_state("authorizationStateReady"));
+    let (client, ready) = client(&mock, BotAuthenticator::new(parameters(), "123:token".to_owned()));
+
+    mock.push_json(authorization_state("authorizationStateWaitTdlibParameters"));
+    ready.await.unwrap();
+    assert_eq!(mock.sent_of::<methods::SetTdlibParameters>()[0].parameters.api_hash, "hash");
+    assert_eq!(mock.sent_of::<methods::CheckAuthenticationBotToken>()[0].token, "123:token");
+    assert!(mock.sent_of::<methods::SetAuthenticationPhoneNumber>().is_empty());
+    client.close().await.unwrap();
+}
+
+// Answers with the next of `codes` on every request for a code
+struct User {
+    codes: Mutex<Vec<&'static str>>,
+    rejected: Arc<Mutex<Vec<String>>>,
+}
+
+impl Authenticator for User {
+    fn tdlib_parameters(&self) -> BoxFuture<'static, Result<types::TdlibParameters>> {
+        Box::pin(async { Ok(parameters()) })
+    }
+
+    fn phone_number(&self) -> BoxFuture<'static, Result<String>> {
+        Box::pin(async { Ok("+10000000000".to_owned()) })
+    }
+
+    fn code(&self, _: types::AuthenticationCodeInfo) -> BoxFuture<'static, Result<String>> {
+        let code = self.codes.lock().unwrap().remove(0).to_owned();
+        Box::pin(async move { Ok(code) })
+    }
+
+    fn retry(&self, err: &Error) -> bool {
+        self.rejected.lock().unwrap().push(err.to_string());
+        matches!(err.message(), Some("PHONE_CODE_INVALID"))
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn asks_for_the_code_again_when_retrying() {
+    let mock = MockTdlib::new();
+    advance::<methods::SetTdlibParameters>(&mock, authorization_state("authorizationStateWaitEncryptionKey"));
+    advance::<methods::CheckDatabaseEncryptionKey>(&mock, authorization_state("authorizationStateWaitPhoneNumber"));
+    advance::<methods::SetAuthenticationPhoneNumber>(&mock, wait_code());
+    let tdlib = mock.clone();
+    mock.respond_json("checkAuthenticationCode", move |req| {
+        if req["code"] == "11111" {
+            json!({ "@type": "error", "code": 400, "message": "PHONE_CODE_INVALID" })
+        } else {
+            tdlib.push_json(authorization_state("authorizationStateReady"));
+            json!({ "@type": "ok" })
+        }
+    });
+    let rejected = Arc::new(Mutex::new(Vec::new()));
+    let user = User { codes: Mutex::new(vec!["11111", "22222"]), rejected: rejected.clone() };
+    let (client, ready) = client(&mock, user);
+
+    mock.push_json(authorization_state("authorizationStateWaitTdlibParameters"));
+    ready.await.unwrap();
+    let codes: Vec<_> = mock.sent_of::<methods::CheckAuthenticationCode>().into_iter().map(|req| req.code).collect();
+    assert_eq!(codes, ["11111", "22222"]);
+    assert_eq!(*rejected.lock().unwrap(), ["tdlib error 400: PHONE_CODE_INVALID"]);
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn fails_when_a_step_fails() {
+    let mock = MockTdlib::new();
+    mock.respond_error::<methods::SetTdlibParameters>(400, "API_ID_INVALID");
+    let (client, ready) = client(&mock, BotAuthenticator::new(parameters(), "123:token".to_owned()));
+
+    mock.push_json(authorization_state("authorizationStateWaitTdlibParameters"));
+    let err = ready.await.unwrap_err();
+    assert_eq!(err.message(), Some("API_ID_INVALID"));
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn fails_when_the_client_closes() {
+    let mock = MockTdlib::new();
+    let (client, ready) = client(&mock, BotAuthenticator::new(parameters(), "123:token".to_owned()));
+
+    client.close().await.unwrap();
+    assert!(matches!(ready.await, Err(Error::ClientClosed)));
+}
+
+#[test]
+fn reads_the_bot_token_from_the_environment() {
+    env::set_var("BOT_TOKEN", "123:token");
+    assert!(BotAuthenticator::from_env(parameters()).is_ok());
+    env::remove_var("BOT_TOKEN");
+    assert!(matches!(BotAuthenticator::from_env(parameters()), Err(Error::Auth(_))));
+}
@@ -0,0 +1,45 @@
+use serde_json::json;
+use paperplane::{ blocking::Client, testing::MockTdlib, Error };
+use paperplane_types::{ methods, types::{ self, OptionValue, Update } };
+
+fn client(mock: &MockTdlib) -> Client {
+    Client::with_transport(mock.clone(), |_: paperplane::client::Client, _: Update| async {})
+}
+
+#[test]
+fn sends_and_closes_without_a_runtime() {
+    let mock = MockTdlib::new();
+    mock.respond(|req: methods::GetOption| {
+        OptionValue::OptionValueString(types::OptionValueString { value: req.name })
+    });
+    let client = client(&mock);
+
+    let value = client.send(methods::GetOption { name: "version".to_owned() }).unwrap();
+    assert!(matches!(value, OptionValue::OptionValueString(value) if value.value == "version"));
+    let raw = client.send_raw(json!({ "@type": "getOption", "name": "raw" })).unwrap();
+    assert_eq!(raw["value"], "raw");
+    let err = client.send(methods::GetMe {}).unwrap_err();
+    assert_eq!(err.code(), Some(400));
+
+    client.close().unwrap();
+    assert!(client.is_closed());
+    assert!(matches!(client.send(methods::GetMe {}), Err(Error::ClientClosed)));
+}
+
+#[test]
+fn iterates_over_updates() {
+    let mock = MockTdlib::new();
+    let client = client(&mock);
+    let updates = client.updates();
+
+    mock.push_json(json!({
+        "@type": "updateOption",
+        "name": "test",
+        "value": { "@type": "optionValueEmpty" },
+    }));
+    client.close().unwrap();
+    // Ends once the client is closed, with authorizationStateClosed last
+    let updates: Vec<_> = updates.collect();
+    assert_eq!(updates.len(), 2);
+    assert!(matches!(&updates[0], Update::UpdateOption(upd) if upd.name == "test"));
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use std::sync::{ Arc, Mutex };
+use serde_json::{ json, Value as JsonValue };
+use paperplane::{
+    bot::{ Bot, Command, FromArgs },
+    client::Client,
+    testing::MockTdlib,
+};
+use paperplane_types::{ methods, types::{ Message, Update } };
+use common::{ authorization_state, eventually, formatted_text };
+
+// A message starting with a bot command entity `command_length` long
+fn command_text(text: &str, command_length: usize) -> JsonValue {
+    formatted_text(text, json!([{
+        "@type": "textEntity",
+        "offset": 0,
+        "length": command_length,
+        "type": { "@type": "textEntityTypeBotCommand" },
+    }]))
+}
+
+fn command(text: &str, command_length: usize) -> Option<Command> {
+    let message: Message = serde_json::from_value(common::message(5, 1, command_text(text, command_length))).unwrap();
+    Command::parse(&message)
+}
+
+#[test]
+fn parses_commands() {
+    let start = command("/Start@MyBot  hello there ", 12).unwrap();
+    assert_eq!(start.name, "start");
+    assert_eq!(start.bot_username.as_deref(), Some("MyBot"));
+    assert_eq!(start.args, "hello there");
+    assert_eq!(start.chat_id(), 5);
+
+    let help = command("/help", 5).unwrap();
+    assert_eq!((help.name.as_str(), help.bot_username, help.args.as_str()), ("help", None, ""));
+
+    // Only entities at the very start count
+    assert!(command("/help", 0).is_none());
+    let message: Message = serde_json::from_value(common::message(5, 1, formatted_text("/help", json!([])))).unwrap();
+    assert!(Command::parse(&message).is_none());
+}
+
+#[test]
+fn parses_arguments() {
+    assert_eq!(String::from_args("  a b  "), Ok("a b".to_owned()));
+    assert_eq!(u32::from_args(" 7 "), Ok(7));
+    assert!(u32::from_args("7 8").is_err());
+    assert!(u32::from_args("").is_err());
+    assert!(u32::from_args("-1").unwrap_err().contains("invalid argument \"-1\""));
+    assert_eq!(bool::from_args("true"), Ok(true));
+    assert_eq!(<(i32, String)>::from_args("-3 x"), Ok((-3, "x".to_owned())));
+    assert_eq!(<(i32, String)>::from_args("-3"), Err("expected 2 arguments, got 1".to_owned()));
+    assert_eq!(Vec::<u64>::from_args("1 2 3"), Ok(vec![1, 2, 3]));
+    assert_eq!(Vec::<u64>::from_args(""), Ok(vec![]));
+    assert_eq!(Option::<u32>::from_args(" "), Ok(None));
+    assert_eq!(Option::<u32>::from_args("4"), Ok(Some(4)));
+    assert_eq!(<()>::from_args("ignored"), Ok(()));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn runs_commands() {
+    let mock = MockTdlib::new();
+    mock.respond_json("getMe", |_| json!({
+        "@type": "user",
+        "id": 1,
+        "first_name": "Bot",
+        "last_name": "",
+        "username": "MyBot",
+        "phone_number": "",
+        "status": { "@type": "userStatusEmpty" },
+        "restriction_reason": "",
+        "language_code": "",
+        "type": {
+            "@type": "userTypeBot",
+            "can_join_groups": true,
+            "can_read_all_group_messages": false,
+            "is_inline": false,
+            "inline_query_placeholder": "",
+            "need_location": false,
+        },
+    }));
+    mock.respond_json("setCommands", |_| json!({ "@type": "ok" }));
+    mock.respond_json("sendMessage", |req| {
+        common::message(req["chat_id"].as_i64().unwrap(), 100, req["input_message_content"]["text"].clone())
+    });
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let (roll, fallback) = (log.clone(), log.clone());
+    let bot = Bot::new()
+        .command("roll", "Roll a die", move |_: Client, _: Command, (count, sides): (u32, u32)| {
+            roll.lock().unwrap().push(format!("roll {}d{}", count, sides));
+            async {}
+        })
+        .help("Show the commands")
+        .fallback(move |_: Client, upd: Update| {
+            if let Update::UpdateNewMessage(upd) = upd {
+                fallback.lock().unwrap().push(format!("fallback {}", upd.message.id));
+            }
+            async {}
+        });
+    let client = Client::with_transport(mock.clone(), bot);
+
+    mock.push_json(authorization_state("authorizationStateReady"));
+    eventually(|| !mock.sent_of::<methods::SetCommands>().is_empty()).await;
+    let published: Vec<_> = mock.sent_of::<methods::SetCommands>()[0].commands.iter().map(|cmd| cmd.command.clone()).collect();
+    assert_eq!(published, vec!["roll", "help"]);
+
+    let new_command = |id: i64, text: &str, length: usize| {
+        json!({ "@type": "updateNewMessage", "message": common::message(5, id, command_text(text, length)) })
+    };
+    mock.push_json(new_command(1, "/roll 2 6", 5));
+    mock.push_json(new_command(2, "/roll@mybot 1 20", 11));
+    mock.push_json(new_command(3, "/roll@OtherBot 1 20", 14));
+    mock.push_json(new_command(4, "/roll six", 5));
+    mock.push_json(new_command(5, "/help", 5));
+    mock.push_json(new_command(6, "/unknown", 8));
+    eventually(|| mock.sent_of::<methods::SendMessage>().len() == 2 && log.lock().unwrap().len() == 4).await;
+
+    let mut handled = log.lock().unwrap().clone();
+    handled.sort();
+    assert_eq!(handled, vec!["fallback 3", "fallback 6", "roll 1d20", "roll 2d6"]);
+    let mut replies: Vec<_> = mock.sent_of::<methods::SendMessage>().into_iter().map(|msg| msg.reply_to_message_id).collect();
+    replies.sort();
+    assert_eq!(replies, vec![4, 5]);
+    client.close().await.unwrap();
+}
@@ -0,0 +1,133 @@
+mod common;
+
+use std::sync::{ Arc, Mutex };
+use serde_json::{ json, Value as JsonValue };
+use paperplane::{ cache::Cache, client::Client, testing::MockTdlib };
+use paperplane_types::types::{ Message, MessageContent, Update, UserStatus };
+use common::{ eventually, formatted_text, message, new_chat };
+
+fn apply(cache: &Cache, upd: JsonValue) {
+    cache.apply(&serde_json::from_value::<Update>(upd).unwrap());
+}
+
+fn text(message: &Message) -> &str {
+    match &message.content {
+        MessageContent::MessageText(content) => &content.text.text,
+        _ => panic!("not a text message"),
+    }
+}
+
+fn user(id: i32) -> JsonValue {
+    json!({
+        "@type": "updateUser",
+        "user": {
+            "@type": "user",
+            "id": id,
+            "first_name": "Jane",
+            "last_name": "",
+            "username": "jane",
+            "phone_number": "",
+            "status": { "@type": "userStatusEmpty" },
+            "is_contact": false,
+            "is_mutual_contact": false,
+            "is_verified": false,
+            "is_support": false,
+            "restriction_reason": "",
+            "is_scam": false,
+            "have_access": true,
+            "type": { "@type": "userTypeRegular" },
+            "language_code": "",
+        },
+    })
+}
+
+#[test]
+fn tracks_chats() {
+    let cache = Cache::new();
+    apply(&cache, new_chat(1, 10, "chatListMain"));
+    apply(&cache, json!({ "@type": "updateChatTitle", "chat_id": 1, "title": "Renamed" }));
+    apply(&cache, json!({ "@type": "updateChatOrder", "chat_id": 1, "order": "20" }));
+    apply(&cache, json!({
+        "@type": "updateChatReadInbox",
+        "chat_id": 1,
+        "last_read_inbox_message_id": 7,
+        "unread_count": 3,
+    }));
+    // Ignored, as the chat was never announced
+    apply(&cache, json!({ "@type": "updateChatTitle", "chat_id": 2, "title": "Unknown" }));
+
+    let chat = cache.chat(1).unwrap();
+    assert_eq!(chat.title, "Renamed");
+    assert_eq!(chat.order, 20);
+    assert_eq!(chat.last_read_inbox_message_id, 7);
+    assert_eq!(chat.unread_count, 3);
+    assert!(cache.chat(2).is_none());
+    assert_eq!(cache.chat_ids(), vec![1]);
+    assert_eq!(cache.with_chat(1, |chat| chat.id), Some(1));
+}
+
+#[test]
+fn keeps_the_last_message_up_to_date() {
+    let cache = Cache::new();
+    apply(&cache, new_chat(1, 10, "chatListMain"));
+    apply(&cache, json!({
+        "@type": "updateChatLastMessage",
+        "chat_id": 1,
+        "last_message": message(1, 5, formatted_text("hello", json!([]))),
+        "order": "30",
+    }));
+    let edit = |message_id: i64, text: &str| json!({
+        "@type": "updateMessageContent",
+        "chat_id": 1,
+        "message_id": message_id,
+        "new_content": { "@type": "messageText", "text": formatted_text(text, json!([])) },
+    });
+    apply(&cache, edit(5, "edited"));
+    // Not the last message, so there is nothing cached to edit
+    apply(&cache, edit(4, "older"));
+    apply(&cache, json!({ "@type": "updateMessageViews", "chat_id": 1, "message_id": 5, "views": 9 }));
+
+    let chat = cache.chat(1).unwrap();
+    assert_eq!(chat.order, 30);
+    let last = chat.last_message.unwrap();
+    assert_eq!(text(&last), "edited");
+    assert_eq!(last.views, 9);
+}
+
+#[test]
+fn tracks_users() {
+    let cache = Cache::new();
+    apply(&cache, user(3));
+    apply(&cache, json!({
+        "@type": "updateUserStatus",
+        "user_id": 3,
+        "status": { "@type": "userStatusOnline", "expires": 100 },
+    }));
+
+    let user = cache.user(3).unwrap();
+    assert_eq!(user.username, "jane");
+    assert!(matches!(user.status, UserStatus::UserStatusOnline(status) if status.expires == 100));
+    assert!(cache.user(4).is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn wrapped_handlers_see_the_cache_updated() {
+    let mock = MockTdlib::new();
+    let cache = Cache::new();
+    let titles = Arc::new(Mutex::new(Vec::new()));
+    let seen = titles.clone();
+    let lookup = cache.clone();
+    let client = Client::with_transport(mock.clone(), cache.wrap(move |_: Client, upd: Update| {
+        if let Update::UpdateChatTitle(upd) = upd {
+            seen.lock().unwrap().push(lookup.chat(upd.chat_id).map(|chat| chat.title));
+        }
+        async {}
+    }));
+
+    mock.push_json(new_chat(1, 10, "chatListMain"));
+    mock.push_json(json!({ "@type": "updateChatTitle", "chat_id": 1, "title": "First" }));
+    mock.push_json(json!({ "@type": "updateChatTitle", "chat_id": 1, "title": "Second" }));
+    eventually(|| titles.lock().unwrap().len() == 2).await;
+    assert_eq!(*titles.lock().unwrap(), [Some("First".to_owned()), Some("Second".to_owned())]);
+    client.close().await.unwrap();
+}
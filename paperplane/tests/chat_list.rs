@@ -0,0 +1,108 @@
+mod common;
+
+use futures::channel::mpsc::UnboundedReceiver;
+use serde_json::{ json, Value as JsonValue };
+use paperplane::{
+    chat_list::{ ChatListEvent, ChatListView },
+    client::Client,
+    testing::MockTdlib,
+};
+use paperplane_types::types::{ self, Update };
+use common::{ eventually, new_chat };
+
+fn chat_order(chat_id: i64, order: i64) -> JsonValue {
+    json!({ "@type": "updateChatOrder", "chat_id": chat_id, "order": order.to_string() })
+}
+
+fn apply(view: &ChatListView, upd: JsonValue) {
+    view.apply(&serde_json::from_value::<Update>(upd).unwrap());
+}
+
+fn archive() -> ChatListView {
+    ChatListView::new(types::ChatList::ChatListArchive(types::ChatListArchive {}))
+}
+
+fn drain(events: &mut UnboundedReceiver<ChatListEvent>) -> Vec<ChatListEvent> {
+    let mut drained = Vec::new();
+    while let Ok(event) = events.try_recv() {
+        drained.push(event);
+    }
+    drained
+}
+
+#[test]
+fn places_chats_by_order() {
+    let view = archive();
+    let mut events = view.events();
+    apply(&view, new_chat(1, 10, "chatListArchive"));
+    apply(&view, new_chat(2, 30, "chatListArchive"));
+    // Same order as chat 1, ties go to the larger id like in TDLib
+    apply(&view, new_chat(3, 10, "chatListArchive"));
+    apply(&view, new_chat(4, 20, "chatListMain"));
+    assert_eq!(view.chat_ids(), vec![2, 3, 1]);
+    assert_eq!(drain(&mut events), vec![
+        ChatListEvent::Inserted { chat_id: 1, index: 0 },
+        ChatListEvent::Inserted { chat_id: 2, index: 0 },
+        ChatListEvent::Inserted { chat_id: 3, index: 1 },
+    ]);
+
+    apply(&view, chat_order(1, 40));
+    apply(&view, chat_order(2, 35));
+    apply(&view, chat_order(3, 0));
+    assert_eq!(view.chat_ids(), vec![1, 2]);
+    assert_eq!(drain(&mut events), vec![
+        ChatListEvent::Moved { chat_id: 1, from: 2, to: 0 },
+        ChatListEvent::Removed { chat_id: 3, index: 2 },
+    ]);
+
+    // An order of 0 hides the chat until it has one again
+    apply(&view, chat_order(3, 5));
+    assert_eq!(drain(&mut events), vec![ChatListEvent::Inserted { chat_id: 3, index: 2 }]);
+    assert_eq!(view.len(), 3);
+}
+
+#[test]
+fn follows_chats_between_lists() {
+    let view = archive();
+    apply(&view, new_chat(1, 10, "chatListArchive"));
+    apply(&view, new_chat(2, 20, "chatListMain"));
+    let mut events = view.events();
+
+    apply(&view, json!({ "@type": "updateChatChatList", "chat_id": 2, "chat_list": { "@type": "chatListArchive" } }));
+    apply(&view, json!({ "@type": "updateChatChatList", "chat_id": 1 }));
+    apply(&view, json!({ "@type": "updateChatIsPinned", "chat_id": 2, "is_pinned": true, "order": "100" }));
+    // Orders of chats in other lists are kept for when they move over
+    apply(&view, chat_order(1, 200));
+    assert_eq!(view.chat_ids(), vec![2]);
+    assert_eq!(view.pinned_chat_ids(), vec![2]);
+    assert_eq!(drain(&mut events), vec![
+        ChatListEvent::Inserted { chat_id: 2, index: 0 },
+        ChatListEvent::Removed { chat_id: 1, index: 1 },
+    ]);
+
+    apply(&view, json!({ "@type": "updateChatChatList", "chat_id": 1, "chat_list": { "@type": "chatListArchive" } }));
+    assert_eq!(view.chat_ids(), vec![1, 2]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn loads_chats_through_updates() {
+    let mock = MockTdlib::new();
+    let pusher = mock.clone();
+    mock.respond_json("getChats", move |req| {
+        if req["offset_chat_id"] != 0 {
+            return json!({ "@type": "chats", "chat_ids": [] });
+        }
+        pusher.push_json(new_chat(1, 10, "chatListArchive"));
+        pusher.push_json(new_chat(2, 30, "chatListArchive"));
+        json!({ "@type": "chats", "chat_ids": [2, 1] })
+    });
+    let view = archive();
+    let client = Client::with_transport(mock.clone(), view.wrap(|_: Client, _: Update| async {}));
+
+    assert_eq!(view.load_more(&client, 10).await.unwrap(), 2);
+    assert_eq!(view.chat_ids(), vec![2, 1]);
+    assert!(!view.is_complete());
+    assert_eq!(view.load_more(&client, 10).await.unwrap(), 0);
+    eventually(|| view.is_complete()).await;
+    client.close().await.unwrap();
+}
@@ -0,0 +1,118 @@
+mod common;
+
+use std::time::Duration;
+use paperplane::{ client::Client, testing::MockTdlib, transport::Transport, Error, Result };
+use paperplane_types::{ methods, types::{ self, Update } };
+use common::{ client, eventually, Traffic };
+
+// Never answers synchronous requests, as libtdjson does for ones it
+// cannot parse
+struct Mute(MockTdlib);
+
+impl Transport for Mute {
+    fn send(&self, request: &str) -> Result<()> {
+        self.0.send(request)
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> {
+        self.0.receive(timeout)
+    }
+
+    fn execute(&self, _: &str) -> Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn request_times_out() {
+    let mock = MockTdlib::new();
+    mock.ignore::<methods::GetMe>();
+    let client = client(&mock);
+
+    let result = client.send_with_timeout(methods::GetMe {}, Duration::from_millis(50)).unwrap().await;
+    assert!(matches!(result, Err(Error::Timeout)), "{:?}", result);
+    assert_eq!(client.pending_requests(), 0);
+
+    client.set_default_timeout(Some(Duration::from_millis(50)));
+    let result = client.send(methods::GetMe {}).unwrap().await;
+    assert!(matches!(result, Err(Error::Timeout)), "{:?}", result);
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn requests_time_out_under_constant_updates() {
+    let mock = MockTdlib::new();
+    mock.ignore::<methods::GetMe>();
+    let client = client(&mock);
+
+    let _traffic = Traffic::start(&mock);
+    let result = client.send_with_timeout(methods::GetMe {}, Duration::from_millis(50)).unwrap().await;
+    assert!(matches!(result, Err(Error::Timeout)), "{:?}", result);
+    assert_eq!(client.pending_requests(), 0);
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn dropping_a_request_cancels_it() {
+    let mock = MockTdlib::new();
+    mock.ignore::<methods::GetMe>();
+    let client = client(&mock);
+
+    let request = client.send(methods::GetMe {}).unwrap();
+    eventually(|| client.pending_requests() == 1).await;
+    drop(request);
+    eventually(|| client.pending_requests() == 0).await;
+    assert_eq!(mock.sent_of::<methods::GetMe>().len(), 1);
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn close_fails_pending_requests() {
+    let mock = MockTdlib::new();
+    mock.ignore::<methods::GetMe>();
+    let client = client(&mock);
+
+    let request = client.send(methods::GetMe {}).unwrap();
+    client.close().await.unwrap();
+    assert!(client.is_closed());
+    assert!(matches!(request.await, Err(Error::ClientClosed)));
+    assert!(matches!(client.send(methods::GetMe {}), Err(Error::ClientClosed)));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn tdlib_errors_are_returned() {
+    let mock = MockTdlib::new();
+    mock.respond_error::<methods::GetMe>(401, "Unauthorized");
+    let client = client(&mock);
+
+    let err = client.send(methods::GetMe {}).unwrap().await.unwrap_err();
+    assert_eq!(err.code(), Some(401));
+    assert_eq!(err.message(), Some("Unauthorized"));
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn executes_synchronous_methods() {
+    let mock = MockTdlib::new();
+    mock.respond(|req: methods::CleanFileName| types::Text { text: req.file_name.replace('/', "_") });
+    mock.respond_error::<methods::GetFileExtension>(400, "Invalid MIME type");
+    let client = client(&mock);
+
+    let cleaned = client.execute(methods::CleanFileName { file_name: "a/b".to_owned() }).unwrap();
+    assert_eq!(cleaned.text, "a_b");
+    let err = client.execute(methods::GetFileExtension { mime_type: "?".to_owned() }).unwrap_err();
+    assert_eq!(err.code(), Some(400));
+    // Answered on the spot, without going through the responder
+    assert!(mock.sent_of::<methods::CleanFileName>().is_empty());
+    client.close().await.unwrap();
+    assert!(matches!(client.execute(methods::CleanFileName { file_name: "a".to_owned() }), Err(Error::ClientClosed)));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn missing_synchronous_responses_are_protocol_errors() {
+    let client = Client::with_transport(Mute(MockTdlib::new()), |_: Client, _: Update| async {});
+
+    let result = client.execute(methods::CleanFileName { file_name: "a".to_owned() });
+    assert!(matches!(result, Err(Error::Protocol(_))), "{:?}", result);
+    client.close().await.unwrap();
+}
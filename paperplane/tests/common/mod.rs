@@ -0,0 +1,144 @@
+// Shared by the integration tests, each of which uses only some of it
+#![allow(dead_code)]
+
+use std::{
+    sync::{ Arc, atomic::{ AtomicBool, Ordering } },
+    thread::{ self, JoinHandle },
+    time::{ Duration, Instant },
+};
+use serde_json::{ json, Value as JsonValue };
+use paperplane::{ client::Client, testing::MockTdlib };
+use paperplane_types::types::Update;
+
+/// Client on `mock` ignoring every update
+pub fn client(mock: &MockTdlib) -> Client {
+    Client::with_transport(mock.clone(), |_: Client, _: Update| async {})
+}
+
+/// Waits up to a second for `done`, as handlers run on other threads
+pub async fn eventually<F: Fn() -> bool>(done: F) {
+    let deadline = Instant::now() + Duration::from_secs(1);
+    while !done() {
+        assert!(Instant::now() < deadline, "timed out waiting for a condition");
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+}
+
+/// Pushes updates to `mock` faster than the client handles them until
+/// dropped, so the client never goes quiet
+pub struct Traffic {
+    running: Arc<AtomicBool>,
+    pusher: Option<JoinHandle<()>>,
+}
+
+impl Traffic {
+    pub fn start(mock: &MockTdlib) -> Self {
+        let running = Arc::new(AtomicBool::new(true));
+        let (mock, still_running) = (mock.clone(), running.clone());
+        let pusher = thread::spawn(move || while still_running.load(Ordering::SeqCst) {
+            for _ in 0..100 {
+                mock.push_json(json!({ "@type": "updateOption", "name": "x", "value": { "@type": "optionValueEmpty" } }));
+            }
+            thread::sleep(Duration::from_millis(1));
+        });
+        Self { running, pusher: Some(pusher) }
+    }
+}
+
+impl Drop for Traffic {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::SeqCst);
+        if let Some(pusher) = self.pusher.take() {
+            pusher.join().unwrap();
+        }
+    }
+}
+
+pub fn formatted_text(text: &str, entities: JsonValue) -> JsonValue {
+    json!({ "@type": "formattedText", "text": text, "entities": entities })
+}
+
+/// A text message sent at `id * 10` by user 1
+pub fn message(chat_id: i64, id: i64, text: JsonValue) -> JsonValue {
+    json!({
+        "@type": "message",
+        "id": id,
+        "sender_user_id": 1,
+        "chat_id": chat_id,
+        "date": id * 10,
+        "edit_date": 0,
+        "reply_to_message_id": 0,
+        "ttl": 0,
+        "ttl_expires_in": 0.0,
+        "via_bot_user_id": 0,
+        "author_signature": "",
+        "views": 0,
+        "media_album_id": "0",
+        "restriction_reason": "",
+        "content": { "@type": "messageText", "text": text },
+    })
+}
+
+pub fn new_message(chat_id: i64, id: i64, text: JsonValue) -> JsonValue {
+    json!({ "@type": "updateNewMessage", "message": message(chat_id, id, text) })
+}
+
+/// A private chat titled "Chat" in `list`
+pub fn new_chat(id: i64, order: i64, list: &str) -> JsonValue {
+    json!({
+        "@type": "updateNewChat",
+        "chat": {
+            "@type": "chat",
+            "id": id,
+            "type": { "@type": "chatTypePrivate", "user_id": 3 },
+            "chat_list": { "@type": list },
+            "title": "Chat",
+            "permissions": {
+                "@type": "chatPermissions",
+                "can_send_messages": true,
+                "can_send_media_messages": true,
+                "can_send_polls": true,
+                "can_send_other_messages": true,
+                "can_add_web_page_previews": true,
+                "can_change_info": true,
+                "can_invite_users": true,
+                "can_pin_messages": true,
+            },
+            "order": order.to_string(),
+            "is_pinned": false,
+            "is_marked_as_unread": false,
+            "has_scheduled_messages": false,
+            "can_be_deleted_only_for_self": true,
+            "can_be_deleted_for_all_users": true,
+            "can_be_reported": false,
+            "default_disable_notification": false,
+            "unread_count": 0,
+            "last_read_inbox_message_id": 0,
+            "last_read_outbox_message_id": 0,
+            "unread_mention_count": 0,
+            "notification_settings": {
+                "@type": "chatNotificationSettings",
+                "use_default_mute_for": true,
+                "mute_for": 0,
+                "use_default_sound": true,
+                "sound": "",
+                "use_default_show_preview": true,
+                "show_preview": true,
+                "use_default_disable_pinned_message_notifications": true,
+                "disable_pinned_message_notifications": false,
+                "use_default_disable_mention_notifications": true,
+                "disable_mention_notifications": false,
+            },
+            "pinned_message_id": 0,
+            "reply_markup_message_id": 0,
+            "client_data": "",
+        },
+    })
+}
+
+pub fn authorization_state(state: &str) -> JsonValue {
+    json!({
+        "@type": "updateAuthorizationState",
+        "authorization_state": { "@type": state },
+    })
+}
@@ -0,0 +1,171 @@
+mod common;
+
+use std::{
+    env,
+    fs,
+    sync::{ Arc, Mutex },
+    time::Duration,
+};
+use futures::future::BoxFuture;
+use serde_json::json;
+use uuid::Uuid;
+use paperplane::{
+    client::Client,
+    dialogue::{ Dialogue, DialogueHandler, DialogueKey, FileStorage, InMemoryStorage, Storage, Stored, Transition },
+    testing::MockTdlib,
+    update::TypedHandler,
+    Error,
+};
+use paperplane_types::types::{ MessageContent, UpdateNewMessage };
+use common::{ client, formatted_text, new_message };
+
+fn message(user_id: i32, text: &str) -> UpdateNewMessage {
+    let mut upd = new_message(5, 1, formatted_text(text, json!([])));
+    upd["message"]["sender_user_id"] = user_id.into();
+    serde_json::from_value(upd).unwrap()
+}
+
+fn text(upd: &UpdateNewMessage) -> String {
+    match &upd.message.content {
+        MessageContent::MessageText(content) => content.text.text.clone(),
+        _ => panic!("not a text message"),
+    }
+}
+
+fn key(user_id: i32) -> DialogueKey {
+    DialogueKey { chat_id: 5, user_id }
+}
+
+// Counts the messages of a dialogue, recording the state each one saw;
+// "exit" ends the dialogue
+#[derive(Clone, Default)]
+struct Counter {
+    seen: Arc<Mutex<Vec<(String, u32)>>>,
+}
+
+impl DialogueHandler<u32> for Counter {
+    fn handle(&self, _: Client, state: u32, upd: UpdateNewMessage) -> BoxFuture<'static, Transition<u32>> {
+        let text = text(&upd);
+        self.seen.lock().unwrap().push((text.clone(), state));
+        Box::pin(async move {
+            match text.as_str() {
+                "exit" => Transition::Exit,
+                "stay" => Transition::Stay,
+                _ => Transition::Next(state + 1),
+            }
+        })
+    }
+}
+
+fn counting<St: Storage<u32>>(storage: St) -> (Dialogue<u32, St, Counter>, Counter) {
+    let counter = Counter::default();
+    (Dialogue::new(storage, counter.clone()), counter)
+}
+
+async fn send<H: TypedHandler<UpdateNewMessage>>(handler: &H, client: &Client, user_id: i32, text: &str) {
+    handler.handle(client.clone(), message(user_id, text)).await;
+}
+
+fn seen(counter: &Counter) -> Vec<(String, u32)> {
+    counter.seen.lock().unwrap().clone()
+}
+
+fn pairs(expected: &[(&str, u32)]) -> Vec<(String, u32)> {
+    expected.iter().map(|(text, state)| (text.to_string(), *state)).collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn keeps_a_state_per_dialogue() {
+    let mock = MockTdlib::new();
+    let client = client(&mock);
+    let storage = InMemoryStorage::new();
+    let (dialogue, counter) = counting(storage);
+
+    send(&dialogue, &client, 1, "a").await;
+    send(&dialogue, &client, 1, "b").await;
+    send(&dialogue, &client, 2, "c").await;
+    send(&dialogue, &client, 1, "stay").await;
+    send(&dialogue, &client, 1, "exit").await;
+    send(&dialogue, &client, 1, "d").await;
+    assert_eq!(seen(&counter), pairs(&[("a", 0), ("b", 1), ("c", 0), ("stay", 2), ("exit", 2), ("d", 0)]));
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn handles_messages_of_a_dialogue_in_order() {
+    let mock = MockTdlib::new();
+    let client = client(&mock);
+    let (dialogue, counter) = counting(InMemoryStorage::new());
+
+    let first = dialogue.handle(client.clone(), message(1, "a"));
+    let second = dialogue.handle(client.clone(), message(1, "b"));
+    // Whichever runs first takes the oldest message
+    second.await;
+    first.await;
+    assert_eq!(seen(&counter), pairs(&[("a", 0), ("b", 1)]));
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn ignores_outgoing_messages() {
+    let mock = MockTdlib::new();
+    let client = client(&mock);
+    let (dialogue, counter) = counting(InMemoryStorage::new());
+
+    let mut upd = message(1, "a");
+    upd.message.is_outgoing = true;
+    dialogue.handle(client.clone(), upd).await;
+    assert!(seen(&counter).is_empty());
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn restarts_idle_dialogues() {
+    let mock = MockTdlib::new();
+    let client = client(&mock);
+    let storage = InMemoryStorage::new();
+    // Saved long ago, so stale for any timeout
+    storage.save(key(1), Stored { state: 7, updated_at: 0 }).await.unwrap();
+    let (dialogue, counter) = counting(storage);
+    let dialogue = dialogue.timeout(Duration::from_secs(60));
+
+    send(&dialogue, &client, 1, "a").await;
+    send(&dialogue, &client, 1, "b").await;
+    assert_eq!(seen(&counter), pairs(&[("a", 0), ("b", 1)]));
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn keeps_stale_states_without_a_timeout() {
+    let mock = MockTdlib::new();
+    let client = client(&mock);
+    let storage = InMemoryStorage::new();
+    storage.save(key(1), Stored { state: 7, updated_at: 0 }).await.unwrap();
+    let (dialogue, counter) = counting(storage);
+
+    send(&dialogue, &client, 1, "a").await;
+    assert_eq!(seen(&counter), pairs(&[("a", 7)]));
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn stores_dialogues_in_files() {
+    let dir = env::temp_dir().join(format!("paperplane-dialogue-{}", Uuid::new_v4()));
+    let storage = FileStorage::<u32>::new(&dir).unwrap();
+
+    assert!(storage.load(key(1)).await.unwrap().is_none());
+    storage.save(key(1), Stored { state: 3, updated_at: 10 }).await.unwrap();
+    let stored = storage.load(key(1)).await.unwrap().unwrap();
+    assert_eq!((stored.state, stored.updated_at), (3, 10));
+
+    // Survives the storage, unlike `InMemoryStorage`
+    let reopened = FileStorage::<u32>::new(&dir).unwrap();
+    assert_eq!(reopened.load(key(1)).await.unwrap().unwrap().state, 3);
+    reopened.remove(key(1)).await.unwrap();
+    reopened.remove(key(1)).await.unwrap();
+    assert!(storage.load(key(1)).await.unwrap().is_none());
+
+    fs::write(dir.join("5_2.json"), "not json").unwrap();
+    assert!(matches!(storage.load(key(2)).await, Err(Error::Storage(_))));
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use std::sync::{ Arc, Mutex };
+use serde_json::json;
+use paperplane::{
+    client::Client,
+    testing::MockTdlib,
+    update::{ filter, Dispatcher, RawUpdate },
+};
+use paperplane_types::types::{ self, Update };
+use common::{ eventually, formatted_text, new_message };
+
+type Log = Arc<Mutex<Vec<String>>>;
+
+fn text(text: &str) -> serde_json::Value {
+    formatted_text(text, json!([]))
+}
+
+fn photo(chat_id: i64, id: i64) -> serde_json::Value {
+    let mut upd = new_message(chat_id, id, text(""));
+    upd["message"]["content"] = json!({
+        "@type": "messagePhoto",
+        "photo": { "@type": "photo", "has_stickers": false, "sizes": [] },
+        "caption": text(""),
+        "is_secret": false,
+    });
+    upd
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn routes_updates() {
+    let log = Log::default();
+    let (texts, messages, fallback, raw) = (log.clone(), log.clone(), log.clone(), log.clone());
+    let dispatcher = Dispatcher::new()
+        .middleware(|_, upd| match upd {
+            Update::UpdateNewMessage(upd) => upd.message.chat_id != 13,
+            _ => true,
+        })
+        .on_filtered(
+            filter::and(filter::chat(5), filter::content::<types::MessageText>()),
+            move |_: Client, upd: types::UpdateNewMessage| {
+                texts.lock().unwrap().push(format!("text {}", upd.message.id));
+                async {}
+            },
+        )
+        .on_filtered(
+            filter::or(filter::chat(5), filter::not(filter::sender(1))),
+            move |_: Client, upd: types::UpdateNewMessage| {
+                messages.lock().unwrap().push(format!("message {}", upd.message.id));
+                async {}
+            },
+        )
+        .fallback(move |_: Client, upd: Update| {
+            let name = match upd {
+                Update::UpdateNewMessage(upd) => format!("fallback {}", upd.message.id),
+                _ => "fallback".to_owned(),
+            };
+            fallback.lock().unwrap().push(name);
+            async {}
+        })
+        .on_raw(move |_: Client, upd: RawUpdate| {
+            raw.lock().unwrap().push(format!("raw {}", upd.type_name()));
+            async {}
+        });
+
+    let mock = MockTdlib::new();
+    let client = Client::with_transport(mock.clone(), dispatcher);
+    // Texts in chat 5 take the first route, other messages there the second
+    mock.push_json(new_message(5, 1, text("hi")));
+    mock.push_json(photo(5, 2));
+    // Messages elsewhere from user 1 and anything else fall through
+    mock.push_json(new_message(6, 3, text("hi")));
+    mock.push_json(json!({ "@type": "updateOption", "name": "test", "value": { "@type": "optionValueEmpty" } }));
+    // Dropped by the middleware, seen only raw
+    mock.push_json(new_message(13, 4, text("hi")));
+    eventually(|| log.lock().unwrap().len() == 9).await;
+
+    assert_eq!(*log.lock().unwrap(), vec![
+        "raw updateNewMessage",
+        "text 1",
+        "raw updateNewMessage",
+        "message 2",
+        "raw updateNewMessage",
+        "fallback 3",
+        "raw updateOption",
+        "fallback",
+        "raw updateNewMessage",
+    ]);
+    client.close().await.unwrap();
+}
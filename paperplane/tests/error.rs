@@ -0,0 +1,22 @@
+use std::time::Duration;
+use paperplane::Error;
+
+fn tdlib(code: i32, message: &str) -> Error {
+    Error::Tdlib { code, message: message.to_owned() }
+}
+
+#[test]
+fn retry_after() {
+    let err = tdlib(429, "Too Many Requests: retry after 35");
+    assert!(err.is_flood_wait());
+    assert_eq!(err.retry_after(), Some(Duration::from_secs(35)));
+
+    let err = tdlib(420, "FLOOD_WAIT_7");
+    assert!(err.is_flood_wait());
+    assert_eq!(err.retry_after(), Some(Duration::from_secs(7)));
+
+    assert_eq!(tdlib(429, "Too Many Requests").retry_after(), None);
+    assert_eq!(tdlib(400, "Bad Request: chat not found").retry_after(), None);
+    assert!(!tdlib(400, "Bad Request: chat not found").is_flood_wait());
+    assert_eq!(Error::Timeout.retry_after(), None);
+}
@@ -0,0 +1,152 @@
+mod common;
+
+use std::sync::{ Arc, Mutex, atomic::{ AtomicBool, Ordering } };
+use futures::future::BoxFuture;
+use serde_json::json;
+use paperplane::{ client::Client, testing::MockTdlib, transport::Transport, Error, Result };
+use paperplane_types::{ methods, types::{ self, OptionValue, Update } };
+use common::{ client, eventually };
+
+// Every error passed to the client's error hook, as `Error` is not `Clone`
+fn reported(client: &Client) -> Arc<Mutex<Vec<String>>> {
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let hook_errors = errors.clone();
+    client.set_error_hook(move |err| hook_errors.lock().unwrap().push(err.to_string()));
+    errors
+}
+
+fn respond_with_name(mock: &MockTdlib) {
+    mock.respond(|req: methods::GetOption| {
+        OptionValue::OptionValueString(types::OptionValueString { value: req.name })
+    });
+}
+
+async fn get_option(client: &Client, name: &str) -> Result<String> {
+    match client.send(methods::GetOption { name: name.to_owned() })?.await? {
+        OptionValue::OptionValueString(value) => Ok(value.value),
+        other => panic!("unexpected option value: {:?}", other),
+    }
+}
+
+// Refuses to pass on `getMe`, like libtdjson refuses requests it cannot
+// turn into a C string; panics in `receive` once `broken` is set
+#[derive(Clone, Default)]
+struct Faulty {
+    mock: MockTdlib,
+    broken: Arc<AtomicBool>,
+}
+
+impl Transport for Faulty {
+    fn send(&self, request: &str) -> Result<()> {
+        if request.contains("\"getMe\"") {
+            return Err(Error::Transport("request contains a NUL byte".to_owned()));
+        }
+        self.mock.send(request)
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> {
+        let msg = self.mock.receive(timeout);
+        if self.broken.load(Ordering::SeqCst) {
+            panic!("receive failed");
+        }
+        msg
+    }
+
+    fn execute(&self, request: &str) -> Result<Option<String>> {
+        self.mock.execute(request)
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn nul_bytes_reach_tdlib_escaped() {
+    let mock = MockTdlib::new();
+    respond_with_name(&mock);
+    let client = client(&mock);
+
+    assert_eq!(get_option(&client, "a\0b").await.unwrap(), "a\0b");
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn transport_errors_fail_the_request() {
+    let transport = Faulty::default();
+    respond_with_name(&transport.mock);
+    let client = Client::with_transport(transport.clone(), |_: Client, _: Update| async {});
+
+    let result = client.send(methods::GetMe {}).unwrap().await;
+    assert!(matches!(result, Err(Error::Transport(_))), "{:?}", result);
+    assert_eq!(client.pending_requests(), 0);
+    assert_eq!(get_option(&client, "after").await.unwrap(), "after");
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reports_responses_with_unknown_extra() {
+    let mock = MockTdlib::new();
+    respond_with_name(&mock);
+    let client = client(&mock);
+    let errors = reported(&client);
+
+    mock.push_json(json!({ "@type": "ok", "@extra": "not a request id" }));
+    mock.push_json(json!({ "@type": "ok" }));
+    // Well-formed, but no request is waiting for it
+    mock.push_json(json!({ "@type": "ok", "@extra": "00000000-0000-0000-0000-000000000000" }));
+    assert_eq!(get_option(&client, "after").await.unwrap(), "after");
+    // Handled in order, so reported before the response arrived
+    let errors = errors.lock().unwrap().clone();
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert!(errors.iter().all(|err| err.starts_with("unexpected message from tdlib")));
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reports_messages_with_malformed_type() {
+    let mock = MockTdlib::new();
+    respond_with_name(&mock);
+    let client = client(&mock);
+    let errors = reported(&client);
+
+    mock.push_json(json!({ "@type": 5 }));
+    mock.push_json(json!({ "value": "no type at all" }));
+    assert_eq!(get_option(&client, "after").await.unwrap(), "after");
+    // Handled in order, so reported before the response arrived
+    let errors = errors.lock().unwrap().clone();
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert!(errors.iter().all(|err| err.starts_with("unexpected message from tdlib")));
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn responder_panics_fail_pending_requests() {
+    let mock = MockTdlib::new();
+    mock.ignore::<methods::GetMe>();
+    let spawner = |_: BoxFuture<'static, ()>| panic!("spawner failed");
+    let client = Client::with_spawner(mock.clone(), |_: Client, _: Update| async {}, spawner);
+    let errors = reported(&client);
+
+    let request = client.send(methods::GetMe {}).unwrap();
+    eventually(|| client.pending_requests() == 1).await;
+    mock.push_json(json!({ "@type": "updateOption", "name": "x", "value": { "@type": "optionValueEmpty" } }));
+    assert!(matches!(request.await, Err(Error::ClientClosed)));
+    assert!(matches!(client.send(methods::GetMe {}), Err(Error::ClientClosed)));
+    let errors = errors.lock().unwrap();
+    assert_eq!(*errors, ["panic in responder thread: spawner failed"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn listener_panics_fail_pending_requests() {
+    let transport = Faulty::default();
+    transport.mock.ignore::<methods::GetOption>();
+    let client = Client::with_transport(transport.clone(), |_: Client, _: Update| async {});
+    let errors = reported(&client);
+
+    let request = client.send(methods::GetOption { name: "x".to_owned() }).unwrap();
+    eventually(|| client.pending_requests() == 1).await;
+    transport.broken.store(true, Ordering::SeqCst);
+    transport.mock.push_json(json!({ "@type": "updateOption", "name": "x", "value": { "@type": "optionValueEmpty" } }));
+    assert!(matches!(request.await, Err(Error::ClientClosed)));
+    client.close().await.unwrap();
+    assert!(client.is_closed());
+    let errors = errors.lock().unwrap();
+    assert_eq!(*errors, ["panic in listener thread: receive failed"]);
+}
@@ -0,0 +1,343 @@
+mod common;
+
+use std::{
+    io,
+    pin::Pin,
+    sync::{ Arc, Mutex, atomic::{ AtomicUsize, Ordering } },
+    task::{ Context, Poll },
+};
+use futures::{ io::{ AsyncRead, AsyncReadExt }, stream::StreamExt };
+use serde_json::{ json, Value as JsonValue };
+use paperplane::{ testing::MockTdlib, Error };
+use paperplane_types::{ methods, types::{ self, File, FileType } };
+use common::{ client, eventually };
+
+const CONTENTS: &[u8] = b"hello world";
+
+// File 7 of `CONTENTS.len()` bytes, `downloaded` of which are there
+fn file(downloaded: usize, active: bool) -> JsonValue {
+    json!({
+        "@type": "file",
+        "id": 7,
+        "size": CONTENTS.len(),
+        "expected_size": CONTENTS.len(),
+        "local": {
+            "@type": "localFile",
+            "path": "",
+            "can_be_downloaded": true,
+            "can_be_deleted": false,
+            "is_downloading_active": active,
+            "is_downloading_completed": downloaded == CONTENTS.len(),
+            "download_offset": 0,
+            "downloaded_prefix_size": downloaded,
+            "downloaded_size": downloaded,
+        },
+        "remote": {
+            "@type": "remoteFile",
+            "id": "remote",
+            "unique_id": "unique",
+            "is_uploading_active": false,
+            "is_uploading_completed": true,
+            "uploaded_size": CONTENTS.len(),
+        },
+    })
+}
+
+fn update_file(file: JsonValue) -> JsonValue {
+    json!({ "@type": "updateFile", "file": file })
+}
+
+fn downloaded(file: &File) -> i32 {
+    file.local.downloaded_size
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn downloads_with_progress() {
+    let mock = MockTdlib::new();
+    mock.respond_json("getFile", |_| file(0, false));
+    let tdlib = mock.clone();
+    mock.respond_json("downloadFile", move |_| {
+        tdlib.push_json(update_file(file(5, true)));
+        tdlib.push_json(update_file(file(CONTENTS.len(), false)));
+        file(CONTENTS.len(), false)
+    });
+    let client = client(&mock);
+
+    let mut download = client.download(7, 1).unwrap();
+    let progress = download.progress();
+    let file = download.await.unwrap();
+    assert!(file.local.is_downloading_completed);
+    let sizes: Vec<_> = progress.map(|file| downloaded(&file)).collect().await;
+    assert_eq!(sizes, [0, 5, 11]);
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn progress_of_a_downloaded_file_ends_right_away() {
+    let mock = MockTdlib::new();
+    mock.respond_json("getFile", |_| file(CONTENTS.len(), false));
+    mock.respond_json("downloadFile", |_| file(CONTENTS.len(), false));
+    let client = client(&mock);
+
+    let mut download = client.download(7, 1).unwrap();
+    let sizes: Vec<_> = download.progress().map(|file| downloaded(&file)).collect().await;
+    assert_eq!(sizes, [11]);
+    download.await.unwrap();
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn fails_interrupted_downloads() {
+    let mock = MockTdlib::new();
+    mock.respond_json("getFile", |_| file(0, false));
+    mock.respond_json("downloadFile", |_| file(5, false));
+    let client = client(&mock);
+
+    let result = client.download(7, 1).unwrap().await;
+    assert!(matches!(result, Err(Error::File(_))), "{:?}", result);
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reads_files_while_they_download() {
+    let mock = MockTdlib::new();
+    let available = Arc::new(AtomicUsize::new(5));
+    let prefix = available.clone();
+    mock.respond_json("getFileDownloadedPrefixSize", move |req| {
+        let offset = req["offset"].as_u64().unwrap() as usize;
+        json!({ "@type": "count", "count": prefix.load(Ordering::SeqCst) - offset })
+    });
+    // Downloading until the test lets the rest arrive, which it only
+    // does once the reader has asked for the first time
+    let state = available.clone();
+    let asked = AtomicUsize::new(0);
+    mock.respond_json("getFile", move |_| match asked.fetch_add(1, Ordering::SeqCst) {
+        0 => file(5, true),
+        _ => file(state.load(Ordering::SeqCst), true),
+    });
+    mock.respond_json("readFilePart", |req| {
+        let offset = req["offset"].as_u64().unwrap() as usize;
+        let count = req["count"].as_u64().unwrap() as usize;
+        json!({ "@type": "filePart", "data": base64::encode(&CONTENTS[offset..offset + count]) })
+    });
+    let client = client(&mock);
+
+    let mut reader = client.read_file(7);
+    let read = tokio::spawn(async move {
+        let mut contents = Vec::new();
+        reader.read_to_end(&mut contents).await.map(|_| contents)
+    });
+    // Waiting for more bytes once the first five are read
+    eventually(|| !mock.sent_of::<methods::GetFile>().is_empty()).await;
+    available.store(CONTENTS.len(), Ordering::SeqCst);
+    mock.push_json(update_file(file(CONTENTS.len(), false)));
+    assert_eq!(read.await.unwrap().unwrap(), CONTENTS);
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reading_fails_when_nothing_is_downloading() {
+    let mock = MockTdlib::new();
+    mock.respond_json("getFileDownloadedPrefixSize", |_| json!({ "@type": "count", "count": 0 }));
+    mock.respond_json("getFile", |_| file(0, false));
+    let client = client(&mock);
+
+    let mut contents = Vec::new();
+    assert!(client.read_file(7).read_to_end(&mut contents).await.is_err());
+    client.close().await.unwrap();
+}
+
+// File 9 being uploaded, `uploaded` bytes of which have been sent
+fn uploading(uploaded: usize) -> JsonValue {
+    let mut file = file(CONTENTS.len(), false);
+    file["id"] = 9.into();
+    file["remote"]["is_uploading_active"] = (uploaded < CONTENTS.len()).into();
+    file["remote"]["is_uploading_completed"] = (uploaded == CONTENTS.len()).into();
+    file["remote"]["uploaded_size"] = uploaded.into();
+    file
+}
+
+fn generation_start(generation_id: usize, req: &JsonValue) -> JsonValue {
+    json!({
+        "@type": "updateFileGenerationStart",
+        "generation_id": generation_id.to_string(),
+        "original_path": req["file"]["original_path"],
+        "destination_path": "/tmp/generated",
+        "conversion": req["file"]["conversion"],
+    })
+}
+
+// Answers uploadFile by asking for the generated file, again after each
+// finished generation until there were `generations`, and the last
+// finished generation by completing the upload
+fn respond_upload(mock: &MockTdlib, generations: usize) {
+    let upload = Arc::new(Mutex::new(JsonValue::Null));
+    let (tdlib, started) = (mock.clone(), upload.clone());
+    mock.respond_json("uploadFile", move |req| {
+        *started.lock().unwrap() = req.clone();
+        tdlib.push_json(generation_start(0, req));
+        uploading(0)
+    });
+    mock.respond_json("writeGeneratedFilePart", |_| json!({ "@type": "ok" }));
+    mock.respond_json("setFileGenerationProgress", |_| json!({ "@type": "ok" }));
+    let tdlib = mock.clone();
+    let finished = AtomicUsize::new(0);
+    mock.respond_json("finishFileGeneration", move |req| {
+        if req["error"].is_null() {
+            let next = finished.fetch_add(1, Ordering::SeqCst) + 1;
+            if next < generations {
+                tdlib.push_json(generation_start(next, &upload.lock().unwrap()));
+            } else {
+                tdlib.push_json(update_file(uploading(5)));
+                tdlib.push_json(update_file(uploading(CONTENTS.len())));
+            }
+        }
+        json!({ "@type": "ok" })
+    });
+}
+
+fn document() -> FileType {
+    FileType::FileTypeDocument(types::FileTypeDocument {})
+}
+
+// Fails every read
+struct Broken;
+
+impl AsyncRead for Broken {
+    fn poll_read(self: Pin<&mut Self>, _: &mut Context, _: &mut [u8]) -> Poll<io::Result<usize>> {
+        Poll::Ready(Err(io::Error::other("disk is gone")))
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn uploads_from_readers() {
+    let mock = MockTdlib::new();
+    respond_upload(&mock, 1);
+    let client = client(&mock);
+
+    let mut upload = client.upload_from_reader(CONTENTS, document(), "hello.txt");
+    let progress = upload.progress();
+    let file = upload.await.unwrap();
+    assert!(file.remote.is_uploading_completed);
+    let sizes: Vec<_> = progress.map(|file| file.remote.uploaded_size).collect().await;
+    assert_eq!(sizes, [5, 11]);
+
+    let parts = mock.sent_of::<methods::WriteGeneratedFilePart>();
+    let written: Vec<u8> = parts.iter().flat_map(|part| base64::decode(&part.data).unwrap()).collect();
+    assert_eq!(written, CONTENTS);
+    let finished = mock.sent_of::<methods::FinishFileGeneration>();
+    assert_eq!(finished.len(), 1);
+    assert!(finished[0].error.is_none());
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reports_read_errors_to_tdlib() {
+    let mock = MockTdlib::new();
+    respond_upload(&mock, 1);
+    let client = client(&mock);
+
+    let result = client.upload_from_reader(Broken, document(), "broken.txt").await;
+    assert!(matches!(result, Err(Error::File(_))), "{:?}", result);
+    let finished = mock.sent_of::<methods::FinishFileGeneration>();
+    assert_eq!(finished[0].error.as_ref().unwrap().message, "disk is gone");
+    assert!(mock.sent_of::<methods::WriteGeneratedFilePart>().is_empty());
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn refuses_to_generate_a_file_twice() {
+    let mock = MockTdlib::new();
+    respond_upload(&mock, 2);
+    let client = client(&mock);
+
+    let result = client.upload_from_reader(CONTENTS, document(), "hello.txt").await;
+    assert!(matches!(result, Err(Error::File(_))), "{:?}", result);
+    let finished = mock.sent_of::<methods::FinishFileGeneration>();
+    assert_eq!(finished.len(), 2);
+    assert!(finished[0].error.is_none());
+    assert_eq!(finished[1].generation_id, 1);
+    assert_eq!(finished[1].error.as_ref().unwrap().code, 400);
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn returns_files_uploaded_before() {
+    let mock = MockTdlib::new();
+    mock.respond_json("uploadFile", |_| uploading(CONTENTS.len()));
+    let client = client(&mock);
+
+    let mut upload = client.upload_from_reader(CONTENTS, document(), "hello.txt");
+    let progress = upload.progress();
+    assert!(upload.await.unwrap().remote.is_uploading_completed);
+    assert_eq!(progress.count().await, 1);
+    assert!(mock.sent_of::<methods::WriteGeneratedFilePart>().is_empty());
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn fails_uploads_tdlib_gives_up_on() {
+    let mock = MockTdlib::new();
+    let tdlib = mock.clone();
+    mock.respond_json("uploadFile", move |_| {
+        let mut failed = uploading(5);
+        failed["remote"]["is_uploading_active"] = false.into();
+        tdlib.push_json(update_file(failed));
+        uploading(0)
+    });
+    let client = client(&mock);
+
+    let mut upload = client.upload_from_reader(CONTENTS, document(), "hello.txt");
+    let progress = upload.progress();
+    let result = upload.await;
+    assert!(matches!(result, Err(Error::File(_))), "{:?}", result);
+    // The progress stream ends with the upload
+    assert_eq!(progress.count().await, 1);
+    client.close().await.unwrap();
+}
+
+// Never finishes reading, like a reader waiting on a slow source
+struct Pending;
+
+impl AsyncRead for Pending {
+    fn poll_read(self: Pin<&mut Self>, _: &mut Context, _: &mut [u8]) -> Poll<io::Result<usize>> {
+        Poll::Pending
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn fails_when_tdlib_stops_the_generation() {
+    let mock = MockTdlib::new();
+    let tdlib = mock.clone();
+    mock.respond_json("uploadFile", move |req| {
+        tdlib.push_json(generation_start(3, req));
+        tdlib.push_json(json!({ "@type": "updateFileGenerationStop", "generation_id": "3" }));
+        uploading(0)
+    });
+    let client = client(&mock);
+
+    let mut upload = client.upload_from_reader(Pending, document(), "pending.txt");
+    let progress = upload.progress();
+    let result = upload.await;
+    assert!(matches!(result, Err(Error::File(_))), "{:?}", result);
+    assert_eq!(progress.count().await, 0);
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn ignores_stops_of_finished_generations() {
+    let mock = MockTdlib::new();
+    respond_upload(&mock, 1);
+    let tdlib = mock.clone();
+    // TDLib stops the generation once it is finished
+    mock.respond_json("finishFileGeneration", move |_| {
+        tdlib.push_json(json!({ "@type": "updateFileGenerationStop", "generation_id": "0" }));
+        tdlib.push_json(update_file(uploading(CONTENTS.len())));
+        json!({ "@type": "ok" })
+    });
+    let client = client(&mock);
+
+    let file = client.upload_from_reader(CONTENTS, document(), "hello.txt").await.unwrap();
+    assert!(file.remote.is_uploading_completed);
+    client.close().await.unwrap();
+}
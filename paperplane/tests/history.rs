@@ -0,0 +1,113 @@
+mod common;
+
+use futures::StreamExt;
+use serde_json::{ json, Value as JsonValue };
+use paperplane::{ client::Client, history::HistoryOptions, testing::MockTdlib };
+use paperplane_types::{ methods, types };
+use common::{ client, formatted_text, message };
+
+const LAST_MESSAGE: i64 = 250;
+
+// A chat with messages 1 to 250, paged the way TDLib does: from the
+// newest message at or before `from_message_id`, moved `offset` messages
+// towards newer ones
+fn page(req: &JsonValue) -> JsonValue {
+    let from = match req["from_message_id"].as_i64().unwrap() {
+        0 => LAST_MESSAGE,
+        from => from.min(LAST_MESSAGE),
+    };
+    let offset = req["offset"].as_i64().unwrap();
+    let limit = req["limit"].as_i64().unwrap();
+    assert!(limit > -offset, "limit {} must be larger than -offset {}", limit, offset);
+    let newest = (from - offset).min(LAST_MESSAGE);
+    let messages: Vec<_> = (1..=newest)
+        .rev()
+        .take(limit as usize)
+        .map(|id| message(5, id, formatted_text("hi", json!([]))))
+        .collect();
+    json!({ "@type": "messages", "total_count": LAST_MESSAGE, "messages": messages })
+}
+
+fn mock() -> MockTdlib {
+    let mock = MockTdlib::new();
+    mock.respond_json("getChatHistory", page);
+    mock.respond_json("searchChatMessages", page);
+    mock
+}
+
+async fn history(client: &Client, options: HistoryOptions) -> Vec<i64> {
+    client.chat_history(5, options).map(|msg| msg.unwrap().id).collect().await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn pages_backward() {
+    let client = client(&mock());
+    let ids = history(&client, HistoryOptions::new().page_size(30)).await;
+    assert_eq!(ids, (1..=LAST_MESSAGE).rev().collect::<Vec<_>>());
+
+    let ids = history(&client, HistoryOptions::new().from_message(100).page_size(1)).await;
+    assert_eq!(ids, (1..=100).rev().collect::<Vec<_>>());
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn pages_forward() {
+    let client = client(&mock());
+    let ids = history(&client, HistoryOptions::new().from_message(100).forward().page_size(7)).await;
+    assert_eq!(ids, (100..=LAST_MESSAGE).collect::<Vec<_>>());
+
+    for page_size in &[1, 3] {
+        let options = HistoryOptions::new().from_message(3).forward().page_size(*page_size).stop_when(|msg| msg.id > 10);
+        assert_eq!(history(&client, options).await, (3..=10).collect::<Vec<_>>(), "page size {}", page_size);
+    }
+
+    let ids = history(&client, HistoryOptions::new().from_message(200).forward()).await;
+    assert_eq!(ids, (200..=LAST_MESSAGE).collect::<Vec<_>>());
+    // Nothing is newer than the last message
+    assert_eq!(history(&client, HistoryOptions::new().forward()).await, Vec::<i64>::new());
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn stops_at_dates() {
+    let client = client(&mock());
+    let ids = history(&client, HistoryOptions::new().from_message(100).min_date(505).max_date(900)).await;
+    assert_eq!(ids, (51..=90).rev().collect::<Vec<_>>());
+
+    let ids = history(&client, HistoryOptions::new().from_message(10).forward().max_date(150)).await;
+    assert_eq!(ids, (10..=15).collect::<Vec<_>>());
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn pages_search_results() {
+    let mock = mock();
+    let client = client(&mock);
+    let request = methods::SearchChatMessages {
+        chat_id: 5,
+        query: "hi".to_owned(),
+        sender_user_id: 0,
+        from_message_id: 0,
+        offset: 0,
+        limit: 0,
+        filter: types::SearchMessagesFilter::SearchMessagesFilterEmpty(types::SearchMessagesFilterEmpty {}),
+    };
+    let ids: Vec<i64> = client
+        .search_chat_messages(request, HistoryOptions::new().from_message(40).page_size(15))
+        .map(|msg| msg.unwrap().id)
+        .collect()
+        .await;
+    assert_eq!(ids, (1..=40).rev().collect::<Vec<_>>());
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn ends_after_an_error() {
+    let mock = MockTdlib::new();
+    mock.respond_error::<methods::GetChatHistory>(400, "Chat not found");
+    let client = client(&mock);
+    let results: Vec<_> = client.chat_history(5, HistoryOptions::new()).collect().await;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].as_ref().unwrap_err().code(), Some(400));
+    client.close().await.unwrap();
+}
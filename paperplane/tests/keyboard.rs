@@ -0,0 +1,93 @@
+use serde::{ Serialize, Deserialize };
+use serde_json::json;
+use paperplane::keyboard::{ self, InlineKeyboard, ReplyKeyboard, MAX_CALLBACK_DATA };
+use paperplane_types::types::{ self, InlineKeyboardButton, InlineKeyboardButtonType, ReplyMarkup };
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+enum Action {
+    Like(u32),
+    Skip,
+}
+
+fn inline_rows(markup: &ReplyMarkup) -> &[Vec<InlineKeyboardButton>] {
+    match markup {
+        ReplyMarkup::ReplyMarkupInlineKeyboard(keyboard) => &keyboard.rows,
+        markup => panic!("not an inline keyboard: {:?}", markup),
+    }
+}
+
+fn texts<'a, I: IntoIterator<Item = &'a String>>(rows: Vec<I>) -> Vec<Vec<&'a str>> {
+    rows.into_iter().map(|row| row.into_iter().map(String::as_str).collect()).collect()
+}
+
+// What TDLib would report for a press of `button`
+fn callback_query(button: &InlineKeyboardButton) -> types::UpdateNewCallbackQuery {
+    let data = match &button.type_ {
+        InlineKeyboardButtonType::InlineKeyboardButtonTypeCallback(callback) => &callback.data,
+        type_ => panic!("not a callback button: {:?}", type_),
+    };
+    serde_json::from_value(json!({
+        "id": "1",
+        "sender_user_id": 1,
+        "chat_id": "2",
+        "message_id": "3",
+        "chat_instance": "4",
+        "payload": { "@type": "callbackQueryPayloadData", "data": data },
+    }))
+    .unwrap()
+}
+
+#[test]
+fn lays_out_inline_buttons() {
+    let markup = InlineKeyboard::new()
+        .columns(2)
+        .callback("a", b"1")
+        .callback("b", b"2")
+        .callback("c", b"3")
+        .row()
+        .row()
+        .url("d", "https://example.com")
+        .build()
+        .unwrap();
+    let rows = inline_rows(&markup).iter().map(|row| row.iter().map(|button| &button.text)).collect();
+    assert_eq!(texts(rows), vec![vec!["a", "b"], vec!["c"], vec!["d"]]);
+}
+
+#[test]
+fn lays_out_reply_buttons() {
+    let markup = ReplyKeyboard::new().text("a").text("b").row().request_location("c").resize().one_time().build();
+    let keyboard = match markup {
+        ReplyMarkup::ReplyMarkupShowKeyboard(keyboard) => keyboard,
+        markup => panic!("not a reply keyboard: {:?}", markup),
+    };
+    let rows = keyboard.rows.iter().map(|row| row.iter().map(|button| &button.text)).collect();
+    assert_eq!(texts(rows), vec![vec!["a", "b"], vec!["c"]]);
+    assert!(keyboard.resize_keyboard && keyboard.one_time && !keyboard.is_personal);
+}
+
+#[test]
+fn round_trips_callback_data() {
+    let markup = InlineKeyboard::new()
+        .callback("raw", b"\x00\xff")
+        .callback_with("like", &Action::Like(3))
+        .callback_with("skip", &Action::Skip)
+        .build()
+        .unwrap();
+    let row = &inline_rows(&markup)[0];
+    assert_eq!(keyboard::callback_data(&callback_query(&row[0])), Some(vec![0, 255]));
+    assert_eq!(keyboard::decode_callback::<Action>(&callback_query(&row[1])).unwrap(), Action::Like(3));
+    assert_eq!(keyboard::decode_callback::<Action>(&callback_query(&row[2])).unwrap(), Action::Skip);
+    assert!(keyboard::decode_callback::<Action>(&callback_query(&row[0])).is_err());
+}
+
+#[test]
+fn refuses_oversized_callback_data() {
+    let fits = InlineKeyboard::new().callback("a", [0; MAX_CALLBACK_DATA]).build();
+    assert!(fits.is_ok());
+    let err = InlineKeyboard::new()
+        .callback("a", [0; MAX_CALLBACK_DATA + 1])
+        .callback("b", b"ok")
+        .build()
+        .unwrap_err();
+    assert!(err.to_string().contains("65"), "{}", err);
+}
@@ -0,0 +1,108 @@
+mod common;
+
+use std::{
+    sync::{ Arc, atomic::{ AtomicUsize, Ordering } },
+    time::{ Duration, Instant },
+};
+use serde_json::json;
+use paperplane::{
+    limit::{ Rate, RateLimits },
+    testing::MockTdlib,
+    text::{ self, FormattedTextBuilder },
+    Error,
+};
+use paperplane_types::methods;
+use common::{ client, formatted_text, Traffic };
+
+fn hello(chat_id: i64) -> methods::SendMessage {
+    text::send_message(chat_id, FormattedTextBuilder::new().text("hello").build())
+}
+
+// Answers sendMessage with consecutive message ids, failing the first
+// `flood_waits` attempts with a 429
+fn respond_flood_waits(mock: &MockTdlib, flood_waits: usize, message: &str) -> Arc<AtomicUsize> {
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let counter = attempts.clone();
+    let message = message.to_owned();
+    mock.respond_json("sendMessage", move |req| {
+        let attempt = counter.fetch_add(1, Ordering::SeqCst);
+        if attempt < flood_waits {
+            json!({ "@type": "error", "code": 429, "message": message })
+        } else {
+            let chat_id = req["chat_id"].as_i64().unwrap();
+            common::message(chat_id, attempt as i64, formatted_text("hello", json!([])))
+        }
+    });
+    attempts
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn retries_flood_waits() {
+    let mock = MockTdlib::new();
+    let attempts = respond_flood_waits(&mock, 1, "Too Many Requests: retry after 1");
+    let client = client(&mock);
+    client.set_rate_limits(RateLimits::new().retry_flood_wait(true)).unwrap();
+
+    let start = Instant::now();
+    let sent = client.send(hello(5)).unwrap().await.unwrap();
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    assert_eq!(sent.id, 1);
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    assert_eq!(client.rate_limit_stats().retried, 1);
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn retries_flood_waits_under_constant_updates() {
+    let mock = MockTdlib::new();
+    let attempts = respond_flood_waits(&mock, 1, "Too Many Requests: retry after 1");
+    let client = client(&mock);
+    client.set_rate_limits(RateLimits::new().retry_flood_wait(true)).unwrap();
+
+    let _traffic = Traffic::start(&mock);
+    let sent = client.send_with_timeout(hello(5), Duration::from_secs(3)).unwrap().await.unwrap();
+    assert_eq!(sent.id, 1);
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn fails_flood_waits_without_retries() {
+    let mock = MockTdlib::new();
+    respond_flood_waits(&mock, 1, "FLOOD_WAIT_30");
+    let client = client(&mock);
+    client.set_rate_limits(RateLimits::new().retry_flood_wait(true).max_retry_delay(Duration::from_secs(10))).unwrap();
+
+    let err = client.send(hello(5)).unwrap().await.unwrap_err();
+    assert_eq!(err.retry_after(), Some(Duration::from_secs(30)));
+    assert_eq!(client.rate_limit_stats().retried, 0);
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn holds_requests_over_the_limit() {
+    let mock = MockTdlib::new();
+    respond_flood_waits(&mock, 0, "");
+    mock.respond_error::<methods::GetMe>(401, "Unauthorized");
+    let client = client(&mock);
+    client.set_rate_limits(RateLimits::new().per_chat(Rate::new(1, Duration::from_millis(200)))).unwrap();
+
+    let start = Instant::now();
+    let first = client.send(hello(5)).unwrap();
+    let second = client.send(hello(5)).unwrap();
+    // Neither other chats nor methods that are not limited wait
+    client.send(hello(6)).unwrap().await.unwrap();
+    assert!(client.send(methods::GetMe {}).unwrap().await.is_err());
+    assert!(start.elapsed() < Duration::from_millis(200));
+
+    first.await.unwrap();
+    second.await.unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(200));
+    assert_eq!(client.rate_limit_stats().held, 1);
+
+    // Held requests still time out
+    let result = client.send_with_timeout(hello(5), Duration::from_millis(50)).unwrap().await;
+    assert!(matches!(result, Err(Error::Timeout)), "{:?}", result);
+    assert_eq!(client.pending_requests(), 0);
+    client.close().await.unwrap();
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use std::sync::{ Arc, Mutex };
+use serde_json::json;
+use paperplane::{ client::Client, testing::MockTdlib, Error };
+use paperplane_types::{ methods, types::{ self, OptionValue, Update } };
+use common::{ client, eventually };
+
+fn option(value: &str) -> OptionValue {
+    OptionValue::OptionValueString(types::OptionValueString { value: value.to_owned() })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn answers_scripted_requests() {
+    let mock = MockTdlib::new();
+    mock.respond(|req: methods::GetOption| option(&req.name));
+    let client = client(&mock);
+
+    let value = client.send(methods::GetOption { name: "version".to_owned() }).unwrap().await.unwrap();
+    assert!(matches!(value, OptionValue::OptionValueString(value) if value.value == "version"));
+    let sent = mock.sent_of::<methods::GetOption>();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].name, "version");
+    // Recorded as sent, with the @extra set by the client
+    assert!(mock.sent()[0]["@extra"].is_string());
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn tags_object_responses() {
+    let mock = MockTdlib::new();
+    // Structs serialize without @type, which the client needs to see
+    mock.respond(|_: methods::GetMe| serde_json::from_value::<types::User>(json!({
+        "@type": "user",
+        "id": 1,
+        "first_name": "Bot",
+        "last_name": "",
+        "username": "MyBot",
+        "phone_number": "",
+        "status": { "@type": "userStatusEmpty" },
+        "restriction_reason": "",
+        "language_code": "",
+        "type": { "@type": "userTypeRegular" },
+    })).unwrap());
+    // Neither tagged nor taggable, as options are a class
+    mock.respond(|_: methods::GetOption| json!({ "value": "untagged" }));
+    let client = client(&mock);
+
+    let me = client.send(methods::GetMe {}).unwrap().await.unwrap();
+    assert_eq!(me.username, "MyBot");
+    let err = client.send(methods::GetOption { name: "version".to_owned() }).unwrap().await.unwrap_err();
+    assert_eq!(err.code(), Some(500));
+    assert_eq!(err.message(), Some("mock response has no @type"));
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn fails_unscripted_requests() {
+    let mock = MockTdlib::new();
+    let client = client(&mock);
+
+    let err = client.send(methods::GetMe {}).unwrap().await.unwrap_err();
+    assert_eq!(err.code(), Some(400));
+    assert_eq!(err.message(), Some("no mock response for getMe"));
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn ignored_requests_stay_pending() {
+    let mock = MockTdlib::new();
+    mock.ignore::<methods::GetMe>();
+    let client = client(&mock);
+
+    let request = client.send(methods::GetMe {}).unwrap();
+    eventually(|| mock.sent_of::<methods::GetMe>().len() == 1).await;
+    assert_eq!(client.pending_requests(), 1);
+    client.close().await.unwrap();
+    assert!(matches!(request.await, Err(Error::ClientClosed)));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn delivers_pushed_updates() {
+    let mock = MockTdlib::new();
+    let names = Arc::new(Mutex::new(Vec::new()));
+    let seen = names.clone();
+    let client = Client::with_transport(mock.clone(), move |_: Client, upd: Update| {
+        if let Update::UpdateOption(upd) = upd {
+            seen.lock().unwrap().push(upd.name);
+        }
+        async {}
+    });
+
+    mock.push_json(json!({
+        "@type": "updateOption",
+        "name": "first",
+        "value": { "@type": "optionValueEmpty" },
+    }));
+    mock.push_update(Update::UpdateOption(types::UpdateOption {
+        name: "second".to_owned(),
+        value: option("value"),
+    }));
+    eventually(|| names.lock().unwrap().len() == 2).await;
+    assert_eq!(*names.lock().unwrap(), ["first", "second"]);
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn answers_close_like_tdlib() {
+    let mock = MockTdlib::new();
+    let client = client(&mock);
+
+    // Resolves only once authorizationStateClosed has arrived
+    client.close().await.unwrap();
+    assert!(client.is_closed());
+    assert_eq!(mock.sent_of::<methods::Close>().len(), 1);
+}
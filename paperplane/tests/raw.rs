@@ -0,0 +1,60 @@
+mod common;
+
+use std::sync::{ Arc, Mutex };
+use serde_json::json;
+use paperplane::{
+    client::Client,
+    testing::MockTdlib,
+    update::{ Dispatcher, RawUpdate },
+    Error,
+};
+use common::{ client, eventually };
+
+#[tokio::test(flavor = "multi_thread")]
+async fn sends_raw_requests() {
+    let mock = MockTdlib::new();
+    mock.respond_json("getFutureThing", |req| json!({ "@type": "futureThing", "echo": req["value"] }));
+    let client = client(&mock);
+
+    // @extra set by the caller is replaced, or the response would not find the request
+    let resp = client.send_raw(json!({ "@type": "getFutureThing", "value": 3, "@extra": "mine" })).unwrap().await.unwrap();
+    assert_eq!(resp["@type"], "futureThing");
+    assert_eq!(resp["echo"], 3);
+    let sent = mock.sent();
+    assert_ne!(sent[0]["@extra"], "mine");
+    assert_eq!(resp["@extra"], sent[0]["@extra"]);
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn fails_raw_requests_on_errors() {
+    let mock = MockTdlib::new();
+    let client = client(&mock);
+
+    let err = client.send_raw(json!({ "@type": "getFutureThing" })).unwrap().await.unwrap_err();
+    assert_eq!(err.code(), Some(400));
+    let result = client.send_raw(json!(["getFutureThing"]));
+    assert!(matches!(result, Err(Error::InvalidRequest(_))), "{:?}", result);
+    // Refused before it got to TDLib
+    assert_eq!(mock.sent().len(), 1);
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn passes_raw_updates_on() {
+    let mock = MockTdlib::new();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let record = seen.clone();
+    let dispatcher = Dispatcher::new().on_raw(move |_: Client, upd: RawUpdate| {
+        record.lock().unwrap().push(upd.type_name().to_owned());
+        async {}
+    });
+    let client = Client::with_transport(mock.clone(), dispatcher);
+
+    // Unknown to paperplane_types, so only raw handlers see it whole
+    mock.push_json(json!({ "@type": "updateFutureThing", "value": 1 }));
+    mock.push_json(json!({ "@type": "updateOption", "name": "x", "value": { "@type": "optionValueEmpty" } }));
+    eventually(|| seen.lock().unwrap().len() == 2).await;
+    assert_eq!(*seen.lock().unwrap(), ["updateFutureThing", "updateOption"]);
+    client.close().await.unwrap();
+}
@@ -0,0 +1,75 @@
+use paperplane::text::{ self, FormattedTextBuilder };
+use paperplane_types::types::{ FormattedText, TextEntityType, TextEntityTypeTextUrl };
+
+fn ranges(formatted: &FormattedText) -> Vec<(i32, i32)> {
+    formatted.entities.iter().map(|entity| (entity.offset, entity.length)).collect()
+}
+
+fn texts(parts: &[FormattedText]) -> Vec<&str> {
+    parts.iter().map(|part| part.text.as_str()).collect()
+}
+
+#[test]
+fn counts_offsets_in_utf16() {
+    let formatted = FormattedTextBuilder::new().text("👋 ").bold("héllo").text(" ").mention("bob", 5).build();
+    assert_eq!(formatted.text, "👋 héllo bob");
+    assert_eq!(ranges(&formatted), vec![(3, 5), (9, 3)]);
+    assert_eq!(text::utf16_len(&formatted.text), 12);
+
+    let link = TextEntityType::TextEntityTypeTextUrl(TextEntityTypeTextUrl { url: "https://example.com".to_owned() });
+    let inner = FormattedTextBuilder::new().text("a").bold("😀b").build();
+    let wrapped = FormattedTextBuilder::new().text("x").wrap(inner, link).build();
+    assert_eq!(ranges(&wrapped), vec![(1, 4), (2, 3)]);
+
+    let twice = text::concat(vec![formatted.clone(), formatted]);
+    assert_eq!(ranges(&twice), vec![(3, 5), (9, 3), (15, 5), (21, 3)]);
+}
+
+#[test]
+fn slices_entities() {
+    let formatted = FormattedTextBuilder::new().text("👋 ").bold("héllo").text(" ").mention("bob", 5).build();
+    let sliced = text::slice(&formatted, "👋 hé".len().."👋 héllo b".len());
+    assert_eq!(sliced.text, "llo b");
+    assert_eq!(ranges(&sliced), vec![(0, 3), (4, 1)]);
+
+    let empty = text::slice(&formatted, 0..0);
+    assert_eq!(empty.text, "");
+    assert!(empty.entities.is_empty());
+}
+
+#[test]
+fn splits_at_line_breaks() {
+    let long = FormattedTextBuilder::new().bold(&"ab cd\n".repeat(10)).text("😀😀😀").build();
+    let parts = text::split(&long, 14);
+    assert_eq!(parts[0].text, "ab cd\nab cd\n");
+    assert_eq!(parts.len(), 6);
+    assert_eq!(parts[5].text, "😀😀😀");
+    assert_eq!(texts(&parts).concat(), long.text);
+    for part in &parts {
+        let length = text::utf16_len(&part.text);
+        assert!(length <= 14, "{:?} is too long", part.text);
+        assert!(part.entities.iter().all(|entity| entity.offset + entity.length <= length));
+    }
+
+    let tiny = text::split(&long, 1);
+    assert_eq!(texts(&tiny).concat(), long.text);
+}
+
+#[test]
+fn splits_at_whitespace_without_line_breaks() {
+    let formatted = FormattedTextBuilder::new().text("one two three").build();
+    assert_eq!(texts(&text::split(&formatted, 9)), vec!["one two ", "three"]);
+    // Words longer than a part are cut
+    let formatted = FormattedTextBuilder::new().text("abcdefgh").build();
+    assert_eq!(texts(&text::split(&formatted, 3)), vec!["abc", "def", "gh"]);
+}
+
+#[test]
+fn keeps_leading_line_breaks_with_the_text() {
+    let formatted = FormattedTextBuilder::new().text("\nfoo bar baz").build();
+    assert_eq!(texts(&text::split(&formatted, 8)), vec!["\nfoo ", "bar baz"]);
+
+    let formatted = FormattedTextBuilder::new().text("\n\nfoo\nbar").build();
+    assert_eq!(texts(&text::split(&formatted, 7)), vec!["\n\nfoo\n", "bar"]);
+    assert_eq!(texts(&text::split(&formatted, 100)), vec!["\n\nfoo\nbar"]);
+}
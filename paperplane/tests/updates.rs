@@ -0,0 +1,102 @@
+mod common;
+
+use std::sync::{ Arc, atomic::{ AtomicUsize, Ordering } };
+use futures::stream::{ Stream, StreamExt };
+use serde_json::json;
+use paperplane::{ client::Client, testing::MockTdlib, update::LagPolicy };
+use paperplane_types::types::{ OptionValue, Update };
+use common::eventually;
+
+// Client counting the updates it has handled, which it does after
+// publishing them to the streams
+fn counting_client(mock: &MockTdlib) -> (Client, Arc<AtomicUsize>) {
+    let handled = Arc::new(AtomicUsize::new(0));
+    let counter = handled.clone();
+    let client = Client::with_transport(mock.clone(), move |_: Client, _: Update| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        async {}
+    });
+    (client, handled)
+}
+
+fn push_options(mock: &MockTdlib, values: &[i32]) {
+    for value in values {
+        mock.push_json(json!({
+            "@type": "updateOption",
+            "name": "test",
+            "value": { "@type": "optionValueInteger", "value": value },
+        }));
+    }
+}
+
+async fn option_values<S: Stream<Item = Update>>(stream: S) -> Vec<i32> {
+    stream
+        .filter_map(|upd| async move {
+            match upd {
+                Update::UpdateOption(upd) => match upd.value {
+                    OptionValue::OptionValueInteger(value) => Some(value.value),
+                    _ => None,
+                },
+                _ => None,
+            }
+        })
+        .collect()
+        .await
+}
+
+// What a subscriber buffering two updates gets of four, and how many
+// it lost
+async fn lagging(policy: LagPolicy) -> (Vec<i32>, u64) {
+    let mock = MockTdlib::new();
+    let (client, handled) = counting_client(&mock);
+    let mut stream = client.updates_with(2, policy);
+    push_options(&mock, &[1, 2, 3, 4]);
+    eventually(|| handled.load(Ordering::SeqCst) == 4).await;
+    let received = option_values(stream.by_ref().take(2)).await;
+    let lagged = stream.lagged();
+    client.close().await.unwrap();
+    (received, lagged)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn drops_the_oldest_updates() {
+    assert_eq!(lagging(LagPolicy::DropOldest).await, (vec![3, 4], 2));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn drops_the_newest_updates() {
+    assert_eq!(lagging(LagPolicy::DropNewest).await, (vec![1, 2], 2));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn disconnects_lagging_subscribers() {
+    assert_eq!(lagging(LagPolicy::Disconnect).await, (vec![1, 2], 1));
+
+    let mock = MockTdlib::new();
+    let (client, handled) = counting_client(&mock);
+    let stream = client.updates_with(1, LagPolicy::Disconnect);
+    push_options(&mock, &[1, 2, 3]);
+    eventually(|| handled.load(Ordering::SeqCst) == 3).await;
+    // Ends without waiting for the client to close
+    assert_eq!(option_values(stream).await, vec![1]);
+    client.close().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn streams_end_when_the_client_closes() {
+    let mock = MockTdlib::new();
+    let (client, handled) = counting_client(&mock);
+    let first = client.updates();
+    push_options(&mock, &[1]);
+    eventually(|| handled.load(Ordering::SeqCst) == 1).await;
+    let second = client.updates();
+    push_options(&mock, &[2]);
+    client.close().await.unwrap();
+
+    // Ordered, with the closing authorizationStateClosed last
+    let updates: Vec<_> = first.collect().await;
+    assert_eq!(updates.len(), 3);
+    assert!(matches!(updates[2], Update::UpdateAuthorizationState(_)));
+    assert_eq!(option_values(client.updates()).await, Vec::<i32>::new());
+    assert_eq!(option_values(second).await, vec![2]);
+}